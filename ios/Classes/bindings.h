@@ -33,9 +33,28 @@ typedef struct wire_NetAddress_IPv6 {
   uint16_t port;
 } wire_NetAddress_IPv6;
 
+typedef struct wire_NetAddress_OnionV2 {
+  struct wire_uint_8_list *addr;
+} wire_NetAddress_OnionV2;
+
+typedef struct wire_NetAddress_OnionV3 {
+  struct wire_uint_8_list *pubkey;
+  uint16_t checksum;
+  uint8_t version;
+  uint16_t port;
+} wire_NetAddress_OnionV3;
+
+typedef struct wire_NetAddress_Hostname {
+  struct wire_uint_8_list *host;
+  uint16_t port;
+} wire_NetAddress_Hostname;
+
 typedef union NetAddressKind {
   struct wire_NetAddress_IPv4 *IPv4;
   struct wire_NetAddress_IPv6 *IPv6;
+  struct wire_NetAddress_OnionV2 *OnionV2;
+  struct wire_NetAddress_OnionV3 *OnionV3;
+  struct wire_NetAddress_Hostname *Hostname;
 } NetAddressKind;
 
 typedef struct wire_NetAddress {
@@ -43,6 +62,23 @@ typedef struct wire_NetAddress {
   union NetAddressKind *kind;
 } wire_NetAddress;
 
+typedef struct wire_InboundChannelPolicy {
+  struct wire_list_public_key *allowed_peers;
+  uint64_t *min_channel_size_sats;
+  int32_t *required_visibility;
+  uint32_t *max_pending_channels;
+} wire_InboundChannelPolicy;
+
+typedef struct wire_FeeAutopilotConfig {
+  uint32_t min_base_fee_msat;
+  uint32_t max_base_fee_msat;
+  uint32_t min_proportional_millionths;
+  uint32_t max_proportional_millionths;
+  uint8_t target_outbound_percent;
+  uint64_t interval_secs;
+  bool dry_run;
+} wire_FeeAutopilotConfig;
+
 typedef struct wire_Config {
   struct wire_uint_8_list *storage_dir_path;
   int32_t network;
@@ -53,6 +89,8 @@ typedef struct wire_Config {
   int32_t log_level;
   struct wire_NetAddress *listening_address;
   uint32_t default_cltv_expiry_delta;
+  struct wire_InboundChannelPolicy *inbound_channel_policy;
+  struct wire_FeeAutopilotConfig *fee_autopilot;
 } wire_Config;
 
 typedef struct wire_ChainDataSourceConfig_Esplora {
@@ -118,8 +156,13 @@ typedef struct wire_MutexNodeSqliteStore {
   const void *ptr;
 } wire_MutexNodeSqliteStore;
 
+typedef struct wire_NodeStore {
+  const void *ptr;
+} wire_NodeStore;
+
 typedef struct wire_NodePointer {
   struct wire_MutexNodeSqliteStore field0;
+  struct wire_NodeStore field1;
 } wire_NodePointer;
 
 typedef struct wire_Address {
@@ -138,14 +181,82 @@ typedef struct wire_ChannelId {
   struct wire_uint_8_list *internal;
 } wire_ChannelId;
 
+typedef struct wire_ChannelConfigUpdate {
+  uint32_t *forwarding_fee_proportional_millionths;
+  uint32_t *forwarding_fee_base_msat;
+  uint16_t *cltv_expiry_delta;
+  uint64_t *max_dust_htlc_exposure_msat;
+  uint64_t *force_close_avoidance_max_fee_satoshis;
+} wire_ChannelConfigUpdate;
+
+typedef struct wire_ChannelFilter_All {
+
+} wire_ChannelFilter_All;
+
+typedef struct wire_ChannelFilter_Counterparty {
+  struct wire_PublicKey *field0;
+} wire_ChannelFilter_Counterparty;
+
+typedef struct wire_ChannelFilter_Visibility {
+  int32_t field0;
+} wire_ChannelFilter_Visibility;
+
+typedef union ChannelFilterKind {
+  struct wire_ChannelFilter_All *All;
+  struct wire_ChannelFilter_Counterparty *Counterparty;
+  struct wire_ChannelFilter_Visibility *Visibility;
+} ChannelFilterKind;
+
+typedef struct wire_ChannelFilter {
+  int32_t tag;
+  union ChannelFilterKind *kind;
+} wire_ChannelFilter;
+
 typedef struct wire_Invoice {
   struct wire_uint_8_list *internal;
 } wire_Invoice;
 
+typedef struct wire_RetryStrategy_Attempts {
+  uint32_t field0;
+} wire_RetryStrategy_Attempts;
+
+typedef struct wire_RetryStrategy_TimeoutSecs {
+  uint64_t field0;
+} wire_RetryStrategy_TimeoutSecs;
+
+typedef union RetryStrategyKind {
+  struct wire_RetryStrategy_Attempts *Attempts;
+  struct wire_RetryStrategy_TimeoutSecs *TimeoutSecs;
+} RetryStrategyKind;
+
+typedef struct wire_RetryStrategy {
+  int32_t tag;
+  union RetryStrategyKind *kind;
+} wire_RetryStrategy;
+
 typedef struct wire_PaymentHash {
   struct wire_uint_8_list *internal;
 } wire_PaymentHash;
 
+typedef struct wire_list_payment_status {
+  int32_t *ptr;
+  int32_t len;
+} wire_list_payment_status;
+
+typedef struct wire_PaymentQuery {
+  int32_t *direction;
+  struct wire_list_payment_status *statuses;
+  uint64_t *created_from;
+  uint64_t *created_until;
+  uint64_t *min_amount_msat;
+  uint64_t *max_amount_msat;
+  struct wire_uint_8_list *label_contains;
+  int32_t *kind;
+  int32_t sort_order;
+  struct wire_uint_8_list *cursor;
+  uint32_t *limit;
+} wire_PaymentQuery;
+
 typedef struct DartCObject *WireSyncReturn;
 
 void store_dart_post_cobject(DartPostCObjectFnType ptr);
@@ -160,6 +271,8 @@ intptr_t init_frb_dart_api_dl(void *obj);
 
 void wire_generate_entropy_mnemonic(int64_t port_);
 
+void wire_default_channel_config(int64_t port_);
+
 void wire_build_node(int64_t port_,
                      struct wire_Config *config,
                      struct wire_ChainDataSourceConfig *chain_data_source_config,
@@ -199,6 +312,8 @@ void wire_send_all_to_onchain_address__method__NodePointer(int64_t port_,
 
 void wire_list_channels__method__NodePointer(int64_t port_, struct wire_NodePointer *that);
 
+void wire_list_closed_channels__method__NodePointer(int64_t port_, struct wire_NodePointer *that);
+
 void wire_connect__method__NodePointer(int64_t port_,
                                        struct wire_NodePointer *that,
                                        struct wire_PublicKey *node_id,
@@ -225,25 +340,40 @@ void wire_close_channel__method__NodePointer(int64_t port_,
                                              struct wire_ChannelId *channel_id,
                                              struct wire_PublicKey *counterparty_node_id);
 
+void wire_channel_config__method__NodePointer(int64_t port_,
+                                              struct wire_NodePointer *that,
+                                              struct wire_ChannelId *channel_id);
+
 void wire_update_channel_config__method__NodePointer(int64_t port_,
                                                      struct wire_NodePointer *that,
                                                      struct wire_ChannelId *channel_id,
                                                      struct wire_PublicKey *counterparty_node_id,
-                                                     struct wire_ChannelConfig *channel_config);
+                                                     struct wire_ChannelConfigUpdate *update);
+
+void wire_update_all_channel_configs__method__NodePointer(int64_t port_,
+                                                          struct wire_NodePointer *that,
+                                                          struct wire_ChannelConfigUpdate *update,
+                                                          struct wire_ChannelFilter *filter);
+
+void wire_fee_autopilot_proposals__method__NodePointer(int64_t port_,
+                                                       struct wire_NodePointer *that);
 
 void wire_send_payment__method__NodePointer(int64_t port_,
                                             struct wire_NodePointer *that,
-                                            struct wire_Invoice *invoice);
+                                            struct wire_Invoice *invoice,
+                                            struct wire_RetryStrategy *retry_strategy);
 
 void wire_send_payment_using_amount__method__NodePointer(int64_t port_,
                                                          struct wire_NodePointer *that,
                                                          struct wire_Invoice *invoice,
-                                                         uint64_t amount_msat);
+                                                         uint64_t amount_msat,
+                                                         struct wire_RetryStrategy *retry_strategy);
 
 void wire_send_spontaneous_payment__method__NodePointer(int64_t port_,
                                                         struct wire_NodePointer *that,
                                                         uint64_t amount_msat,
-                                                        struct wire_PublicKey *node_id);
+                                                        struct wire_PublicKey *node_id,
+                                                        struct wire_RetryStrategy *retry_strategy);
 
 void wire_receive_payment__method__NodePointer(int64_t port_,
                                                struct wire_NodePointer *that,
@@ -264,12 +394,31 @@ void wire_remove_payment__method__NodePointer(int64_t port_,
                                               struct wire_NodePointer *that,
                                               struct wire_PaymentHash *payment_hash);
 
+void wire_abandon_payment__method__NodePointer(int64_t port_,
+                                               struct wire_NodePointer *that,
+                                               struct wire_PaymentHash *payment_hash);
+
+void wire_set_payment_label__method__NodePointer(int64_t port_,
+                                                 struct wire_NodePointer *that,
+                                                 struct wire_PaymentHash *payment_hash,
+                                                 struct wire_uint_8_list *label);
+
 void wire_list_payments_with_filter__method__NodePointer(int64_t port_,
                                                          struct wire_NodePointer *that,
                                                          int32_t payment_direction);
 
+void wire_query_payments__method__NodePointer(int64_t port_,
+                                              struct wire_NodePointer *that,
+                                              struct wire_PaymentQuery *query);
+
 void wire_list_payments__method__NodePointer(int64_t port_, struct wire_NodePointer *that);
 
+void wire_export_payments__method__NodePointer(int64_t port_,
+                                               struct wire_NodePointer *that,
+                                               int32_t format,
+                                               uint64_t *created_from,
+                                               uint64_t *created_until);
+
 void wire_list_peers__method__NodePointer(int64_t port_, struct wire_NodePointer *that);
 
 void wire_sign_message__method__NodePointer(int64_t port_,
@@ -284,20 +433,32 @@ void wire_verify_signature__method__NodePointer(int64_t port_,
 
 struct wire_MutexNodeSqliteStore new_MutexNodeSqliteStore(void);
 
+struct wire_NodeStore new_NodeStore(void);
+
 struct wire_Address *new_box_autoadd_address_0(void);
 
 struct wire_ChainDataSourceConfig *new_box_autoadd_chain_data_source_config_0(void);
 
 struct wire_ChannelConfig *new_box_autoadd_channel_config_0(void);
 
+struct wire_ChannelConfigUpdate *new_box_autoadd_channel_config_update_0(void);
+
+struct wire_ChannelFilter *new_box_autoadd_channel_filter_0(void);
+
 struct wire_ChannelId *new_box_autoadd_channel_id_0(void);
 
+int32_t *new_box_autoadd_channel_visibility_0(int32_t value);
+
 struct wire_Config *new_box_autoadd_config_0(void);
 
 struct wire_EntropySourceConfig *new_box_autoadd_entropy_source_config_0(void);
 
+struct wire_FeeAutopilotConfig *new_box_autoadd_fee_autopilot_config_0(void);
+
 struct wire_GossipSourceConfig *new_box_autoadd_gossip_source_config_0(void);
 
+struct wire_InboundChannelPolicy *new_box_autoadd_inbound_channel_policy_0(void);
+
 struct wire_Invoice *new_box_autoadd_invoice_0(void);
 
 struct wire_Mnemonic *new_box_autoadd_mnemonic_0(void);
@@ -306,12 +467,26 @@ struct wire_NetAddress *new_box_autoadd_net_address_0(void);
 
 struct wire_NodePointer *new_box_autoadd_node_pointer_0(void);
 
+int32_t *new_box_autoadd_payment_direction_0(int32_t value);
+
 struct wire_PaymentHash *new_box_autoadd_payment_hash_0(void);
 
+int32_t *new_box_autoadd_payment_kind_0(int32_t value);
+
+struct wire_PaymentQuery *new_box_autoadd_payment_query_0(void);
+
 struct wire_PublicKey *new_box_autoadd_public_key_0(void);
 
+struct wire_RetryStrategy *new_box_autoadd_retry_strategy_0(void);
+
+uint16_t *new_box_autoadd_u16_0(uint16_t value);
+
+uint32_t *new_box_autoadd_u32_0(uint32_t value);
+
 uint64_t *new_box_autoadd_u64_0(uint64_t value);
 
+struct wire_list_payment_status *new_list_payment_status_0(int32_t len);
+
 struct wire_list_public_key *new_list_public_key_0(int32_t len);
 
 struct wire_uint_8_list *new_uint_8_list_0(int32_t len);
@@ -320,8 +495,16 @@ void drop_opaque_MutexNodeSqliteStore(const void *ptr);
 
 const void *share_opaque_MutexNodeSqliteStore(const void *ptr);
 
+void drop_opaque_NodeStore(const void *ptr);
+
+const void *share_opaque_NodeStore(const void *ptr);
+
 union ChainDataSourceConfigKind *inflate_ChainDataSourceConfig_Esplora(void);
 
+union ChannelFilterKind *inflate_ChannelFilter_Counterparty(void);
+
+union ChannelFilterKind *inflate_ChannelFilter_Visibility(void);
+
 union EntropySourceConfigKind *inflate_EntropySourceConfig_SeedFile(void);
 
 union EntropySourceConfigKind *inflate_EntropySourceConfig_SeedBytes(void);
@@ -334,11 +517,22 @@ union NetAddressKind *inflate_NetAddress_IPv4(void);
 
 union NetAddressKind *inflate_NetAddress_IPv6(void);
 
+union NetAddressKind *inflate_NetAddress_OnionV2(void);
+
+union NetAddressKind *inflate_NetAddress_OnionV3(void);
+
+union NetAddressKind *inflate_NetAddress_Hostname(void);
+
+union RetryStrategyKind *inflate_RetryStrategy_Attempts(void);
+
+union RetryStrategyKind *inflate_RetryStrategy_TimeoutSecs(void);
+
 void free_WireSyncReturn(WireSyncReturn ptr);
 
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
     dummy_var ^= ((int64_t) (void*) wire_generate_entropy_mnemonic);
+    dummy_var ^= ((int64_t) (void*) wire_default_channel_config);
     dummy_var ^= ((int64_t) (void*) wire_build_node);
     dummy_var ^= ((int64_t) (void*) wire_start__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_stop__method__NodePointer);
@@ -353,12 +547,16 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_send_to_onchain_address__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_send_all_to_onchain_address__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_list_channels__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_list_closed_channels__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_connect__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_disconnect__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_connect_open_channel__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_sync_wallets__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_close_channel__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_channel_config__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_update_channel_config__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_update_all_channel_configs__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_fee_autopilot_proposals__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_send_payment__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_send_payment_using_amount__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_send_spontaneous_payment__method__NodePointer);
@@ -366,37 +564,63 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_receive_variable_amount_payment__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_payment__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_remove_payment__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_abandon_payment__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_set_payment_label__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_list_payments_with_filter__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_query_payments__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_list_payments__method__NodePointer);
+    dummy_var ^= ((int64_t) (void*) wire_export_payments__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_list_peers__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_sign_message__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) wire_verify_signature__method__NodePointer);
     dummy_var ^= ((int64_t) (void*) new_MutexNodeSqliteStore);
+    dummy_var ^= ((int64_t) (void*) new_NodeStore);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_address_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_chain_data_source_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_channel_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_channel_config_update_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_channel_filter_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_channel_id_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_channel_visibility_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_entropy_source_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_fee_autopilot_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_gossip_source_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_inbound_channel_policy_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_invoice_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_mnemonic_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_net_address_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_node_pointer_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_payment_direction_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_payment_hash_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_payment_kind_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_payment_query_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_public_key_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_retry_strategy_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_u16_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_u32_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_u64_0);
+    dummy_var ^= ((int64_t) (void*) new_list_payment_status_0);
     dummy_var ^= ((int64_t) (void*) new_list_public_key_0);
     dummy_var ^= ((int64_t) (void*) new_uint_8_list_0);
     dummy_var ^= ((int64_t) (void*) drop_opaque_MutexNodeSqliteStore);
     dummy_var ^= ((int64_t) (void*) share_opaque_MutexNodeSqliteStore);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_NodeStore);
+    dummy_var ^= ((int64_t) (void*) share_opaque_NodeStore);
     dummy_var ^= ((int64_t) (void*) inflate_ChainDataSourceConfig_Esplora);
+    dummy_var ^= ((int64_t) (void*) inflate_ChannelFilter_Counterparty);
+    dummy_var ^= ((int64_t) (void*) inflate_ChannelFilter_Visibility);
     dummy_var ^= ((int64_t) (void*) inflate_EntropySourceConfig_SeedFile);
     dummy_var ^= ((int64_t) (void*) inflate_EntropySourceConfig_SeedBytes);
     dummy_var ^= ((int64_t) (void*) inflate_EntropySourceConfig_Bip39Mnemonic);
     dummy_var ^= ((int64_t) (void*) inflate_GossipSourceConfig_RapidGossipSync);
     dummy_var ^= ((int64_t) (void*) inflate_NetAddress_IPv4);
     dummy_var ^= ((int64_t) (void*) inflate_NetAddress_IPv6);
+    dummy_var ^= ((int64_t) (void*) inflate_NetAddress_OnionV2);
+    dummy_var ^= ((int64_t) (void*) inflate_NetAddress_OnionV3);
+    dummy_var ^= ((int64_t) (void*) inflate_NetAddress_Hostname);
+    dummy_var ^= ((int64_t) (void*) inflate_RetryStrategy_Attempts);
+    dummy_var ^= ((int64_t) (void*) inflate_RetryStrategy_TimeoutSecs);
     dummy_var ^= ((int64_t) (void*) free_WireSyncReturn);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     dummy_var ^= ((int64_t) (void*) get_dart_object);
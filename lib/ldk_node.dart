@@ -14,8 +14,18 @@ export './src/generated/bridge_definitions.dart'
         GossipSourceConfig_P2PNetwork,
         GossipSourceConfig_RapidGossipSync,
         MutexNodeSqliteStore,
+        NodeStore,
         NetAddress_IPv4,
         NetAddress_IPv6,
+        NetAddress_OnionV2,
+        NetAddress_OnionV3,
+        NetAddress_Hostname,
+        ChannelFilter_All,
+        ChannelFilter_Counterparty,
+        ChannelFilter_Visibility,
+        RetryStrategy_Attempts,
+        RetryStrategy_TimeoutSecs,
+        U8Array12,
         U8Array32,
         U8Array64,
         EntropySourceConfig_SeedFile;
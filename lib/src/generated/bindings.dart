@@ -39,6 +39,21 @@ class RustLdkNodeImpl implements RustLdkNode {
         argNames: [],
       );
 
+  Future<ChannelConfig> defaultChannelConfig({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_default_channel_config(port_),
+      parseSuccessData: _wire2api_channel_config,
+      constMeta: kDefaultChannelConfigConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDefaultChannelConfigConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "default_channel_config",
+        argNames: [],
+      );
+
   Future<NodePointer> buildNode(
       {required Config config, ChainDataSourceConfig? chainDataSourceConfig, EntropySourceConfig? entropySourceConfig, GossipSourceConfig? gossipSourceConfig, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_config(config);
@@ -270,6 +285,22 @@ class RustLdkNodeImpl implements RustLdkNode {
         argNames: ["that"],
       );
 
+  Future<List<ClosedChannelDetails>> listClosedChannelsMethodNodePointer({required NodePointer that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_closed_channels__method__NodePointer(port_, arg0),
+      parseSuccessData: _wire2api_list_closed_channel_details,
+      constMeta: kListClosedChannelsMethodNodePointerConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListClosedChannelsMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_closed_channels__method__NodePointer",
+        argNames: ["that"],
+      );
+
   Future<void> connectMethodNodePointer({required NodePointer that, required PublicKey nodeId, required NetAddress address, required bool persist, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     var arg1 = _platform.api2wire_box_autoadd_public_key(nodeId);
@@ -370,77 +401,131 @@ class RustLdkNodeImpl implements RustLdkNode {
         argNames: ["that", "channelId", "counterpartyNodeId"],
       );
 
+  Future<ChannelConfig?> channelConfigMethodNodePointer({required NodePointer that, required ChannelId channelId, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    var arg1 = _platform.api2wire_box_autoadd_channel_id(channelId);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_channel_config__method__NodePointer(port_, arg0, arg1),
+      parseSuccessData: _wire2api_opt_box_autoadd_channel_config,
+      constMeta: kChannelConfigMethodNodePointerConstMeta,
+      argValues: [that, channelId],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kChannelConfigMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "channel_config__method__NodePointer",
+        argNames: ["that", "channelId"],
+      );
+
   Future<void> updateChannelConfigMethodNodePointer(
-      {required NodePointer that, required ChannelId channelId, required PublicKey counterpartyNodeId, required ChannelConfig channelConfig, dynamic hint}) {
+      {required NodePointer that, required ChannelId channelId, required PublicKey counterpartyNodeId, required ChannelConfigUpdate update, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     var arg1 = _platform.api2wire_box_autoadd_channel_id(channelId);
     var arg2 = _platform.api2wire_box_autoadd_public_key(counterpartyNodeId);
-    var arg3 = _platform.api2wire_box_autoadd_channel_config(channelConfig);
+    var arg3 = _platform.api2wire_box_autoadd_channel_config_update(update);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_update_channel_config__method__NodePointer(port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_unit,
       constMeta: kUpdateChannelConfigMethodNodePointerConstMeta,
-      argValues: [that, channelId, counterpartyNodeId, channelConfig],
+      argValues: [that, channelId, counterpartyNodeId, update],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kUpdateChannelConfigMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "update_channel_config__method__NodePointer",
-        argNames: ["that", "channelId", "counterpartyNodeId", "channelConfig"],
+        argNames: ["that", "channelId", "counterpartyNodeId", "update"],
+      );
+
+  Future<List<ChannelConfigUpdateResult>> updateAllChannelConfigsMethodNodePointer({required NodePointer that, required ChannelConfigUpdate update, required ChannelFilter filter, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    var arg1 = _platform.api2wire_box_autoadd_channel_config_update(update);
+    var arg2 = _platform.api2wire_box_autoadd_channel_filter(filter);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_update_all_channel_configs__method__NodePointer(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_list_channel_config_update_result,
+      constMeta: kUpdateAllChannelConfigsMethodNodePointerConstMeta,
+      argValues: [that, update, filter],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kUpdateAllChannelConfigsMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "update_all_channel_configs__method__NodePointer",
+        argNames: ["that", "update", "filter"],
+      );
+
+  Future<List<ChannelFeeProposal>> feeAutopilotProposalsMethodNodePointer({required NodePointer that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_fee_autopilot_proposals__method__NodePointer(port_, arg0),
+      parseSuccessData: _wire2api_list_channel_fee_proposal,
+      constMeta: kFeeAutopilotProposalsMethodNodePointerConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFeeAutopilotProposalsMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fee_autopilot_proposals__method__NodePointer",
+        argNames: ["that"],
       );
 
-  Future<PaymentHash> sendPaymentMethodNodePointer({required NodePointer that, required Invoice invoice, dynamic hint}) {
+  Future<PaymentHash> sendPaymentMethodNodePointer({required NodePointer that, required Invoice invoice, RetryStrategy? retryStrategy, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     var arg1 = _platform.api2wire_box_autoadd_invoice(invoice);
+    var arg2 = _platform.api2wire_opt_box_autoadd_retry_strategy(retryStrategy);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_send_payment__method__NodePointer(port_, arg0, arg1),
+      callFfi: (port_) => _platform.inner.wire_send_payment__method__NodePointer(port_, arg0, arg1, arg2),
       parseSuccessData: _wire2api_payment_hash,
       constMeta: kSendPaymentMethodNodePointerConstMeta,
-      argValues: [that, invoice],
+      argValues: [that, invoice, retryStrategy],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kSendPaymentMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "send_payment__method__NodePointer",
-        argNames: ["that", "invoice"],
+        argNames: ["that", "invoice", "retryStrategy"],
       );
 
-  Future<PaymentHash> sendPaymentUsingAmountMethodNodePointer({required NodePointer that, required Invoice invoice, required int amountMsat, dynamic hint}) {
+  Future<PaymentHash> sendPaymentUsingAmountMethodNodePointer({required NodePointer that, required Invoice invoice, required int amountMsat, RetryStrategy? retryStrategy, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     var arg1 = _platform.api2wire_box_autoadd_invoice(invoice);
     var arg2 = _platform.api2wire_u64(amountMsat);
+    var arg3 = _platform.api2wire_opt_box_autoadd_retry_strategy(retryStrategy);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_send_payment_using_amount__method__NodePointer(port_, arg0, arg1, arg2),
+      callFfi: (port_) => _platform.inner.wire_send_payment_using_amount__method__NodePointer(port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_payment_hash,
       constMeta: kSendPaymentUsingAmountMethodNodePointerConstMeta,
-      argValues: [that, invoice, amountMsat],
+      argValues: [that, invoice, amountMsat, retryStrategy],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kSendPaymentUsingAmountMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "send_payment_using_amount__method__NodePointer",
-        argNames: ["that", "invoice", "amountMsat"],
+        argNames: ["that", "invoice", "amountMsat", "retryStrategy"],
       );
 
-  Future<PaymentHash> sendSpontaneousPaymentMethodNodePointer({required NodePointer that, required int amountMsat, required PublicKey nodeId, dynamic hint}) {
+  Future<PaymentHash> sendSpontaneousPaymentMethodNodePointer({required NodePointer that, required int amountMsat, required PublicKey nodeId, RetryStrategy? retryStrategy, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     var arg1 = _platform.api2wire_u64(amountMsat);
     var arg2 = _platform.api2wire_box_autoadd_public_key(nodeId);
+    var arg3 = _platform.api2wire_opt_box_autoadd_retry_strategy(retryStrategy);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_send_spontaneous_payment__method__NodePointer(port_, arg0, arg1, arg2),
+      callFfi: (port_) => _platform.inner.wire_send_spontaneous_payment__method__NodePointer(port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_payment_hash,
       constMeta: kSendSpontaneousPaymentMethodNodePointerConstMeta,
-      argValues: [that, amountMsat, nodeId],
+      argValues: [that, amountMsat, nodeId, retryStrategy],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta get kSendSpontaneousPaymentMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
         debugName: "send_spontaneous_payment__method__NodePointer",
-        argNames: ["that", "amountMsat", "nodeId"],
+        argNames: ["that", "amountMsat", "nodeId", "retryStrategy"],
       );
 
   Future<Invoice> receivePaymentMethodNodePointer({required NodePointer that, required int amountMsat, required String description, required int expirySecs, dynamic hint}) {
@@ -514,6 +599,41 @@ class RustLdkNodeImpl implements RustLdkNode {
         argNames: ["that", "paymentHash"],
       );
 
+  Future<void> abandonPaymentMethodNodePointer({required NodePointer that, required PaymentHash paymentHash, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    var arg1 = _platform.api2wire_box_autoadd_payment_hash(paymentHash);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_abandon_payment__method__NodePointer(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kAbandonPaymentMethodNodePointerConstMeta,
+      argValues: [that, paymentHash],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAbandonPaymentMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "abandon_payment__method__NodePointer",
+        argNames: ["that", "paymentHash"],
+      );
+
+  Future<void> setPaymentLabelMethodNodePointer({required NodePointer that, required PaymentHash paymentHash, String? label, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    var arg1 = _platform.api2wire_box_autoadd_payment_hash(paymentHash);
+    var arg2 = _platform.api2wire_opt_String(label);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_payment_label__method__NodePointer(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      constMeta: kSetPaymentLabelMethodNodePointerConstMeta,
+      argValues: [that, paymentHash, label],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetPaymentLabelMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_payment_label__method__NodePointer",
+        argNames: ["that", "paymentHash", "label"],
+      );
+
   Future<List<PaymentDetails>> listPaymentsWithFilterMethodNodePointer({required NodePointer that, required PaymentDirection paymentDirection, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     var arg1 = api2wire_payment_direction(paymentDirection);
@@ -531,6 +651,23 @@ class RustLdkNodeImpl implements RustLdkNode {
         argNames: ["that", "paymentDirection"],
       );
 
+  Future<PaymentPage> queryPaymentsMethodNodePointer({required NodePointer that, required PaymentQuery query, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    var arg1 = _platform.api2wire_box_autoadd_payment_query(query);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_query_payments__method__NodePointer(port_, arg0, arg1),
+      parseSuccessData: _wire2api_payment_page,
+      constMeta: kQueryPaymentsMethodNodePointerConstMeta,
+      argValues: [that, query],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kQueryPaymentsMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "query_payments__method__NodePointer",
+        argNames: ["that", "query"],
+      );
+
   Future<List<PaymentDetails>> listPaymentsMethodNodePointer({required NodePointer that, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -547,6 +684,25 @@ class RustLdkNodeImpl implements RustLdkNode {
         argNames: ["that"],
       );
 
+  Future<String> exportPaymentsMethodNodePointer({required NodePointer that, required ExportFormat format, int? createdFrom, int? createdUntil, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
+    var arg1 = api2wire_export_format(format);
+    var arg2 = _platform.api2wire_opt_box_autoadd_u64(createdFrom);
+    var arg3 = _platform.api2wire_opt_box_autoadd_u64(createdUntil);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_export_payments__method__NodePointer(port_, arg0, arg1, arg2, arg3),
+      parseSuccessData: _wire2api_String,
+      constMeta: kExportPaymentsMethodNodePointerConstMeta,
+      argValues: [that, format, createdFrom, createdUntil],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportPaymentsMethodNodePointerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_payments__method__NodePointer",
+        argNames: ["that", "format", "createdFrom", "createdUntil"],
+      );
+
   Future<List<PeerDetails>> listPeersMethodNodePointer({required NodePointer that, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_node_pointer(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -603,6 +759,10 @@ class RustLdkNodeImpl implements RustLdkNode {
   ShareFnType get shareOpaqueMutexNodeSqliteStore => _platform.inner.share_opaque_MutexNodeSqliteStore;
   OpaqueTypeFinalizer get MutexNodeSqliteStoreFinalizer => _platform.MutexNodeSqliteStoreFinalizer;
 
+  DropFnType get dropOpaqueNodeStore => _platform.inner.drop_opaque_NodeStore;
+  ShareFnType get shareOpaqueNodeStore => _platform.inner.share_opaque_NodeStore;
+  OpaqueTypeFinalizer get NodeStoreFinalizer => _platform.NodeStoreFinalizer;
+
   void dispose() {
     _platform.dispose();
   }
@@ -612,6 +772,10 @@ class RustLdkNodeImpl implements RustLdkNode {
     return MutexNodeSqliteStore.fromRaw(raw[0], raw[1], this);
   }
 
+  NodeStore _wire2api_NodeStore(dynamic raw) {
+    return NodeStore.fromRaw(raw[0], raw[1], this);
+  }
+
   String _wire2api_String(dynamic raw) {
     return raw as String;
   }
@@ -628,6 +792,10 @@ class RustLdkNodeImpl implements RustLdkNode {
     return raw as bool;
   }
 
+  ChannelConfig _wire2api_box_autoadd_channel_config(dynamic raw) {
+    return _wire2api_channel_config(raw);
+  }
+
   ChannelId _wire2api_box_autoadd_channel_id(dynamic raw) {
     return _wire2api_channel_id(raw);
   }
@@ -636,6 +804,10 @@ class RustLdkNodeImpl implements RustLdkNode {
     return _wire2api_event(raw);
   }
 
+  Invoice _wire2api_box_autoadd_invoice(dynamic raw) {
+    return _wire2api_invoice(raw);
+  }
+
   NetAddress _wire2api_box_autoadd_net_address(dynamic raw) {
     return _wire2api_net_address(raw);
   }
@@ -664,6 +836,10 @@ class RustLdkNodeImpl implements RustLdkNode {
     return _wire2api_public_key(raw);
   }
 
+  Txid _wire2api_box_autoadd_txid(dynamic raw) {
+    return _wire2api_txid(raw);
+  }
+
   int _wire2api_box_autoadd_u32(dynamic raw) {
     return raw as int;
   }
@@ -676,13 +852,39 @@ class RustLdkNodeImpl implements RustLdkNode {
     return _wire2api_user_channel_id(raw);
   }
 
+  ChannelCloseType _wire2api_channel_close_type(dynamic raw) {
+    return ChannelCloseType.values[raw as int];
+  }
+
+  ChannelConfig _wire2api_channel_config(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ChannelConfig(
+      forwardingFeeProportionalMillionths: _wire2api_u32(arr[0]),
+      forwardingFeeBaseMsat: _wire2api_u32(arr[1]),
+      cltvExpiryDelta: _wire2api_u16(arr[2]),
+      maxDustHtlcExposureMsat: _wire2api_u64(arr[3]),
+      forceCloseAvoidanceMaxFeeSatoshis: _wire2api_u64(arr[4]),
+    );
+  }
+
+  ChannelConfigUpdateResult _wire2api_channel_config_update_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ChannelConfigUpdateResult(
+      channelId: _wire2api_channel_id(arr[0]),
+      counterpartyNodeId: _wire2api_public_key(arr[1]),
+      error: _wire2api_opt_String(arr[2]),
+    );
+  }
+
   ChannelDetails _wire2api_channel_details(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 16) throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
     return ChannelDetails(
       channelId: _wire2api_channel_id(arr[0]),
       counterpartyNodeId: _wire2api_public_key(arr[1]),
-      fundingTxo: _wire2api_opt_String(arr[2]),
+      fundingTxo: _wire2api_opt_box_autoadd_out_point(arr[2]),
       channelValueSats: _wire2api_u64(arr[3]),
       unspendablePunishmentReserve: _wire2api_opt_box_autoadd_u64(arr[4]),
       userChannelId: _wire2api_user_channel_id(arr[5]),
@@ -696,6 +898,22 @@ class RustLdkNodeImpl implements RustLdkNode {
       isChannelReady: _wire2api_bool(arr[13]),
       isUsable: _wire2api_bool(arr[14]),
       isPublic: _wire2api_bool(arr[15]),
+      config: _wire2api_opt_box_autoadd_channel_config(arr[16]),
+    );
+  }
+
+  ChannelFeeProposal _wire2api_channel_fee_proposal(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ChannelFeeProposal(
+      channelId: _wire2api_channel_id(arr[0]),
+      counterpartyNodeId: _wire2api_public_key(arr[1]),
+      outboundCapacityMsat: _wire2api_u64(arr[2]),
+      inboundCapacityMsat: _wire2api_u64(arr[3]),
+      currentBaseFeeMsat: _wire2api_u32(arr[4]),
+      currentProportionalMillionths: _wire2api_u32(arr[5]),
+      proposedBaseFeeMsat: _wire2api_u32(arr[6]),
+      proposedProportionalMillionths: _wire2api_u32(arr[7]),
     );
   }
 
@@ -707,6 +925,23 @@ class RustLdkNodeImpl implements RustLdkNode {
     );
   }
 
+  ClosedChannelDetails _wire2api_closed_channel_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ClosedChannelDetails(
+      channelId: _wire2api_channel_id(arr[0]),
+      userChannelId: _wire2api_user_channel_id(arr[1]),
+      counterpartyNodeId: _wire2api_public_key(arr[2]),
+      channelValueSats: _wire2api_u64(arr[3]),
+      finalBalanceMsat: _wire2api_u64(arr[4]),
+      fundingTxo: _wire2api_opt_box_autoadd_out_point(arr[5]),
+      closingTxid: _wire2api_opt_box_autoadd_txid(arr[6]),
+      closeType: _wire2api_channel_close_type(arr[7]),
+      openedAt: _wire2api_u64(arr[8]),
+      closedAt: _wire2api_u64(arr[9]),
+    );
+  }
+
   Event _wire2api_event(dynamic raw) {
     switch (raw[0]) {
       case 0:
@@ -757,10 +992,22 @@ class RustLdkNodeImpl implements RustLdkNode {
     );
   }
 
+  List<ChannelConfigUpdateResult> _wire2api_list_channel_config_update_result(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_channel_config_update_result).toList();
+  }
+
   List<ChannelDetails> _wire2api_list_channel_details(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_channel_details).toList();
   }
 
+  List<ChannelFeeProposal> _wire2api_list_channel_fee_proposal(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_channel_fee_proposal).toList();
+  }
+
+  List<ClosedChannelDetails> _wire2api_list_closed_channel_details(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_closed_channel_details).toList();
+  }
+
   List<PaymentDetails> _wire2api_list_payment_details(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_payment_details).toList();
   }
@@ -789,6 +1036,22 @@ class RustLdkNodeImpl implements RustLdkNode {
           addr: _wire2api_String(raw[1]),
           port: _wire2api_u16(raw[2]),
         );
+      case 2:
+        return NetAddress_OnionV2(
+          addr: _wire2api_u8_array_12(raw[1]),
+        );
+      case 3:
+        return NetAddress_OnionV3(
+          pubkey: _wire2api_u8_array_32(raw[1]),
+          checksum: _wire2api_u16(raw[2]),
+          version: _wire2api_u8(raw[3]),
+          port: _wire2api_u16(raw[4]),
+        );
+      case 4:
+        return NetAddress_Hostname(
+          host: _wire2api_String(raw[1]),
+          port: _wire2api_u16(raw[2]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -796,10 +1059,11 @@ class RustLdkNodeImpl implements RustLdkNode {
 
   NodePointer _wire2api_node_pointer(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
     return NodePointer(
       bridge: this,
       field0: _wire2api_MutexNodeSqliteStore(arr[0]),
+      field1: _wire2api_NodeStore(arr[1]),
     );
   }
 
@@ -807,14 +1071,26 @@ class RustLdkNodeImpl implements RustLdkNode {
     return raw == null ? null : _wire2api_String(raw);
   }
 
+  ChannelConfig? _wire2api_opt_box_autoadd_channel_config(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_channel_config(raw);
+  }
+
   Event? _wire2api_opt_box_autoadd_event(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_event(raw);
   }
 
+  Invoice? _wire2api_opt_box_autoadd_invoice(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_invoice(raw);
+  }
+
   NetAddress? _wire2api_opt_box_autoadd_net_address(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_net_address(raw);
   }
 
+  OutPoint? _wire2api_opt_box_autoadd_out_point(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_out_point(raw);
+  }
+
   PaymentDetails? _wire2api_opt_box_autoadd_payment_details(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_payment_details(raw);
   }
@@ -827,6 +1103,14 @@ class RustLdkNodeImpl implements RustLdkNode {
     return raw == null ? null : _wire2api_box_autoadd_payment_secret(raw);
   }
 
+  PublicKey? _wire2api_opt_box_autoadd_public_key(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_public_key(raw);
+  }
+
+  Txid? _wire2api_opt_box_autoadd_txid(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_txid(raw);
+  }
+
   int? _wire2api_opt_box_autoadd_u32(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_u32(raw);
   }
@@ -846,7 +1130,7 @@ class RustLdkNodeImpl implements RustLdkNode {
 
   PaymentDetails _wire2api_payment_details(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
     return PaymentDetails(
       hash: _wire2api_payment_hash(arr[0]),
       preimage: _wire2api_opt_box_autoadd_payment_preimage(arr[1]),
@@ -854,6 +1138,15 @@ class RustLdkNodeImpl implements RustLdkNode {
       amountMsat: _wire2api_opt_box_autoadd_u64(arr[3]),
       direction: _wire2api_payment_direction(arr[4]),
       status: _wire2api_payment_status(arr[5]),
+      kind: _wire2api_payment_kind(arr[6]),
+      attempts: _wire2api_u32(arr[7]),
+      createdAt: _wire2api_u64(arr[8]),
+      updatedAt: _wire2api_u64(arr[9]),
+      feePaidMsat: _wire2api_opt_box_autoadd_u64(arr[10]),
+      bolt11: _wire2api_opt_box_autoadd_invoice(arr[11]),
+      description: _wire2api_opt_String(arr[12]),
+      label: _wire2api_opt_String(arr[13]),
+      counterpartyNodeId: _wire2api_opt_box_autoadd_public_key(arr[14]),
     );
   }
 
@@ -869,6 +1162,20 @@ class RustLdkNodeImpl implements RustLdkNode {
     );
   }
 
+  PaymentKind _wire2api_payment_kind(dynamic raw) {
+    return PaymentKind.values[raw as int];
+  }
+
+  PaymentPage _wire2api_payment_page(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PaymentPage(
+      payments: _wire2api_list_payment_details(arr[0]),
+      nextCursor: _wire2api_opt_String(arr[1]),
+      totalCount: _wire2api_u64(arr[2]),
+    );
+  }
+
   PaymentPreimage _wire2api_payment_preimage(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -931,6 +1238,10 @@ class RustLdkNodeImpl implements RustLdkNode {
     return raw as int;
   }
 
+  U8Array12 _wire2api_u8_array_12(dynamic raw) {
+    return U8Array12(_wire2api_uint_8_list(raw));
+  }
+
   U8Array32 _wire2api_u8_array_32(dynamic raw) {
     return U8Array32(_wire2api_uint_8_list(raw));
   }
@@ -959,6 +1270,16 @@ bool api2wire_bool(bool raw) {
   return raw;
 }
 
+@protected
+int api2wire_channel_visibility(ChannelVisibility raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_export_format(ExportFormat raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_i32(int raw) {
   return raw;
@@ -979,6 +1300,21 @@ int api2wire_payment_direction(PaymentDirection raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_payment_kind(PaymentKind raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_payment_sort_order(PaymentSortOrder raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_payment_status(PaymentStatus raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_u16(int raw) {
   return raw;
@@ -1008,6 +1344,13 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  wire_NodeStore api2wire_NodeStore(NodeStore raw) {
+    final ptr = inner.new_NodeStore();
+    _api_fill_to_wire_NodeStore(raw, ptr);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
     return api2wire_uint_8_list(utf8.encoder.convert(raw));
@@ -1034,6 +1377,20 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_ChannelConfigUpdate> api2wire_box_autoadd_channel_config_update(ChannelConfigUpdate raw) {
+    final ptr = inner.new_box_autoadd_channel_config_update_0();
+    _api_fill_to_wire_channel_config_update(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ChannelFilter> api2wire_box_autoadd_channel_filter(ChannelFilter raw) {
+    final ptr = inner.new_box_autoadd_channel_filter_0();
+    _api_fill_to_wire_channel_filter(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ChannelId> api2wire_box_autoadd_channel_id(ChannelId raw) {
     final ptr = inner.new_box_autoadd_channel_id_0();
@@ -1041,6 +1398,11 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_channel_visibility(ChannelVisibility raw) {
+    return inner.new_box_autoadd_channel_visibility_0(api2wire_channel_visibility(raw));
+  }
+
   @protected
   ffi.Pointer<wire_Config> api2wire_box_autoadd_config(Config raw) {
     final ptr = inner.new_box_autoadd_config_0();
@@ -1055,6 +1417,13 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_FeeAutopilotConfig> api2wire_box_autoadd_fee_autopilot_config(FeeAutopilotConfig raw) {
+    final ptr = inner.new_box_autoadd_fee_autopilot_config_0();
+    _api_fill_to_wire_fee_autopilot_config(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_GossipSourceConfig> api2wire_box_autoadd_gossip_source_config(GossipSourceConfig raw) {
     final ptr = inner.new_box_autoadd_gossip_source_config_0();
@@ -1062,6 +1431,13 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_InboundChannelPolicy> api2wire_box_autoadd_inbound_channel_policy(InboundChannelPolicy raw) {
+    final ptr = inner.new_box_autoadd_inbound_channel_policy_0();
+    _api_fill_to_wire_inbound_channel_policy(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_Invoice> api2wire_box_autoadd_invoice(Invoice raw) {
     final ptr = inner.new_box_autoadd_invoice_0();
@@ -1090,6 +1466,11 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_payment_direction(PaymentDirection raw) {
+    return inner.new_box_autoadd_payment_direction_0(api2wire_payment_direction(raw));
+  }
+
   @protected
   ffi.Pointer<wire_PaymentHash> api2wire_box_autoadd_payment_hash(PaymentHash raw) {
     final ptr = inner.new_box_autoadd_payment_hash_0();
@@ -1097,6 +1478,18 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_payment_kind(PaymentKind raw) {
+    return inner.new_box_autoadd_payment_kind_0(api2wire_payment_kind(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_PaymentQuery> api2wire_box_autoadd_payment_query(PaymentQuery raw) {
+    final ptr = inner.new_box_autoadd_payment_query_0();
+    _api_fill_to_wire_payment_query(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_PublicKey> api2wire_box_autoadd_public_key(PublicKey raw) {
     final ptr = inner.new_box_autoadd_public_key_0();
@@ -1104,11 +1497,37 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_RetryStrategy> api2wire_box_autoadd_retry_strategy(RetryStrategy raw) {
+    final ptr = inner.new_box_autoadd_retry_strategy_0();
+    _api_fill_to_wire_retry_strategy(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> api2wire_box_autoadd_u16(int raw) {
+    return inner.new_box_autoadd_u16_0(api2wire_u16(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
+    return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
+  }
+
   @protected
   ffi.Pointer<ffi.Uint64> api2wire_box_autoadd_u64(int raw) {
     return inner.new_box_autoadd_u64_0(api2wire_u64(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_list_payment_status> api2wire_list_payment_status(List<PaymentStatus> raw) {
+    final ans = inner.new_list_payment_status_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = api2wire_payment_status(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_list_public_key> api2wire_list_public_key(List<PublicKey> raw) {
     final ans = inner.new_list_public_key_0(raw.length);
@@ -1133,21 +1552,61 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_channel_config(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_channel_visibility(ChannelVisibility? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_channel_visibility(raw);
+  }
+
   @protected
   ffi.Pointer<wire_EntropySourceConfig> api2wire_opt_box_autoadd_entropy_source_config(EntropySourceConfig? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_entropy_source_config(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_FeeAutopilotConfig> api2wire_opt_box_autoadd_fee_autopilot_config(FeeAutopilotConfig? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_fee_autopilot_config(raw);
+  }
+
   @protected
   ffi.Pointer<wire_GossipSourceConfig> api2wire_opt_box_autoadd_gossip_source_config(GossipSourceConfig? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_gossip_source_config(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_InboundChannelPolicy> api2wire_opt_box_autoadd_inbound_channel_policy(InboundChannelPolicy? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_inbound_channel_policy(raw);
+  }
+
   @protected
   ffi.Pointer<wire_NetAddress> api2wire_opt_box_autoadd_net_address(NetAddress? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_net_address(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_payment_direction(PaymentDirection? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_payment_direction(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_payment_kind(PaymentKind? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_payment_kind(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_RetryStrategy> api2wire_opt_box_autoadd_retry_strategy(RetryStrategy? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_retry_strategy(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> api2wire_opt_box_autoadd_u16(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u16(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint64> api2wire_opt_box_autoadd_u64(int? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_u64(raw);
@@ -1158,6 +1617,13 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     return raw;
   }
 
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_u8_array_12(U8Array12 raw) {
+    final ans = inner.new_uint_8_list_0(12);
+    ans.ref.ptr.asTypedList(12).setAll(0, raw);
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_uint_8_list> api2wire_u8_array_32(U8Array32 raw) {
     final ans = inner.new_uint_8_list_0(32);
@@ -1182,12 +1648,18 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
 
   late final OpaqueTypeFinalizer _MutexNodeSqliteStoreFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_MutexNodeSqliteStorePtr);
   OpaqueTypeFinalizer get MutexNodeSqliteStoreFinalizer => _MutexNodeSqliteStoreFinalizer;
+  late final OpaqueTypeFinalizer _NodeStoreFinalizer = OpaqueTypeFinalizer(inner._drop_opaque_NodeStorePtr);
+  OpaqueTypeFinalizer get NodeStoreFinalizer => _NodeStoreFinalizer;
 // Section: api_fill_to_wire
 
   void _api_fill_to_wire_MutexNodeSqliteStore(MutexNodeSqliteStore apiObj, wire_MutexNodeSqliteStore wireObj) {
     wireObj.ptr = apiObj.shareOrMove();
   }
 
+  void _api_fill_to_wire_NodeStore(NodeStore apiObj, wire_NodeStore wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
   void _api_fill_to_wire_address(Address apiObj, wire_Address wireObj) {
     wireObj.internal = api2wire_String(apiObj.internal);
   }
@@ -1204,11 +1676,19 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     _api_fill_to_wire_channel_config(apiObj, wireObj.ref);
   }
 
-  void _api_fill_to_wire_box_autoadd_channel_id(ChannelId apiObj, ffi.Pointer<wire_ChannelId> wireObj) {
-    _api_fill_to_wire_channel_id(apiObj, wireObj.ref);
+  void _api_fill_to_wire_box_autoadd_channel_config_update(ChannelConfigUpdate apiObj, ffi.Pointer<wire_ChannelConfigUpdate> wireObj) {
+    _api_fill_to_wire_channel_config_update(apiObj, wireObj.ref);
   }
 
-  void _api_fill_to_wire_box_autoadd_config(Config apiObj, ffi.Pointer<wire_Config> wireObj) {
+  void _api_fill_to_wire_box_autoadd_channel_filter(ChannelFilter apiObj, ffi.Pointer<wire_ChannelFilter> wireObj) {
+    _api_fill_to_wire_channel_filter(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_channel_id(ChannelId apiObj, ffi.Pointer<wire_ChannelId> wireObj) {
+    _api_fill_to_wire_channel_id(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_config(Config apiObj, ffi.Pointer<wire_Config> wireObj) {
     _api_fill_to_wire_config(apiObj, wireObj.ref);
   }
 
@@ -1216,10 +1696,18 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     _api_fill_to_wire_entropy_source_config(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_fee_autopilot_config(FeeAutopilotConfig apiObj, ffi.Pointer<wire_FeeAutopilotConfig> wireObj) {
+    _api_fill_to_wire_fee_autopilot_config(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_gossip_source_config(GossipSourceConfig apiObj, ffi.Pointer<wire_GossipSourceConfig> wireObj) {
     _api_fill_to_wire_gossip_source_config(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_inbound_channel_policy(InboundChannelPolicy apiObj, ffi.Pointer<wire_InboundChannelPolicy> wireObj) {
+    _api_fill_to_wire_inbound_channel_policy(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_invoice(Invoice apiObj, ffi.Pointer<wire_Invoice> wireObj) {
     _api_fill_to_wire_invoice(apiObj, wireObj.ref);
   }
@@ -1240,10 +1728,18 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     _api_fill_to_wire_payment_hash(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_payment_query(PaymentQuery apiObj, ffi.Pointer<wire_PaymentQuery> wireObj) {
+    _api_fill_to_wire_payment_query(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_public_key(PublicKey apiObj, ffi.Pointer<wire_PublicKey> wireObj) {
     _api_fill_to_wire_public_key(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_retry_strategy(RetryStrategy apiObj, ffi.Pointer<wire_RetryStrategy> wireObj) {
+    _api_fill_to_wire_retry_strategy(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_chain_data_source_config(ChainDataSourceConfig apiObj, wire_ChainDataSourceConfig wireObj) {
     if (apiObj is ChainDataSourceConfig_Esplora) {
       var pre_field0 = api2wire_String(apiObj.field0);
@@ -1262,6 +1758,35 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     wireObj.force_close_avoidance_max_fee_satoshis = api2wire_u64(apiObj.forceCloseAvoidanceMaxFeeSatoshis);
   }
 
+  void _api_fill_to_wire_channel_config_update(ChannelConfigUpdate apiObj, wire_ChannelConfigUpdate wireObj) {
+    wireObj.forwarding_fee_proportional_millionths = api2wire_opt_box_autoadd_u32(apiObj.forwardingFeeProportionalMillionths);
+    wireObj.forwarding_fee_base_msat = api2wire_opt_box_autoadd_u32(apiObj.forwardingFeeBaseMsat);
+    wireObj.cltv_expiry_delta = api2wire_opt_box_autoadd_u16(apiObj.cltvExpiryDelta);
+    wireObj.max_dust_htlc_exposure_msat = api2wire_opt_box_autoadd_u64(apiObj.maxDustHtlcExposureMsat);
+    wireObj.force_close_avoidance_max_fee_satoshis = api2wire_opt_box_autoadd_u64(apiObj.forceCloseAvoidanceMaxFeeSatoshis);
+  }
+
+  void _api_fill_to_wire_channel_filter(ChannelFilter apiObj, wire_ChannelFilter wireObj) {
+    if (apiObj is ChannelFilter_All) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is ChannelFilter_Counterparty) {
+      var pre_field0 = api2wire_box_autoadd_public_key(apiObj.field0);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_ChannelFilter_Counterparty();
+      wireObj.kind.ref.Counterparty.ref.field0 = pre_field0;
+      return;
+    }
+    if (apiObj is ChannelFilter_Visibility) {
+      var pre_field0 = api2wire_channel_visibility(apiObj.field0);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_ChannelFilter_Visibility();
+      wireObj.kind.ref.Visibility.ref.field0 = pre_field0;
+      return;
+    }
+  }
+
   void _api_fill_to_wire_channel_id(ChannelId apiObj, wire_ChannelId wireObj) {
     wireObj.internal = api2wire_u8_array_32(apiObj.internal);
   }
@@ -1276,6 +1801,8 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     wireObj.log_level = api2wire_log_level(apiObj.logLevel);
     wireObj.listening_address = api2wire_opt_box_autoadd_net_address(apiObj.listeningAddress);
     wireObj.default_cltv_expiry_delta = api2wire_u32(apiObj.defaultCltvExpiryDelta);
+    wireObj.inbound_channel_policy = api2wire_opt_box_autoadd_inbound_channel_policy(apiObj.inboundChannelPolicy);
+    wireObj.fee_autopilot = api2wire_opt_box_autoadd_fee_autopilot_config(apiObj.feeAutopilot);
   }
 
   void _api_fill_to_wire_entropy_source_config(EntropySourceConfig apiObj, wire_EntropySourceConfig wireObj) {
@@ -1304,6 +1831,16 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     }
   }
 
+  void _api_fill_to_wire_fee_autopilot_config(FeeAutopilotConfig apiObj, wire_FeeAutopilotConfig wireObj) {
+    wireObj.min_base_fee_msat = api2wire_u32(apiObj.minBaseFeeMsat);
+    wireObj.max_base_fee_msat = api2wire_u32(apiObj.maxBaseFeeMsat);
+    wireObj.min_proportional_millionths = api2wire_u32(apiObj.minProportionalMillionths);
+    wireObj.max_proportional_millionths = api2wire_u32(apiObj.maxProportionalMillionths);
+    wireObj.target_outbound_percent = api2wire_u8(apiObj.targetOutboundPercent);
+    wireObj.interval_secs = api2wire_u64(apiObj.intervalSecs);
+    wireObj.dry_run = api2wire_bool(apiObj.dryRun);
+  }
+
   void _api_fill_to_wire_gossip_source_config(GossipSourceConfig apiObj, wire_GossipSourceConfig wireObj) {
     if (apiObj is GossipSourceConfig_P2PNetwork) {
       wireObj.tag = 0;
@@ -1318,6 +1855,13 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     }
   }
 
+  void _api_fill_to_wire_inbound_channel_policy(InboundChannelPolicy apiObj, wire_InboundChannelPolicy wireObj) {
+    wireObj.allowed_peers = api2wire_list_public_key(apiObj.allowedPeers);
+    wireObj.min_channel_size_sats = api2wire_opt_box_autoadd_u64(apiObj.minChannelSizeSats);
+    wireObj.required_visibility = api2wire_opt_box_autoadd_channel_visibility(apiObj.requiredVisibility);
+    wireObj.max_pending_channels = api2wire_opt_box_autoadd_u32(apiObj.maxPendingChannels);
+  }
+
   void _api_fill_to_wire_invoice(Invoice apiObj, wire_Invoice wireObj) {
     wireObj.internal = api2wire_String(apiObj.internal);
   }
@@ -1345,10 +1889,40 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
       wireObj.kind.ref.IPv6.ref.port = pre_port;
       return;
     }
+    if (apiObj is NetAddress_OnionV2) {
+      var pre_addr = api2wire_u8_array_12(apiObj.addr);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_NetAddress_OnionV2();
+      wireObj.kind.ref.OnionV2.ref.addr = pre_addr;
+      return;
+    }
+    if (apiObj is NetAddress_OnionV3) {
+      var pre_pubkey = api2wire_u8_array_32(apiObj.pubkey);
+      var pre_checksum = api2wire_u16(apiObj.checksum);
+      var pre_version = api2wire_u8(apiObj.version);
+      var pre_port = api2wire_u16(apiObj.port);
+      wireObj.tag = 3;
+      wireObj.kind = inner.inflate_NetAddress_OnionV3();
+      wireObj.kind.ref.OnionV3.ref.pubkey = pre_pubkey;
+      wireObj.kind.ref.OnionV3.ref.checksum = pre_checksum;
+      wireObj.kind.ref.OnionV3.ref.version = pre_version;
+      wireObj.kind.ref.OnionV3.ref.port = pre_port;
+      return;
+    }
+    if (apiObj is NetAddress_Hostname) {
+      var pre_host = api2wire_String(apiObj.host);
+      var pre_port = api2wire_u16(apiObj.port);
+      wireObj.tag = 4;
+      wireObj.kind = inner.inflate_NetAddress_Hostname();
+      wireObj.kind.ref.Hostname.ref.host = pre_host;
+      wireObj.kind.ref.Hostname.ref.port = pre_port;
+      return;
+    }
   }
 
   void _api_fill_to_wire_node_pointer(NodePointer apiObj, wire_NodePointer wireObj) {
     wireObj.field0 = api2wire_MutexNodeSqliteStore(apiObj.field0);
+    wireObj.field1 = api2wire_NodeStore(apiObj.field1);
   }
 
   void _api_fill_to_wire_opt_box_autoadd_chain_data_source_config(ChainDataSourceConfig? apiObj, ffi.Pointer<wire_ChainDataSourceConfig> wireObj) {
@@ -1363,21 +1937,64 @@ class RustLdkNodePlatform extends FlutterRustBridgeBase<RustLdkNodeWire> {
     if (apiObj != null) _api_fill_to_wire_box_autoadd_entropy_source_config(apiObj, wireObj);
   }
 
+  void _api_fill_to_wire_opt_box_autoadd_fee_autopilot_config(FeeAutopilotConfig? apiObj, ffi.Pointer<wire_FeeAutopilotConfig> wireObj) {
+    if (apiObj != null) _api_fill_to_wire_box_autoadd_fee_autopilot_config(apiObj, wireObj);
+  }
+
   void _api_fill_to_wire_opt_box_autoadd_gossip_source_config(GossipSourceConfig? apiObj, ffi.Pointer<wire_GossipSourceConfig> wireObj) {
     if (apiObj != null) _api_fill_to_wire_box_autoadd_gossip_source_config(apiObj, wireObj);
   }
 
+  void _api_fill_to_wire_opt_box_autoadd_inbound_channel_policy(InboundChannelPolicy? apiObj, ffi.Pointer<wire_InboundChannelPolicy> wireObj) {
+    if (apiObj != null) _api_fill_to_wire_box_autoadd_inbound_channel_policy(apiObj, wireObj);
+  }
+
   void _api_fill_to_wire_opt_box_autoadd_net_address(NetAddress? apiObj, ffi.Pointer<wire_NetAddress> wireObj) {
     if (apiObj != null) _api_fill_to_wire_box_autoadd_net_address(apiObj, wireObj);
   }
 
+  void _api_fill_to_wire_opt_box_autoadd_retry_strategy(RetryStrategy? apiObj, ffi.Pointer<wire_RetryStrategy> wireObj) {
+    if (apiObj != null) _api_fill_to_wire_box_autoadd_retry_strategy(apiObj, wireObj);
+  }
+
   void _api_fill_to_wire_payment_hash(PaymentHash apiObj, wire_PaymentHash wireObj) {
     wireObj.internal = api2wire_u8_array_32(apiObj.internal);
   }
 
+  void _api_fill_to_wire_payment_query(PaymentQuery apiObj, wire_PaymentQuery wireObj) {
+    wireObj.direction = api2wire_opt_box_autoadd_payment_direction(apiObj.direction);
+    wireObj.statuses = api2wire_list_payment_status(apiObj.statuses);
+    wireObj.created_from = api2wire_opt_box_autoadd_u64(apiObj.createdFrom);
+    wireObj.created_until = api2wire_opt_box_autoadd_u64(apiObj.createdUntil);
+    wireObj.min_amount_msat = api2wire_opt_box_autoadd_u64(apiObj.minAmountMsat);
+    wireObj.max_amount_msat = api2wire_opt_box_autoadd_u64(apiObj.maxAmountMsat);
+    wireObj.label_contains = api2wire_opt_String(apiObj.labelContains);
+    wireObj.kind = api2wire_opt_box_autoadd_payment_kind(apiObj.kind);
+    wireObj.sort_order = api2wire_payment_sort_order(apiObj.sortOrder);
+    wireObj.cursor = api2wire_opt_String(apiObj.cursor);
+    wireObj.limit = api2wire_opt_box_autoadd_u32(apiObj.limit);
+  }
+
   void _api_fill_to_wire_public_key(PublicKey apiObj, wire_PublicKey wireObj) {
     wireObj.internal = api2wire_String(apiObj.internal);
   }
+
+  void _api_fill_to_wire_retry_strategy(RetryStrategy apiObj, wire_RetryStrategy wireObj) {
+    if (apiObj is RetryStrategy_Attempts) {
+      var pre_field0 = api2wire_u32(apiObj.field0);
+      wireObj.tag = 0;
+      wireObj.kind = inner.inflate_RetryStrategy_Attempts();
+      wireObj.kind.ref.Attempts.ref.field0 = pre_field0;
+      return;
+    }
+    if (apiObj is RetryStrategy_TimeoutSecs) {
+      var pre_field0 = api2wire_u64(apiObj.field0);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_RetryStrategy_TimeoutSecs();
+      wireObj.kind.ref.TimeoutSecs.ref.field0 = pre_field0;
+      return;
+    }
+  }
 }
 
 // ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
@@ -1467,6 +2084,17 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _wire_generate_entropy_mnemonicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_generate_entropy_mnemonic');
   late final _wire_generate_entropy_mnemonic = _wire_generate_entropy_mnemonicPtr.asFunction<void Function(int)>();
 
+  void wire_default_channel_config(
+    int port_,
+  ) {
+    return _wire_default_channel_config(
+      port_,
+    );
+  }
+
+  late final _wire_default_channel_configPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_default_channel_config');
+  late final _wire_default_channel_config = _wire_default_channel_configPtr.asFunction<void Function(int)>();
+
   void wire_build_node(
     int port_,
     ffi.Pointer<wire_Config> config,
@@ -1673,6 +2301,20 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _wire_list_channels__method__NodePointerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>)>>('wire_list_channels__method__NodePointer');
   late final _wire_list_channels__method__NodePointer = _wire_list_channels__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>)>();
 
+  void wire_list_closed_channels__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+  ) {
+    return _wire_list_closed_channels__method__NodePointer(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire_list_closed_channels__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>)>>('wire_list_closed_channels__method__NodePointer');
+  late final _wire_list_closed_channels__method__NodePointer = _wire_list_closed_channels__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>)>();
+
   void wire_connect__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
@@ -1772,81 +2414,140 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _wire_close_channel__method__NodePointer =
       _wire_close_channel__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelId>, ffi.Pointer<wire_PublicKey>)>();
 
+  void wire_channel_config__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+    ffi.Pointer<wire_ChannelId> channel_id,
+  ) {
+    return _wire_channel_config__method__NodePointer(
+      port_,
+      that,
+      channel_id,
+    );
+  }
+
+  late final _wire_channel_config__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelId>)>>('wire_channel_config__method__NodePointer');
+  late final _wire_channel_config__method__NodePointer = _wire_channel_config__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelId>)>();
+
   void wire_update_channel_config__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
     ffi.Pointer<wire_ChannelId> channel_id,
     ffi.Pointer<wire_PublicKey> counterparty_node_id,
-    ffi.Pointer<wire_ChannelConfig> channel_config,
+    ffi.Pointer<wire_ChannelConfigUpdate> update,
   ) {
     return _wire_update_channel_config__method__NodePointer(
       port_,
       that,
       channel_id,
       counterparty_node_id,
-      channel_config,
+      update,
     );
   }
 
   late final _wire_update_channel_config__method__NodePointerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelId>, ffi.Pointer<wire_PublicKey>, ffi.Pointer<wire_ChannelConfig>)>>(
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelId>, ffi.Pointer<wire_PublicKey>, ffi.Pointer<wire_ChannelConfigUpdate>)>>(
           'wire_update_channel_config__method__NodePointer');
   late final _wire_update_channel_config__method__NodePointer = _wire_update_channel_config__method__NodePointerPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelId>, ffi.Pointer<wire_PublicKey>, ffi.Pointer<wire_ChannelConfig>)>();
+      .asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelId>, ffi.Pointer<wire_PublicKey>, ffi.Pointer<wire_ChannelConfigUpdate>)>();
+
+  void wire_update_all_channel_configs__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+    ffi.Pointer<wire_ChannelConfigUpdate> update,
+    ffi.Pointer<wire_ChannelFilter> filter,
+  ) {
+    return _wire_update_all_channel_configs__method__NodePointer(
+      port_,
+      that,
+      update,
+      filter,
+    );
+  }
+
+  late final _wire_update_all_channel_configs__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelConfigUpdate>, ffi.Pointer<wire_ChannelFilter>)>>(
+          'wire_update_all_channel_configs__method__NodePointer');
+  late final _wire_update_all_channel_configs__method__NodePointer =
+      _wire_update_all_channel_configs__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_ChannelConfigUpdate>, ffi.Pointer<wire_ChannelFilter>)>();
+
+  void wire_fee_autopilot_proposals__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+  ) {
+    return _wire_fee_autopilot_proposals__method__NodePointer(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire_fee_autopilot_proposals__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>)>>('wire_fee_autopilot_proposals__method__NodePointer');
+  late final _wire_fee_autopilot_proposals__method__NodePointer = _wire_fee_autopilot_proposals__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>)>();
 
   void wire_send_payment__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
     ffi.Pointer<wire_Invoice> invoice,
+    ffi.Pointer<wire_RetryStrategy> retry_strategy,
   ) {
     return _wire_send_payment__method__NodePointer(
       port_,
       that,
       invoice,
+      retry_strategy,
     );
   }
 
   late final _wire_send_payment__method__NodePointerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>)>>('wire_send_payment__method__NodePointer');
-  late final _wire_send_payment__method__NodePointer = _wire_send_payment__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>)>();
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>, ffi.Pointer<wire_RetryStrategy>)>>('wire_send_payment__method__NodePointer');
+  late final _wire_send_payment__method__NodePointer =
+      _wire_send_payment__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>, ffi.Pointer<wire_RetryStrategy>)>();
 
   void wire_send_payment_using_amount__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
     ffi.Pointer<wire_Invoice> invoice,
     int amount_msat,
+    ffi.Pointer<wire_RetryStrategy> retry_strategy,
   ) {
     return _wire_send_payment_using_amount__method__NodePointer(
       port_,
       that,
       invoice,
       amount_msat,
+      retry_strategy,
     );
   }
 
   late final _wire_send_payment_using_amount__method__NodePointerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>, ffi.Uint64)>>('wire_send_payment_using_amount__method__NodePointer');
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>, ffi.Uint64, ffi.Pointer<wire_RetryStrategy>)>>(
+          'wire_send_payment_using_amount__method__NodePointer');
   late final _wire_send_payment_using_amount__method__NodePointer =
-      _wire_send_payment_using_amount__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>, int)>();
+      _wire_send_payment_using_amount__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_Invoice>, int, ffi.Pointer<wire_RetryStrategy>)>();
 
   void wire_send_spontaneous_payment__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
     int amount_msat,
     ffi.Pointer<wire_PublicKey> node_id,
+    ffi.Pointer<wire_RetryStrategy> retry_strategy,
   ) {
     return _wire_send_spontaneous_payment__method__NodePointer(
       port_,
       that,
       amount_msat,
       node_id,
+      retry_strategy,
     );
   }
 
   late final _wire_send_spontaneous_payment__method__NodePointerPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Uint64, ffi.Pointer<wire_PublicKey>)>>('wire_send_spontaneous_payment__method__NodePointer');
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Uint64, ffi.Pointer<wire_PublicKey>, ffi.Pointer<wire_RetryStrategy>)>>(
+          'wire_send_spontaneous_payment__method__NodePointer');
   late final _wire_send_spontaneous_payment__method__NodePointer =
-      _wire_send_spontaneous_payment__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, int, ffi.Pointer<wire_PublicKey>)>();
+      _wire_send_spontaneous_payment__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, int, ffi.Pointer<wire_PublicKey>, ffi.Pointer<wire_RetryStrategy>)>();
 
   void wire_receive_payment__method__NodePointer(
     int port_,
@@ -1920,6 +2621,42 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentHash>)>>('wire_remove_payment__method__NodePointer');
   late final _wire_remove_payment__method__NodePointer = _wire_remove_payment__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentHash>)>();
 
+  void wire_abandon_payment__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+    ffi.Pointer<wire_PaymentHash> payment_hash,
+  ) {
+    return _wire_abandon_payment__method__NodePointer(
+      port_,
+      that,
+      payment_hash,
+    );
+  }
+
+  late final _wire_abandon_payment__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentHash>)>>('wire_abandon_payment__method__NodePointer');
+  late final _wire_abandon_payment__method__NodePointer = _wire_abandon_payment__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentHash>)>();
+
+  void wire_set_payment_label__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+    ffi.Pointer<wire_PaymentHash> payment_hash,
+    ffi.Pointer<wire_uint_8_list> label,
+  ) {
+    return _wire_set_payment_label__method__NodePointer(
+      port_,
+      that,
+      payment_hash,
+      label,
+    );
+  }
+
+  late final _wire_set_payment_label__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentHash>, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_set_payment_label__method__NodePointer');
+  late final _wire_set_payment_label__method__NodePointer =
+      _wire_set_payment_label__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentHash>, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_list_payments_with_filter__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
@@ -1936,6 +2673,22 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Int32)>>('wire_list_payments_with_filter__method__NodePointer');
   late final _wire_list_payments_with_filter__method__NodePointer = _wire_list_payments_with_filter__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, int)>();
 
+  void wire_query_payments__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+    ffi.Pointer<wire_PaymentQuery> query,
+  ) {
+    return _wire_query_payments__method__NodePointer(
+      port_,
+      that,
+      query,
+    );
+  }
+
+  late final _wire_query_payments__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentQuery>)>>('wire_query_payments__method__NodePointer');
+  late final _wire_query_payments__method__NodePointer = _wire_query_payments__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, ffi.Pointer<wire_PaymentQuery>)>();
+
   void wire_list_payments__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
@@ -1949,6 +2702,28 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _wire_list_payments__method__NodePointerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>)>>('wire_list_payments__method__NodePointer');
   late final _wire_list_payments__method__NodePointer = _wire_list_payments__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>)>();
 
+  void wire_export_payments__method__NodePointer(
+    int port_,
+    ffi.Pointer<wire_NodePointer> that,
+    int format,
+    ffi.Pointer<ffi.Uint64> created_from,
+    ffi.Pointer<ffi.Uint64> created_until,
+  ) {
+    return _wire_export_payments__method__NodePointer(
+      port_,
+      that,
+      format,
+      created_from,
+      created_until,
+    );
+  }
+
+  late final _wire_export_payments__method__NodePointerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodePointer>, ffi.Int32, ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint64>)>>(
+          'wire_export_payments__method__NodePointer');
+  late final _wire_export_payments__method__NodePointer =
+      _wire_export_payments__method__NodePointerPtr.asFunction<void Function(int, ffi.Pointer<wire_NodePointer>, int, ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint64>)>();
+
   void wire_list_peers__method__NodePointer(
     int port_,
     ffi.Pointer<wire_NodePointer> that,
@@ -2007,6 +2782,13 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_MutexNodeSqliteStorePtr = _lookup<ffi.NativeFunction<wire_MutexNodeSqliteStore Function()>>('new_MutexNodeSqliteStore');
   late final _new_MutexNodeSqliteStore = _new_MutexNodeSqliteStorePtr.asFunction<wire_MutexNodeSqliteStore Function()>();
 
+  wire_NodeStore new_NodeStore() {
+    return _new_NodeStore();
+  }
+
+  late final _new_NodeStorePtr = _lookup<ffi.NativeFunction<wire_NodeStore Function()>>('new_NodeStore');
+  late final _new_NodeStore = _new_NodeStorePtr.asFunction<wire_NodeStore Function()>();
+
   ffi.Pointer<wire_Address> new_box_autoadd_address_0() {
     return _new_box_autoadd_address_0();
   }
@@ -2028,6 +2810,20 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_channel_config_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_ChannelConfig> Function()>>('new_box_autoadd_channel_config_0');
   late final _new_box_autoadd_channel_config_0 = _new_box_autoadd_channel_config_0Ptr.asFunction<ffi.Pointer<wire_ChannelConfig> Function()>();
 
+  ffi.Pointer<wire_ChannelConfigUpdate> new_box_autoadd_channel_config_update_0() {
+    return _new_box_autoadd_channel_config_update_0();
+  }
+
+  late final _new_box_autoadd_channel_config_update_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_ChannelConfigUpdate> Function()>>('new_box_autoadd_channel_config_update_0');
+  late final _new_box_autoadd_channel_config_update_0 = _new_box_autoadd_channel_config_update_0Ptr.asFunction<ffi.Pointer<wire_ChannelConfigUpdate> Function()>();
+
+  ffi.Pointer<wire_ChannelFilter> new_box_autoadd_channel_filter_0() {
+    return _new_box_autoadd_channel_filter_0();
+  }
+
+  late final _new_box_autoadd_channel_filter_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_ChannelFilter> Function()>>('new_box_autoadd_channel_filter_0');
+  late final _new_box_autoadd_channel_filter_0 = _new_box_autoadd_channel_filter_0Ptr.asFunction<ffi.Pointer<wire_ChannelFilter> Function()>();
+
   ffi.Pointer<wire_ChannelId> new_box_autoadd_channel_id_0() {
     return _new_box_autoadd_channel_id_0();
   }
@@ -2035,6 +2831,17 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_channel_id_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_ChannelId> Function()>>('new_box_autoadd_channel_id_0');
   late final _new_box_autoadd_channel_id_0 = _new_box_autoadd_channel_id_0Ptr.asFunction<ffi.Pointer<wire_ChannelId> Function()>();
 
+  ffi.Pointer<ffi.Int32> new_box_autoadd_channel_visibility_0(
+    int value,
+  ) {
+    return _new_box_autoadd_channel_visibility_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_channel_visibility_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>('new_box_autoadd_channel_visibility_0');
+  late final _new_box_autoadd_channel_visibility_0 = _new_box_autoadd_channel_visibility_0Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
   ffi.Pointer<wire_Config> new_box_autoadd_config_0() {
     return _new_box_autoadd_config_0();
   }
@@ -2049,6 +2856,13 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_entropy_source_config_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_EntropySourceConfig> Function()>>('new_box_autoadd_entropy_source_config_0');
   late final _new_box_autoadd_entropy_source_config_0 = _new_box_autoadd_entropy_source_config_0Ptr.asFunction<ffi.Pointer<wire_EntropySourceConfig> Function()>();
 
+  ffi.Pointer<wire_FeeAutopilotConfig> new_box_autoadd_fee_autopilot_config_0() {
+    return _new_box_autoadd_fee_autopilot_config_0();
+  }
+
+  late final _new_box_autoadd_fee_autopilot_config_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_FeeAutopilotConfig> Function()>>('new_box_autoadd_fee_autopilot_config_0');
+  late final _new_box_autoadd_fee_autopilot_config_0 = _new_box_autoadd_fee_autopilot_config_0Ptr.asFunction<ffi.Pointer<wire_FeeAutopilotConfig> Function()>();
+
   ffi.Pointer<wire_GossipSourceConfig> new_box_autoadd_gossip_source_config_0() {
     return _new_box_autoadd_gossip_source_config_0();
   }
@@ -2056,6 +2870,13 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_gossip_source_config_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_GossipSourceConfig> Function()>>('new_box_autoadd_gossip_source_config_0');
   late final _new_box_autoadd_gossip_source_config_0 = _new_box_autoadd_gossip_source_config_0Ptr.asFunction<ffi.Pointer<wire_GossipSourceConfig> Function()>();
 
+  ffi.Pointer<wire_InboundChannelPolicy> new_box_autoadd_inbound_channel_policy_0() {
+    return _new_box_autoadd_inbound_channel_policy_0();
+  }
+
+  late final _new_box_autoadd_inbound_channel_policy_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_InboundChannelPolicy> Function()>>('new_box_autoadd_inbound_channel_policy_0');
+  late final _new_box_autoadd_inbound_channel_policy_0 = _new_box_autoadd_inbound_channel_policy_0Ptr.asFunction<ffi.Pointer<wire_InboundChannelPolicy> Function()>();
+
   ffi.Pointer<wire_Invoice> new_box_autoadd_invoice_0() {
     return _new_box_autoadd_invoice_0();
   }
@@ -2084,6 +2905,17 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_node_pointer_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_NodePointer> Function()>>('new_box_autoadd_node_pointer_0');
   late final _new_box_autoadd_node_pointer_0 = _new_box_autoadd_node_pointer_0Ptr.asFunction<ffi.Pointer<wire_NodePointer> Function()>();
 
+  ffi.Pointer<ffi.Int32> new_box_autoadd_payment_direction_0(
+    int value,
+  ) {
+    return _new_box_autoadd_payment_direction_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_payment_direction_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>('new_box_autoadd_payment_direction_0');
+  late final _new_box_autoadd_payment_direction_0 = _new_box_autoadd_payment_direction_0Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
   ffi.Pointer<wire_PaymentHash> new_box_autoadd_payment_hash_0() {
     return _new_box_autoadd_payment_hash_0();
   }
@@ -2091,6 +2923,24 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_payment_hash_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_PaymentHash> Function()>>('new_box_autoadd_payment_hash_0');
   late final _new_box_autoadd_payment_hash_0 = _new_box_autoadd_payment_hash_0Ptr.asFunction<ffi.Pointer<wire_PaymentHash> Function()>();
 
+  ffi.Pointer<ffi.Int32> new_box_autoadd_payment_kind_0(
+    int value,
+  ) {
+    return _new_box_autoadd_payment_kind_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_payment_kind_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>('new_box_autoadd_payment_kind_0');
+  late final _new_box_autoadd_payment_kind_0 = _new_box_autoadd_payment_kind_0Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_PaymentQuery> new_box_autoadd_payment_query_0() {
+    return _new_box_autoadd_payment_query_0();
+  }
+
+  late final _new_box_autoadd_payment_query_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_PaymentQuery> Function()>>('new_box_autoadd_payment_query_0');
+  late final _new_box_autoadd_payment_query_0 = _new_box_autoadd_payment_query_0Ptr.asFunction<ffi.Pointer<wire_PaymentQuery> Function()>();
+
   ffi.Pointer<wire_PublicKey> new_box_autoadd_public_key_0() {
     return _new_box_autoadd_public_key_0();
   }
@@ -2098,6 +2948,35 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_public_key_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_PublicKey> Function()>>('new_box_autoadd_public_key_0');
   late final _new_box_autoadd_public_key_0 = _new_box_autoadd_public_key_0Ptr.asFunction<ffi.Pointer<wire_PublicKey> Function()>();
 
+  ffi.Pointer<wire_RetryStrategy> new_box_autoadd_retry_strategy_0() {
+    return _new_box_autoadd_retry_strategy_0();
+  }
+
+  late final _new_box_autoadd_retry_strategy_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_RetryStrategy> Function()>>('new_box_autoadd_retry_strategy_0');
+  late final _new_box_autoadd_retry_strategy_0 = _new_box_autoadd_retry_strategy_0Ptr.asFunction<ffi.Pointer<wire_RetryStrategy> Function()>();
+
+  ffi.Pointer<ffi.Uint16> new_box_autoadd_u16_0(
+    int value,
+  ) {
+    return _new_box_autoadd_u16_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_u16_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint16> Function(ffi.Uint16)>>('new_box_autoadd_u16_0');
+  late final _new_box_autoadd_u16_0 = _new_box_autoadd_u16_0Ptr.asFunction<ffi.Pointer<ffi.Uint16> Function(int)>();
+
+  ffi.Pointer<ffi.Uint32> new_box_autoadd_u32_0(
+    int value,
+  ) {
+    return _new_box_autoadd_u32_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_u32_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>('new_box_autoadd_u32_0');
+  late final _new_box_autoadd_u32_0 = _new_box_autoadd_u32_0Ptr.asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
   ffi.Pointer<ffi.Uint64> new_box_autoadd_u64_0(
     int value,
   ) {
@@ -2109,6 +2988,17 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_u64_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>('new_box_autoadd_u64_0');
   late final _new_box_autoadd_u64_0 = _new_box_autoadd_u64_0Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
 
+  ffi.Pointer<wire_list_payment_status> new_list_payment_status_0(
+    int len,
+  ) {
+    return _new_list_payment_status_0(
+      len,
+    );
+  }
+
+  late final _new_list_payment_status_0Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_payment_status> Function(ffi.Int32)>>('new_list_payment_status_0');
+  late final _new_list_payment_status_0 = _new_list_payment_status_0Ptr.asFunction<ffi.Pointer<wire_list_payment_status> Function(int)>();
+
   ffi.Pointer<wire_list_public_key> new_list_public_key_0(
     int len,
   ) {
@@ -2153,6 +3043,28 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _share_opaque_MutexNodeSqliteStorePtr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('share_opaque_MutexNodeSqliteStore');
   late final _share_opaque_MutexNodeSqliteStore = _share_opaque_MutexNodeSqliteStorePtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  void drop_opaque_NodeStore(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_NodeStore(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_NodeStorePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('drop_opaque_NodeStore');
+  late final _drop_opaque_NodeStore = _drop_opaque_NodeStorePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_NodeStore(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_NodeStore(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_NodeStorePtr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>('share_opaque_NodeStore');
+  late final _share_opaque_NodeStore = _share_opaque_NodeStorePtr.asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   ffi.Pointer<ChainDataSourceConfigKind> inflate_ChainDataSourceConfig_Esplora() {
     return _inflate_ChainDataSourceConfig_Esplora();
   }
@@ -2160,6 +3072,20 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _inflate_ChainDataSourceConfig_EsploraPtr = _lookup<ffi.NativeFunction<ffi.Pointer<ChainDataSourceConfigKind> Function()>>('inflate_ChainDataSourceConfig_Esplora');
   late final _inflate_ChainDataSourceConfig_Esplora = _inflate_ChainDataSourceConfig_EsploraPtr.asFunction<ffi.Pointer<ChainDataSourceConfigKind> Function()>();
 
+  ffi.Pointer<ChannelFilterKind> inflate_ChannelFilter_Counterparty() {
+    return _inflate_ChannelFilter_Counterparty();
+  }
+
+  late final _inflate_ChannelFilter_CounterpartyPtr = _lookup<ffi.NativeFunction<ffi.Pointer<ChannelFilterKind> Function()>>('inflate_ChannelFilter_Counterparty');
+  late final _inflate_ChannelFilter_Counterparty = _inflate_ChannelFilter_CounterpartyPtr.asFunction<ffi.Pointer<ChannelFilterKind> Function()>();
+
+  ffi.Pointer<ChannelFilterKind> inflate_ChannelFilter_Visibility() {
+    return _inflate_ChannelFilter_Visibility();
+  }
+
+  late final _inflate_ChannelFilter_VisibilityPtr = _lookup<ffi.NativeFunction<ffi.Pointer<ChannelFilterKind> Function()>>('inflate_ChannelFilter_Visibility');
+  late final _inflate_ChannelFilter_Visibility = _inflate_ChannelFilter_VisibilityPtr.asFunction<ffi.Pointer<ChannelFilterKind> Function()>();
+
   ffi.Pointer<EntropySourceConfigKind> inflate_EntropySourceConfig_SeedFile() {
     return _inflate_EntropySourceConfig_SeedFile();
   }
@@ -2202,6 +3128,41 @@ class RustLdkNodeWire implements FlutterRustBridgeWireBase {
   late final _inflate_NetAddress_IPv6Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<NetAddressKind> Function()>>('inflate_NetAddress_IPv6');
   late final _inflate_NetAddress_IPv6 = _inflate_NetAddress_IPv6Ptr.asFunction<ffi.Pointer<NetAddressKind> Function()>();
 
+  ffi.Pointer<NetAddressKind> inflate_NetAddress_OnionV2() {
+    return _inflate_NetAddress_OnionV2();
+  }
+
+  late final _inflate_NetAddress_OnionV2Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<NetAddressKind> Function()>>('inflate_NetAddress_OnionV2');
+  late final _inflate_NetAddress_OnionV2 = _inflate_NetAddress_OnionV2Ptr.asFunction<ffi.Pointer<NetAddressKind> Function()>();
+
+  ffi.Pointer<NetAddressKind> inflate_NetAddress_OnionV3() {
+    return _inflate_NetAddress_OnionV3();
+  }
+
+  late final _inflate_NetAddress_OnionV3Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<NetAddressKind> Function()>>('inflate_NetAddress_OnionV3');
+  late final _inflate_NetAddress_OnionV3 = _inflate_NetAddress_OnionV3Ptr.asFunction<ffi.Pointer<NetAddressKind> Function()>();
+
+  ffi.Pointer<NetAddressKind> inflate_NetAddress_Hostname() {
+    return _inflate_NetAddress_Hostname();
+  }
+
+  late final _inflate_NetAddress_HostnamePtr = _lookup<ffi.NativeFunction<ffi.Pointer<NetAddressKind> Function()>>('inflate_NetAddress_Hostname');
+  late final _inflate_NetAddress_Hostname = _inflate_NetAddress_HostnamePtr.asFunction<ffi.Pointer<NetAddressKind> Function()>();
+
+  ffi.Pointer<RetryStrategyKind> inflate_RetryStrategy_Attempts() {
+    return _inflate_RetryStrategy_Attempts();
+  }
+
+  late final _inflate_RetryStrategy_AttemptsPtr = _lookup<ffi.NativeFunction<ffi.Pointer<RetryStrategyKind> Function()>>('inflate_RetryStrategy_Attempts');
+  late final _inflate_RetryStrategy_Attempts = _inflate_RetryStrategy_AttemptsPtr.asFunction<ffi.Pointer<RetryStrategyKind> Function()>();
+
+  ffi.Pointer<RetryStrategyKind> inflate_RetryStrategy_TimeoutSecs() {
+    return _inflate_RetryStrategy_TimeoutSecs();
+  }
+
+  late final _inflate_RetryStrategy_TimeoutSecsPtr = _lookup<ffi.NativeFunction<ffi.Pointer<RetryStrategyKind> Function()>>('inflate_RetryStrategy_TimeoutSecs');
+  late final _inflate_RetryStrategy_TimeoutSecs = _inflate_RetryStrategy_TimeoutSecsPtr.asFunction<ffi.Pointer<RetryStrategyKind> Function()>();
+
   void free_WireSyncReturn(
     WireSyncReturn ptr,
   ) {
@@ -2248,10 +3209,40 @@ final class wire_NetAddress_IPv6 extends ffi.Struct {
   external int port;
 }
 
+final class wire_NetAddress_OnionV2 extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> addr;
+}
+
+final class wire_NetAddress_OnionV3 extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> pubkey;
+
+  @ffi.Uint16()
+  external int checksum;
+
+  @ffi.Uint8()
+  external int version;
+
+  @ffi.Uint16()
+  external int port;
+}
+
+final class wire_NetAddress_Hostname extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> host;
+
+  @ffi.Uint16()
+  external int port;
+}
+
 final class NetAddressKind extends ffi.Union {
   external ffi.Pointer<wire_NetAddress_IPv4> IPv4;
 
   external ffi.Pointer<wire_NetAddress_IPv6> IPv6;
+
+  external ffi.Pointer<wire_NetAddress_OnionV2> OnionV2;
+
+  external ffi.Pointer<wire_NetAddress_OnionV3> OnionV3;
+
+  external ffi.Pointer<wire_NetAddress_Hostname> Hostname;
 }
 
 final class wire_NetAddress extends ffi.Struct {
@@ -2261,6 +3252,39 @@ final class wire_NetAddress extends ffi.Struct {
   external ffi.Pointer<NetAddressKind> kind;
 }
 
+final class wire_InboundChannelPolicy extends ffi.Struct {
+  external ffi.Pointer<wire_list_public_key> allowed_peers;
+
+  external ffi.Pointer<ffi.Uint64> min_channel_size_sats;
+
+  external ffi.Pointer<ffi.Int32> required_visibility;
+
+  external ffi.Pointer<ffi.Uint32> max_pending_channels;
+}
+
+final class wire_FeeAutopilotConfig extends ffi.Struct {
+  @ffi.Uint32()
+  external int min_base_fee_msat;
+
+  @ffi.Uint32()
+  external int max_base_fee_msat;
+
+  @ffi.Uint32()
+  external int min_proportional_millionths;
+
+  @ffi.Uint32()
+  external int max_proportional_millionths;
+
+  @ffi.Uint8()
+  external int target_outbound_percent;
+
+  @ffi.Uint64()
+  external int interval_secs;
+
+  @ffi.Bool()
+  external bool dry_run;
+}
+
 final class wire_Config extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> storage_dir_path;
 
@@ -2285,6 +3309,10 @@ final class wire_Config extends ffi.Struct {
 
   @ffi.Uint32()
   external int default_cltv_expiry_delta;
+
+  external ffi.Pointer<wire_InboundChannelPolicy> inbound_channel_policy;
+
+  external ffi.Pointer<wire_FeeAutopilotConfig> fee_autopilot;
 }
 
 final class wire_ChainDataSourceConfig_Esplora extends ffi.Struct {
@@ -2358,8 +3386,14 @@ final class wire_MutexNodeSqliteStore extends ffi.Struct {
   external ffi.Pointer<ffi.Void> ptr;
 }
 
+final class wire_NodeStore extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
 final class wire_NodePointer extends ffi.Struct {
   external wire_MutexNodeSqliteStore field0;
+
+  external wire_NodeStore field1;
 }
 
 final class wire_Address extends ffi.Struct {
@@ -2387,13 +3421,106 @@ final class wire_ChannelId extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> internal;
 }
 
+final class wire_ChannelConfigUpdate extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> forwarding_fee_proportional_millionths;
+
+  external ffi.Pointer<ffi.Uint32> forwarding_fee_base_msat;
+
+  external ffi.Pointer<ffi.Uint16> cltv_expiry_delta;
+
+  external ffi.Pointer<ffi.Uint64> max_dust_htlc_exposure_msat;
+
+  external ffi.Pointer<ffi.Uint64> force_close_avoidance_max_fee_satoshis;
+}
+
+final class wire_ChannelFilter_All extends ffi.Opaque {}
+
+final class wire_ChannelFilter_Counterparty extends ffi.Struct {
+  external ffi.Pointer<wire_PublicKey> field0;
+}
+
+final class wire_ChannelFilter_Visibility extends ffi.Struct {
+  @ffi.Int32()
+  external int field0;
+}
+
+final class ChannelFilterKind extends ffi.Union {
+  external ffi.Pointer<wire_ChannelFilter_All> All;
+
+  external ffi.Pointer<wire_ChannelFilter_Counterparty> Counterparty;
+
+  external ffi.Pointer<wire_ChannelFilter_Visibility> Visibility;
+}
+
+final class wire_ChannelFilter extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<ChannelFilterKind> kind;
+}
+
 final class wire_Invoice extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> internal;
 }
 
+final class wire_RetryStrategy_Attempts extends ffi.Struct {
+  @ffi.Uint32()
+  external int field0;
+}
+
+final class wire_RetryStrategy_TimeoutSecs extends ffi.Struct {
+  @ffi.Uint64()
+  external int field0;
+}
+
+final class RetryStrategyKind extends ffi.Union {
+  external ffi.Pointer<wire_RetryStrategy_Attempts> Attempts;
+
+  external ffi.Pointer<wire_RetryStrategy_TimeoutSecs> TimeoutSecs;
+}
+
+final class wire_RetryStrategy extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<RetryStrategyKind> kind;
+}
+
 final class wire_PaymentHash extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> internal;
 }
 
+final class wire_list_payment_status extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_PaymentQuery extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> direction;
+
+  external ffi.Pointer<wire_list_payment_status> statuses;
+
+  external ffi.Pointer<ffi.Uint64> created_from;
+
+  external ffi.Pointer<ffi.Uint64> created_until;
+
+  external ffi.Pointer<ffi.Uint64> min_amount_msat;
+
+  external ffi.Pointer<ffi.Uint64> max_amount_msat;
+
+  external ffi.Pointer<wire_uint_8_list> label_contains;
+
+  external ffi.Pointer<ffi.Int32> kind;
+
+  @ffi.Int32()
+  external int sort_order;
+
+  external ffi.Pointer<wire_uint_8_list> cursor;
+
+  external ffi.Pointer<ffi.Uint32> limit;
+}
+
 typedef DartPostCObjectFnType = ffi.Pointer<ffi.NativeFunction<ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message)>>;
 typedef DartPort = ffi.Int64;
@@ -17,6 +17,11 @@ abstract class RustLdkNode {
 
   FlutterRustBridgeTaskConstMeta get kGenerateEntropyMnemonicConstMeta;
 
+  /// Returns the default config LDK Node opens channels with.
+  Future<ChannelConfig> defaultChannelConfig({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDefaultChannelConfigConstMeta;
+
   Future<NodePointer> buildNode({required Config config, ChainDataSourceConfig? chainDataSourceConfig, EntropySourceConfig? entropySourceConfig, GossipSourceConfig? gossipSourceConfig, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kBuildNodeConstMeta;
@@ -102,6 +107,11 @@ abstract class RustLdkNode {
 
   FlutterRustBridgeTaskConstMeta get kListChannelsMethodNodePointerConstMeta;
 
+  /// Retrieves the channels that were closed, as far as they were known to this package.
+  Future<List<ClosedChannelDetails>> listClosedChannelsMethodNodePointer({required NodePointer that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListClosedChannelsMethodNodePointerConstMeta;
+
   /// Connect to a node on the peer-to-peer network.
   ///
   /// If `permanently` is set to `true`, we'll remember the peer and reconnect to it on restart.
@@ -144,19 +154,55 @@ abstract class RustLdkNode {
   FlutterRustBridgeTaskConstMeta get kSyncWalletsMethodNodePointerConstMeta;
 
   /// Close a previously opened channel.
+  ///
+  /// The channel is closed cooperatively, with the closing fee negotiated by LDK and the funds
+  /// sent to the on-chain wallet. LDK Node 0.1.0 offers no way to force-close a channel.
   Future<void> closeChannelMethodNodePointer({required NodePointer that, required ChannelId channelId, required PublicKey counterpartyNodeId, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kCloseChannelMethodNodePointerConstMeta;
 
+  /// Returns the config of a previously opened channel.
+  ///
+  /// This is the config set on channel open or via `node.updateChannelConfig()`, or `null` if it
+  /// was never set via this package, as LDK Node 0.1.0 offers no way to read it back.
+  Future<ChannelConfig?> channelConfigMethodNodePointer({required NodePointer that, required ChannelId channelId, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kChannelConfigMethodNodePointerConstMeta;
+
   ///Update the config for a previously opened channel.
   ///
+  /// Only the fields set in `update` are changed, all others keep their current value. Throws
+  /// if `node.channelConfig()` returns `null` for the channel and not all fields are set, as
+  /// their current value is unknown then.
   Future<void> updateChannelConfigMethodNodePointer(
-      {required NodePointer that, required ChannelId channelId, required PublicKey counterpartyNodeId, required ChannelConfig channelConfig, dynamic hint});
+      {required NodePointer that, required ChannelId channelId, required PublicKey counterpartyNodeId, required ChannelConfigUpdate update, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kUpdateChannelConfigMethodNodePointerConstMeta;
 
+  /// Updates the config of all channels matching the given filter.
+  ///
+  /// Only the fields set in `update` are changed, all others keep their current value. Channels
+  /// whose config is unknown are only updated if all fields are set. A failed update doesn't
+  /// stop the remaining channels from being updated; the outcome is reported
+  /// per channel.
+  ///
+  /// Throws if the node ID of a `ChannelFilter.counterparty()` filter is invalid.
+  Future<List<ChannelConfigUpdateResult>> updateAllChannelConfigsMethodNodePointer({required NodePointer that, required ChannelConfigUpdate update, required ChannelFilter filter, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kUpdateAllChannelConfigsMethodNodePointerConstMeta;
+
+  /// Returns the changes the fee autopilot would currently make to the forwarding fees of our
+  /// channels, without applying them.
+  ///
+  /// Throws if no `Config.feeAutopilot` is set.
+  Future<List<ChannelFeeProposal>> feeAutopilotProposalsMethodNodePointer({required NodePointer that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFeeAutopilotProposalsMethodNodePointerConstMeta;
+
   /// Send a payement given an invoice.
-  Future<PaymentHash> sendPaymentMethodNodePointer({required NodePointer that, required Invoice invoice, dynamic hint});
+  ///
+  /// If `retryStrategy` is set, the payment is sent again according to it when it fails.
+  Future<PaymentHash> sendPaymentMethodNodePointer({required NodePointer that, required Invoice invoice, RetryStrategy? retryStrategy, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSendPaymentMethodNodePointerConstMeta;
 
@@ -166,12 +212,19 @@ abstract class RustLdkNode {
   ///
   /// This can be used to pay a so-called "zero-amount" invoice, i.e., an invoice that leaves the
   /// amount paid to be determined by the user.
-  Future<PaymentHash> sendPaymentUsingAmountMethodNodePointer({required NodePointer that, required Invoice invoice, required int amountMsat, dynamic hint});
+  ///
+  /// If `retryStrategy` is set, the payment is sent again according to it when it fails.
+  Future<PaymentHash> sendPaymentUsingAmountMethodNodePointer({required NodePointer that, required Invoice invoice, required int amountMsat, RetryStrategy? retryStrategy, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSendPaymentUsingAmountMethodNodePointerConstMeta;
 
   /// Send a spontaneous, aka. "keysend", payment
-  Future<PaymentHash> sendSpontaneousPaymentMethodNodePointer({required NodePointer that, required int amountMsat, required PublicKey nodeId, dynamic hint});
+  ///
+  /// If `retryStrategy` is set, the payment is sent again according to it when it fails. Every
+  /// attempt uses a new payment hash, but the payment stays known by the returned hash of its
+  /// first attempt: its events and `PaymentDetails` carry that hash, and it is the one to pass
+  /// to `node.payment()` or `node.abandonPayment()`.
+  Future<PaymentHash> sendSpontaneousPaymentMethodNodePointer({required NodePointer that, required int amountMsat, required PublicKey nodeId, RetryStrategy? retryStrategy, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSendSpontaneousPaymentMethodNodePointerConstMeta;
 
@@ -201,17 +254,52 @@ abstract class RustLdkNode {
 
   FlutterRustBridgeTaskConstMeta get kRemovePaymentMethodNodePointerConstMeta;
 
+  /// Gives up on the pending outbound payment with the given hash.
+  ///
+  /// No further retries are made, and the payment's status changes to `Abandoning` until LDK
+  /// resolves its in-flight HTLCs, after which it is marked as failed and an
+  /// `Event.paymentFailed` is emitted.
+  ///
+  /// **Note:** LDK Node 0.1.0 can't cancel HTLCs that are already in flight, so an abandoned
+  /// payment may still succeed, in which case an `Event.paymentSuccessful` is emitted instead.
+  Future<void> abandonPaymentMethodNodePointer({required NodePointer that, required PaymentHash paymentHash, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAbandonPaymentMethodNodePointerConstMeta;
+
+  /// Sets the label of the payment with the given hash, or clears it if `label` is `null`.
+  Future<void> setPaymentLabelMethodNodePointer({required NodePointer that, required PaymentHash paymentHash, String? label, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetPaymentLabelMethodNodePointerConstMeta;
+
   /// Retrieves all payments that match the given predicate.
   ///
   Future<List<PaymentDetails>> listPaymentsWithFilterMethodNodePointer({required NodePointer that, required PaymentDirection paymentDirection, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kListPaymentsWithFilterMethodNodePointerConstMeta;
 
+  /// Retrieves the payments matching the given query, one page at a time.
+  ///
+  /// Payments are sorted by their creation time. To retrieve the following page, call this
+  /// again with `query.cursor` set to the `nextCursor` of the returned page.
+  Future<PaymentPage> queryPaymentsMethodNodePointer({required NodePointer that, required PaymentQuery query, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kQueryPaymentsMethodNodePointerConstMeta;
+
   /// Retrieves all payments.
   Future<List<PaymentDetails>> listPaymentsMethodNodePointer({required NodePointer that, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kListPaymentsMethodNodePointerConstMeta;
 
+  /// Exports the Lightning payments and on-chain transactions created in the given time range,
+  /// e.g. for accounting.
+  ///
+  /// `createdFrom` and `createdUntil` are in seconds since the UNIX epoch; the former is
+  /// inclusive, the latter exclusive, and either may be `null` to leave the range open. See
+  /// [ExportFormat] for the fields of the export.
+  Future<String> exportPaymentsMethodNodePointer({required NodePointer that, required ExportFormat format, int? createdFrom, int? createdUntil, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportPaymentsMethodNodePointerConstMeta;
+
   /// Retrieves a list of known peers.
   Future<List<PeerDetails>> listPeersMethodNodePointer({required NodePointer that, dynamic hint});
 
@@ -236,6 +324,10 @@ abstract class RustLdkNode {
   DropFnType get dropOpaqueMutexNodeSqliteStore;
   ShareFnType get shareOpaqueMutexNodeSqliteStore;
   OpaqueTypeFinalizer get MutexNodeSqliteStoreFinalizer;
+
+  DropFnType get dropOpaqueNodeStore;
+  ShareFnType get shareOpaqueNodeStore;
+  OpaqueTypeFinalizer get NodeStoreFinalizer;
 }
 
 @sealed
@@ -252,6 +344,20 @@ class MutexNodeSqliteStore extends FrbOpaque {
   OpaqueTypeFinalizer get staticFinalizer => bridge.MutexNodeSqliteStoreFinalizer;
 }
 
+@sealed
+class NodeStore extends FrbOpaque {
+  final RustLdkNode bridge;
+  NodeStore.fromRaw(int ptr, int size, this.bridge) : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueNodeStore;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueNodeStore;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.NodeStoreFinalizer;
+}
+
 /// A Bitcoin address.
 ///
 class Address {
@@ -269,6 +375,20 @@ sealed class ChainDataSourceConfig with _$ChainDataSourceConfig {
   ) = ChainDataSourceConfig_Esplora;
 }
 
+/// How a channel was closed.
+///
+enum ChannelCloseType {
+  /// We closed the channel cooperatively via `node.closeChannel()`, or because it violated the
+  /// `InboundChannelPolicy`.
+  ///
+  localCooperative,
+
+  /// The channel was closed by the counterparty or force-closed. LDK Node 0.1.0 doesn't report
+  /// how.
+  ///
+  unknown,
+}
+
 ///Options which apply on a per-channel basis and may change at runtime or based on negotiation with our counterparty.
 class ChannelConfig {
   ///Amount (in millionths of a satoshi) charged per satoshi for payments forwarded outbound over the channel. This may be allowed to change at runtime in a later update, however doing so must result in update messages sent to notify all nodes of our updated relay fee.
@@ -319,6 +439,63 @@ class ChannelConfig {
   });
 }
 
+/// A change to some of the fields of a channel's `ChannelConfig`. Fields left `null` keep their
+/// current value.
+///
+/// Channels whose config is unknown, see `node.channelConfig()`, only accept an update setting
+/// all fields.
+///
+class ChannelConfigUpdate {
+  /// The new `forwardingFeeProportionalMillionths`.
+  ///
+  final int? forwardingFeeProportionalMillionths;
+
+  /// The new `forwardingFeeBaseMsat`.
+  ///
+  final int? forwardingFeeBaseMsat;
+
+  /// The new `cltvExpiryDelta`.
+  ///
+  final int? cltvExpiryDelta;
+
+  /// The new `maxDustHtlcExposureMsat`.
+  ///
+  final int? maxDustHtlcExposureMsat;
+
+  /// The new `forceCloseAvoidanceMaxFeeSatoshis`.
+  ///
+  final int? forceCloseAvoidanceMaxFeeSatoshis;
+
+  const ChannelConfigUpdate({
+    this.forwardingFeeProportionalMillionths,
+    this.forwardingFeeBaseMsat,
+    this.cltvExpiryDelta,
+    this.maxDustHtlcExposureMsat,
+    this.forceCloseAvoidanceMaxFeeSatoshis,
+  });
+}
+
+/// The outcome of updating the config of a single channel via `node.updateAllChannelConfigs()`.
+///
+class ChannelConfigUpdateResult {
+  /// The channel's ID.
+  ///
+  final ChannelId channelId;
+
+  ///The node ID of our the channel’s counterparty.
+  final PublicKey counterpartyNodeId;
+
+  /// Why the update failed, or `null` if it succeeded.
+  ///
+  final String? error;
+
+  const ChannelConfigUpdateResult({
+    required this.channelId,
+    required this.counterpartyNodeId,
+    this.error,
+  });
+}
+
 /// Details of a channel, as returned by node.listChannels()
 ///
 class ChannelDetails {
@@ -335,7 +512,7 @@ class ChannelDetails {
   /// The Channel's funding transaction output, if we've negotiated the funding transaction with
   /// our counterparty already.
   ///
-  final String? fundingTxo;
+  final OutPoint? fundingTxo;
 
   ///The value, in satoshis, of this channel as it appears in the funding output.
   final int channelValueSats;
@@ -431,6 +608,13 @@ class ChannelDetails {
   ///
   final bool isPublic;
 
+  /// The channel's config, as set on channel open or via `node.updateChannelConfig()`.
+  ///
+  /// This is `null` for channels whose config was never set via this package, as LDK Node 0.1.0
+  /// offers no way to read it back.
+  ///
+  final ChannelConfig? config;
+
   const ChannelDetails({
     required this.channelId,
     required this.counterpartyNodeId,
@@ -448,9 +632,75 @@ class ChannelDetails {
     required this.isChannelReady,
     required this.isUsable,
     required this.isPublic,
+    this.config,
+  });
+}
+
+/// A change of a channel's forwarding fees proposed by the fee autopilot.
+///
+class ChannelFeeProposal {
+  /// The channel's ID.
+  ///
+  final ChannelId channelId;
+
+  ///The node ID of our the channel’s counterparty.
+  final PublicKey counterpartyNodeId;
+
+  /// The channel's outbound capacity the proposal is based on.
+  ///
+  final int outboundCapacityMsat;
+
+  /// The channel's inbound capacity the proposal is based on.
+  ///
+  final int inboundCapacityMsat;
+
+  /// The channel's current `forwardingFeeBaseMsat`.
+  ///
+  final int currentBaseFeeMsat;
+
+  /// The channel's current `forwardingFeeProportionalMillionths`.
+  ///
+  final int currentProportionalMillionths;
+
+  /// The proposed `forwardingFeeBaseMsat`.
+  ///
+  final int proposedBaseFeeMsat;
+
+  /// The proposed `forwardingFeeProportionalMillionths`.
+  ///
+  final int proposedProportionalMillionths;
+
+  const ChannelFeeProposal({
+    required this.channelId,
+    required this.counterpartyNodeId,
+    required this.outboundCapacityMsat,
+    required this.inboundCapacityMsat,
+    required this.currentBaseFeeMsat,
+    required this.currentProportionalMillionths,
+    required this.proposedBaseFeeMsat,
+    required this.proposedProportionalMillionths,
   });
 }
 
+@freezed
+sealed class ChannelFilter with _$ChannelFilter {
+  /// All channels.
+  ///
+  const factory ChannelFilter.all() = ChannelFilter_All;
+
+  /// All channels with the given counterparty.
+  ///
+  const factory ChannelFilter.counterparty(
+    PublicKey field0,
+  ) = ChannelFilter_Counterparty;
+
+  /// All announced or unannounced channels, respectively.
+  ///
+  const factory ChannelFilter.visibility(
+    ChannelVisibility field0,
+  ) = ChannelFilter_Visibility;
+}
+
 /// The global identifier of a channel.
 ///
 /// Note that this will start out to be a temporary ID until channel funding negotiation is
@@ -465,6 +715,78 @@ class ChannelId {
   });
 }
 
+/// Whether a channel is announced to the network.
+///
+enum ChannelVisibility {
+  /// The channel is announced, i.e., can be used to route payments of other nodes.
+  ///
+  public,
+
+  /// The channel is unannounced.
+  ///
+  private,
+}
+
+/// Details of a closed channel, as returned by node.listClosedChannels()
+///
+class ClosedChannelDetails {
+  /// The channel's ID.
+  ///
+  final ChannelId channelId;
+
+  /// The userChannelId of the channel.
+  ///
+  final UserChannelId userChannelId;
+
+  ///The node ID of our the channel’s counterparty.
+  final PublicKey counterpartyNodeId;
+
+  ///The value, in satoshis, of this channel as it appears in the funding output.
+  final int channelValueSats;
+
+  /// Our last known balance in the channel before it was closed.
+  ///
+  final int finalBalanceMsat;
+
+  /// The Channel's funding transaction output, if it was negotiated before the channel closed.
+  ///
+  final OutPoint? fundingTxo;
+
+  /// The ID of the transaction that closed the channel, if known.
+  ///
+  /// **Note:** LDK Node 0.1.0 doesn't report the closing transaction, so this is currently
+  /// always `null`.
+  ///
+  final Txid? closingTxid;
+
+  /// How the channel was closed.
+  ///
+  final ChannelCloseType closeType;
+
+  /// When the channel was opened, in seconds since the UNIX epoch.
+  ///
+  /// For channels opened before this was tracked, this is the time the channel was first seen.
+  ///
+  final int openedAt;
+
+  /// When the channel was closed, in seconds since the UNIX epoch.
+  ///
+  final int closedAt;
+
+  const ClosedChannelDetails({
+    required this.channelId,
+    required this.userChannelId,
+    required this.counterpartyNodeId,
+    required this.channelValueSats,
+    required this.finalBalanceMsat,
+    this.fundingTxo,
+    this.closingTxid,
+    required this.closeType,
+    required this.openedAt,
+    required this.closedAt,
+  });
+}
+
 /// Represents the configuration of an [Node] instance.
 ///
 class Config {
@@ -506,6 +828,14 @@ class Config {
   ///
   int defaultCltvExpiryDelta;
 
+  /// The policy inbound channels have to comply with, or `null` to accept all of them.
+  ///
+  InboundChannelPolicy? inboundChannelPolicy;
+
+  /// The settings of the fee autopilot, or `null` to leave forwarding fees alone.
+  ///
+  FeeAutopilotConfig? feeAutopilot;
+
   Config({
     required this.storageDirPath,
     required this.network,
@@ -516,6 +846,8 @@ class Config {
     required this.logLevel,
     this.listeningAddress,
     required this.defaultCltvExpiryDelta,
+    this.inboundChannelPolicy,
+    this.feeAutopilot,
   });
 }
 
@@ -593,6 +925,101 @@ sealed class Event with _$Event {
   }) = Event_ChannelPending;
 }
 
+/// The format of `node.exportPayments()`.
+///
+/// Both formats contain one record per Lightning payment or on-chain transaction, oldest first,
+/// with the following fields in this order:
+///
+/// - `created_at`: when the payment was created, in seconds since the UNIX epoch.
+/// - `updated_at`: when the payment was last updated, in seconds since the UNIX epoch.
+/// - `kind`: `bolt11`, `spontaneous` or `onchain`.
+/// - `direction`: `inbound` or `outbound`.
+/// - `amount_msat`: the amount in millisatoshis, empty if unknown. This is the case for on-chain
+/// transactions sent via `node.sendAllToOnchainAddress()`, as LDK Node 0.1.0 doesn't report the
+/// amount sent.
+/// - `fee_paid_msat`: the fee paid in millisatoshis, empty if unknown.
+/// - `status`: `pending`, `succeeded`, `failed` or `abandoning`, and `broadcast` for on-chain
+/// transactions.
+/// - `payment_hash`: the hex-encoded payment hash of Lightning payments.
+/// - `txid`: the transaction ID of on-chain transactions.
+/// - `address`: the destination address of on-chain transactions.
+/// - `label`: the label set via `node.setPaymentLabel()`.
+/// - `description`: the invoice description of Lightning payments.
+///
+/// **Note:** LDK Node 0.1.0 doesn't expose the transactions of its on-chain wallet, so only
+/// on-chain payments sent via `node.sendToOnchainAddress()` and `node.sendAllToOnchainAddress()`
+/// are included.
+///
+enum ExportFormat {
+  /// Comma-separated values with a header row. Empty fields are left blank.
+  ///
+  /// Text fields starting with `=`, `+`, `-`, `@`, a tab or a carriage return are prefixed with
+  /// a `'`, so spreadsheet software doesn't evaluate labels or invoice descriptions chosen by
+  /// a counterparty as formulas.
+  csv,
+
+  /// A JSON array of objects keyed by field name. Empty fields are `null`.
+  json,
+}
+
+/// Settings of the fee autopilot, which periodically adjusts the forwarding fees of our public
+/// channels to their balance while the node is running.
+///
+/// A channel holding less than the target share of its capacity on our side gets higher fees,
+/// up to the maximums for a drained channel, to discourage forwarding through it. A channel
+/// holding more gets lower fees, down to the minimums for a channel with all of its capacity on
+/// our side. At the target share, fees are halfway between the minimums and maximums.
+///
+/// A channel's fees are only changed once its base or proportional fee would move by at least
+/// 10% of its current value, so small shifts in balance don't broadcast a channel update.
+///
+/// Channels whose config is unknown, see `node.channelConfig()`, are left alone, as updating
+/// their fees would overwrite the rest of their config with guessed values. Set their config via
+/// `node.updateChannelConfig()` once to put them under the autopilot.
+///
+class FeeAutopilotConfig {
+  /// The lowest base fee the autopilot sets.
+  ///
+  final int minBaseFeeMsat;
+
+  /// The highest base fee the autopilot sets.
+  ///
+  final int maxBaseFeeMsat;
+
+  /// The lowest proportional fee the autopilot sets.
+  ///
+  final int minProportionalMillionths;
+
+  /// The highest proportional fee the autopilot sets.
+  ///
+  final int maxProportionalMillionths;
+
+  /// The share of a channel's capacity we aim to hold on our side, in percent. Must be between
+  /// 1 and 99.
+  ///
+  final int targetOutboundPercent;
+
+  /// The time in-between fee adjustments, in seconds.
+  /// Note: A minimum of 60 seconds is always enforced.
+  ///
+  final int intervalSecs;
+
+  /// If set, fees are never changed automatically and the proposed changes can only be
+  /// retrieved via `node.feeAutopilotProposals()`.
+  ///
+  final bool dryRun;
+
+  const FeeAutopilotConfig({
+    required this.minBaseFeeMsat,
+    required this.maxBaseFeeMsat,
+    required this.minProportionalMillionths,
+    required this.maxProportionalMillionths,
+    required this.targetOutboundPercent,
+    required this.intervalSecs,
+    required this.dryRun,
+  });
+}
+
 @freezed
 sealed class GossipSourceConfig with _$GossipSourceConfig {
   const factory GossipSourceConfig.p2PNetwork() = GossipSourceConfig_P2PNetwork;
@@ -601,6 +1028,40 @@ sealed class GossipSourceConfig with _$GossipSourceConfig {
   ) = GossipSourceConfig_RapidGossipSync;
 }
 
+/// Requirements inbound channels have to meet.
+///
+/// **Note:** LDK Node 0.1.0 accepts inbound channels on its own, so the policy is enforced once
+/// a channel is pending: the `Event.channelPending` of a channel violating the policy is not
+/// emitted and the channel is closed right away, and neither are its later events, such as
+/// `Event.channelClosed`. This happens while the app retrieves events via `node.nextEvent()` or
+/// `node.waitNextEvent()` only, so channels arriving while it doesn't poll stay open until it
+/// does. If closing a channel fails, its `Event.channelPending` is emitted as usual.
+///
+class InboundChannelPolicy {
+  /// Only accept channels from these peers. An empty list accepts channels from any peer.
+  ///
+  final List<PublicKey> allowedPeers;
+
+  /// Only accept channels of at least this capacity.
+  ///
+  final int? minChannelSizeSats;
+
+  /// Only accept channels that are announced or unannounced, respectively.
+  ///
+  final ChannelVisibility? requiredVisibility;
+
+  /// Only accept up to this many inbound channels that are not ready yet at the same time.
+  ///
+  final int? maxPendingChannels;
+
+  const InboundChannelPolicy({
+    required this.allowedPeers,
+    this.minChannelSizeSats,
+    this.requiredVisibility,
+    this.maxPendingChannels,
+  });
+}
+
 ///Represents a syntactically and semantically correct lightning BOLT11 invoice.
 ///
 class Invoice {
@@ -652,14 +1113,54 @@ class Mnemonic {
 
 @freezed
 sealed class NetAddress with _$NetAddress {
+  ///An IPv4 address/port on which the peer is listening.
+  ///
   const factory NetAddress.iPv4({
     required String addr,
     required int port,
   }) = NetAddress_IPv4;
+
+  ///An IPv6 address/port on which the peer is listening.
+  ///
   const factory NetAddress.iPv6({
     required String addr,
     required int port,
   }) = NetAddress_IPv6;
+
+  ///An old-style Tor onion address/port on which the peer is listening.
+  ///
+  /// The 12 bytes are the 10 byte onion address followed by the big-endian port. This field is deprecated and the Tor network generally no longer supports V2 Onion addresses.
+  const factory NetAddress.onionV2({
+    required U8Array12 addr,
+  }) = NetAddress_OnionV2;
+
+  ///A new-style Tor onion address/port on which the peer is listening.
+  ///
+  /// To create the human-readable "hostname", concatenate the ED25519 pubkey, checksum, and version, wrap as base32 and append ".onion".
+  const factory NetAddress.onionV3({
+    ///The ed25519 long-term public key of the peer.
+    ///
+    required U8Array32 pubkey,
+
+    ///The checksum of the pubkey and version, as included in the onion address.
+    ///
+    required int checksum,
+
+    ///The version byte, as defined by the Tor Onion v3 spec.
+    ///
+    required int version,
+
+    ///The port on which the node is listening.
+    ///
+    required int port,
+  }) = NetAddress_OnionV3;
+
+  ///A hostname/port on which the peer is listening.
+  ///
+  const factory NetAddress.hostname({
+    required String host,
+    required int port,
+  }) = NetAddress_Hostname;
 }
 
 /// Bitcoin network enum
@@ -685,10 +1186,12 @@ enum Network {
 class NodePointer {
   final RustLdkNode bridge;
   final MutexNodeSqliteStore field0;
+  final NodeStore field1;
 
   const NodePointer({
     required this.bridge,
     required this.field0,
+    required this.field1,
   });
 
   /// Starts the necessary background tasks, such as handling events coming from user input,
@@ -775,6 +1278,11 @@ class NodePointer {
         that: this,
       );
 
+  /// Retrieves the channels that were closed, as far as they were known to this package.
+  Future<List<ClosedChannelDetails>> listClosedChannels({dynamic hint}) => bridge.listClosedChannelsMethodNodePointer(
+        that: this,
+      );
+
   /// Connect to a node on the peer-to-peer network.
   ///
   /// If `permanently` is set to `true`, we'll remember the peer and reconnect to it on restart.
@@ -827,26 +1335,67 @@ class NodePointer {
       );
 
   /// Close a previously opened channel.
+  ///
+  /// The channel is closed cooperatively, with the closing fee negotiated by LDK and the funds
+  /// sent to the on-chain wallet. LDK Node 0.1.0 offers no way to force-close a channel.
   Future<void> closeChannel({required ChannelId channelId, required PublicKey counterpartyNodeId, dynamic hint}) => bridge.closeChannelMethodNodePointer(
         that: this,
         channelId: channelId,
         counterpartyNodeId: counterpartyNodeId,
       );
 
+  /// Returns the config of a previously opened channel.
+  ///
+  /// This is the config set on channel open or via `node.updateChannelConfig()`, or `null` if it
+  /// was never set via this package, as LDK Node 0.1.0 offers no way to read it back.
+  Future<ChannelConfig?> channelConfig({required ChannelId channelId, dynamic hint}) => bridge.channelConfigMethodNodePointer(
+        that: this,
+        channelId: channelId,
+      );
+
   ///Update the config for a previously opened channel.
   ///
-  Future<void> updateChannelConfig({required ChannelId channelId, required PublicKey counterpartyNodeId, required ChannelConfig channelConfig, dynamic hint}) =>
+  /// Only the fields set in `update` are changed, all others keep their current value. Throws
+  /// if `node.channelConfig()` returns `null` for the channel and not all fields are set, as
+  /// their current value is unknown then.
+  Future<void> updateChannelConfig({required ChannelId channelId, required PublicKey counterpartyNodeId, required ChannelConfigUpdate update, dynamic hint}) =>
       bridge.updateChannelConfigMethodNodePointer(
         that: this,
         channelId: channelId,
         counterpartyNodeId: counterpartyNodeId,
-        channelConfig: channelConfig,
+        update: update,
+      );
+
+  /// Updates the config of all channels matching the given filter.
+  ///
+  /// Only the fields set in `update` are changed, all others keep their current value. Channels
+  /// whose config is unknown are only updated if all fields are set. A failed update doesn't
+  /// stop the remaining channels from being updated; the outcome is reported
+  /// per channel.
+  ///
+  /// Throws if the node ID of a `ChannelFilter.counterparty()` filter is invalid.
+  Future<List<ChannelConfigUpdateResult>> updateAllChannelConfigs({required ChannelConfigUpdate update, required ChannelFilter filter, dynamic hint}) =>
+      bridge.updateAllChannelConfigsMethodNodePointer(
+        that: this,
+        update: update,
+        filter: filter,
+      );
+
+  /// Returns the changes the fee autopilot would currently make to the forwarding fees of our
+  /// channels, without applying them.
+  ///
+  /// Throws if no `Config.feeAutopilot` is set.
+  Future<List<ChannelFeeProposal>> feeAutopilotProposals({dynamic hint}) => bridge.feeAutopilotProposalsMethodNodePointer(
+        that: this,
       );
 
   /// Send a payement given an invoice.
-  Future<PaymentHash> sendPayment({required Invoice invoice, dynamic hint}) => bridge.sendPaymentMethodNodePointer(
+  ///
+  /// If `retryStrategy` is set, the payment is sent again according to it when it fails.
+  Future<PaymentHash> sendPayment({required Invoice invoice, RetryStrategy? retryStrategy, dynamic hint}) => bridge.sendPaymentMethodNodePointer(
         that: this,
         invoice: invoice,
+        retryStrategy: retryStrategy,
       );
 
   /// Send a payment given an invoice and an amount in millisatoshi.
@@ -855,17 +1404,26 @@ class NodePointer {
   ///
   /// This can be used to pay a so-called "zero-amount" invoice, i.e., an invoice that leaves the
   /// amount paid to be determined by the user.
-  Future<PaymentHash> sendPaymentUsingAmount({required Invoice invoice, required int amountMsat, dynamic hint}) => bridge.sendPaymentUsingAmountMethodNodePointer(
+  ///
+  /// If `retryStrategy` is set, the payment is sent again according to it when it fails.
+  Future<PaymentHash> sendPaymentUsingAmount({required Invoice invoice, required int amountMsat, RetryStrategy? retryStrategy, dynamic hint}) => bridge.sendPaymentUsingAmountMethodNodePointer(
         that: this,
         invoice: invoice,
         amountMsat: amountMsat,
+        retryStrategy: retryStrategy,
       );
 
   /// Send a spontaneous, aka. "keysend", payment
-  Future<PaymentHash> sendSpontaneousPayment({required int amountMsat, required PublicKey nodeId, dynamic hint}) => bridge.sendSpontaneousPaymentMethodNodePointer(
+  ///
+  /// If `retryStrategy` is set, the payment is sent again according to it when it fails. Every
+  /// attempt uses a new payment hash, but the payment stays known by the returned hash of its
+  /// first attempt: its events and `PaymentDetails` carry that hash, and it is the one to pass
+  /// to `node.payment()` or `node.abandonPayment()`.
+  Future<PaymentHash> sendSpontaneousPayment({required int amountMsat, required PublicKey nodeId, RetryStrategy? retryStrategy, dynamic hint}) => bridge.sendSpontaneousPaymentMethodNodePointer(
         that: this,
         amountMsat: amountMsat,
         nodeId: nodeId,
+        retryStrategy: retryStrategy,
       );
 
   /// Returns a payable invoice that can be used to request and receive a payment of the amount
@@ -901,6 +1459,26 @@ class NodePointer {
         paymentHash: paymentHash,
       );
 
+  /// Gives up on the pending outbound payment with the given hash.
+  ///
+  /// No further retries are made, and the payment's status changes to `Abandoning` until LDK
+  /// resolves its in-flight HTLCs, after which it is marked as failed and an
+  /// `Event.paymentFailed` is emitted.
+  ///
+  /// **Note:** LDK Node 0.1.0 can't cancel HTLCs that are already in flight, so an abandoned
+  /// payment may still succeed, in which case an `Event.paymentSuccessful` is emitted instead.
+  Future<void> abandonPayment({required PaymentHash paymentHash, dynamic hint}) => bridge.abandonPaymentMethodNodePointer(
+        that: this,
+        paymentHash: paymentHash,
+      );
+
+  /// Sets the label of the payment with the given hash, or clears it if `label` is `null`.
+  Future<void> setPaymentLabel({required PaymentHash paymentHash, String? label, dynamic hint}) => bridge.setPaymentLabelMethodNodePointer(
+        that: this,
+        paymentHash: paymentHash,
+        label: label,
+      );
+
   /// Retrieves all payments that match the given predicate.
   ///
   Future<List<PaymentDetails>> listPaymentsWithFilter({required PaymentDirection paymentDirection, dynamic hint}) => bridge.listPaymentsWithFilterMethodNodePointer(
@@ -908,11 +1486,33 @@ class NodePointer {
         paymentDirection: paymentDirection,
       );
 
+  /// Retrieves the payments matching the given query, one page at a time.
+  ///
+  /// Payments are sorted by their creation time. To retrieve the following page, call this
+  /// again with `query.cursor` set to the `nextCursor` of the returned page.
+  Future<PaymentPage> queryPayments({required PaymentQuery query, dynamic hint}) => bridge.queryPaymentsMethodNodePointer(
+        that: this,
+        query: query,
+      );
+
   /// Retrieves all payments.
   Future<List<PaymentDetails>> listPayments({dynamic hint}) => bridge.listPaymentsMethodNodePointer(
         that: this,
       );
 
+  /// Exports the Lightning payments and on-chain transactions created in the given time range,
+  /// e.g. for accounting.
+  ///
+  /// `createdFrom` and `createdUntil` are in seconds since the UNIX epoch; the former is
+  /// inclusive, the latter exclusive, and either may be `null` to leave the range open. See
+  /// [ExportFormat] for the fields of the export.
+  Future<String> exportPayments({required ExportFormat format, int? createdFrom, int? createdUntil, dynamic hint}) => bridge.exportPaymentsMethodNodePointer(
+        that: this,
+        format: format,
+        createdFrom: createdFrom,
+        createdUntil: createdUntil,
+      );
+
   /// Retrieves a list of known peers.
   Future<List<PeerDetails>> listPeers({dynamic hint}) => bridge.listPeersMethodNodePointer(
         that: this,
@@ -958,6 +1558,9 @@ class PaymentDetails {
   final PaymentHash hash;
 
   /// The pre-image used by the payment.
+  ///
+  /// For a retried spontaneous payment, this is the pre-image of its latest attempt, while
+  /// `hash` stays the hash of its first attempt.
   final PaymentPreimage? preimage;
 
   /// The secret used by the payment.
@@ -972,6 +1575,41 @@ class PaymentDetails {
   /// The status of the payment.
   final PaymentStatus status;
 
+  /// The kind of the payment.
+  final PaymentKind kind;
+
+  /// How many times an outbound payment was sent, including retries. Always 0 for inbound
+  /// payments.
+  final int attempts;
+
+  /// The time the payment was created, in seconds since the UNIX epoch.
+  ///
+  /// For payments made before this was tracked, this is the time the payment was first seen.
+  /// It is only kept across restarts once the payment is updated, e.g. by setting its label.
+  final int createdAt;
+
+  /// The time the payment was last updated, in seconds since the UNIX epoch.
+  final int updatedAt;
+
+  /// The routing fee paid for an outbound payment, if known.
+  ///
+  /// **Note:** LDK Node 0.1.0 doesn't report the fees paid, so this is currently always `null`.
+  final int? feePaidMsat;
+
+  /// The BOLT11 invoice this payment was made to or received for, if any.
+  final Invoice? bolt11;
+
+  /// The description of the invoice, if it had a plain text description.
+  final String? description;
+
+  /// A user-provided label, as set via `node.setPaymentLabel()`.
+  final String? label;
+
+  /// The node ID of the counterparty, if known.
+  ///
+  /// This is the payee for outbound payments, and unknown for inbound ones.
+  final PublicKey? counterpartyNodeId;
+
   const PaymentDetails({
     required this.hash,
     this.preimage,
@@ -979,6 +1617,15 @@ class PaymentDetails {
     this.amountMsat,
     required this.direction,
     required this.status,
+    required this.kind,
+    required this.attempts,
+    required this.createdAt,
+    required this.updatedAt,
+    this.feePaidMsat,
+    this.bolt11,
+    this.description,
+    this.label,
+    this.counterpartyNodeId,
   });
 }
 
@@ -1002,6 +1649,36 @@ class PaymentHash {
   });
 }
 
+/// Represents the kind of a payment.
+///
+enum PaymentKind {
+  /// A payment to or from a BOLT11 invoice.
+  bolt11,
+
+  /// A spontaneous, aka. "keysend", payment.
+  spontaneous,
+}
+
+/// A page of payments, as returned by `node.queryPayments()`.
+///
+class PaymentPage {
+  /// The payments on this page.
+  final List<PaymentDetails> payments;
+
+  /// The cursor to pass in `PaymentQuery.cursor` to retrieve the next page, or `null` if this
+  /// is the last page.
+  final String? nextCursor;
+
+  /// The total number of payments matching the query, across all pages.
+  final int totalCount;
+
+  const PaymentPage({
+    required this.payments,
+    this.nextCursor,
+    required this.totalCount,
+  });
+}
+
 /// paymentPreimage type, use to route payment between hop
 ///
 class PaymentPreimage {
@@ -1012,6 +1689,59 @@ class PaymentPreimage {
   });
 }
 
+/// Criteria for `node.queryPayments()`. A payment is returned if it matches all of the criteria
+/// that are set.
+///
+class PaymentQuery {
+  /// Only return payments in this direction.
+  final PaymentDirection? direction;
+
+  /// Only return payments with one of these statuses. An empty list matches any status.
+  final List<PaymentStatus> statuses;
+
+  /// Only return payments created at or after this time, in seconds since the UNIX epoch.
+  final int? createdFrom;
+
+  /// Only return payments created before this time, in seconds since the UNIX epoch.
+  final int? createdUntil;
+
+  /// Only return payments of at least this amount. Payments of unknown amount never match.
+  final int? minAmountMsat;
+
+  /// Only return payments of at most this amount. Payments of unknown amount never match.
+  final int? maxAmountMsat;
+
+  /// Only return payments whose label contains this text, ignoring case.
+  final String? labelContains;
+
+  /// Only return payments of this kind.
+  final PaymentKind? kind;
+
+  /// The order in which payments are returned.
+  final PaymentSortOrder sortOrder;
+
+  /// The `nextCursor` of the previous page, or `null` to start from the first page.
+  final String? cursor;
+
+  /// The maximum number of payments returned in one page, or `null` to return all of them. Must
+  /// be greater than 0.
+  final int? limit;
+
+  const PaymentQuery({
+    this.direction,
+    required this.statuses,
+    this.createdFrom,
+    this.createdUntil,
+    this.minAmountMsat,
+    this.maxAmountMsat,
+    this.labelContains,
+    this.kind,
+    required this.sortOrder,
+    this.cursor,
+    this.limit,
+  });
+}
+
 /// payment_secret type, use to authenticate sender to the receiver and tie MPP HTLCs together
 ///
 class PaymentSecret {
@@ -1022,6 +1752,16 @@ class PaymentSecret {
   });
 }
 
+/// The order in which `node.queryPayments()` returns payments.
+///
+enum PaymentSortOrder {
+  /// Most recently created payments first.
+  newestFirst,
+
+  /// Least recently created payments first.
+  oldestFirst,
+}
+
 /// Represents the current status of a payment.
 ///
 enum PaymentStatus {
@@ -1033,6 +1773,10 @@ enum PaymentStatus {
 
   /// The payment failed.
   failed,
+
+  /// The payment was abandoned via `node.abandonPayment()` and will be marked as failed once
+  /// all of its HTLCs are resolved.
+  abandoning,
 }
 
 /// Details of a known Lightning peer as returned by `node.listPeers`.
@@ -1067,6 +1811,19 @@ class PublicKey {
   });
 }
 
+@freezed
+sealed class RetryStrategy with _$RetryStrategy {
+  /// Send the payment again up to this many times after the first attempt failed.
+  const factory RetryStrategy.attempts(
+    int field0,
+  ) = RetryStrategy_Attempts;
+
+  /// Keep sending the payment again until this many seconds passed since the first attempt.
+  const factory RetryStrategy.timeoutSecs(
+    int field0,
+  ) = RetryStrategy_TimeoutSecs;
+}
+
 ///A bitcoin transaction hash/transaction ID.
 ///
 class Txid {
@@ -1077,6 +1834,15 @@ class Txid {
   });
 }
 
+class U8Array12 extends NonGrowableListView<int> {
+  static const arraySize = 12;
+  U8Array12(Uint8List inner)
+      : assert(inner.length == arraySize),
+        super(inner);
+  U8Array12.unchecked(Uint8List inner) : super(inner);
+  U8Array12.init() : super(Uint8List(arraySize));
+}
+
 class U8Array32 extends NonGrowableListView<int> {
   static const arraySize = 32;
   U8Array32(Uint8List inner)
@@ -204,61 +204,61 @@ abstract class ChainDataSourceConfig_Esplora implements ChainDataSourceConfig {
 }
 
 /// @nodoc
-mixin _$EntropySourceConfig {
+mixin _$ChannelFilter {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) seedFile,
-    required TResult Function(U8Array64 field0) seedBytes,
-    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
+    required TResult Function() all,
+    required TResult Function(PublicKey field0) counterparty,
+    required TResult Function(ChannelVisibility field0) visibility,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? seedFile,
-    TResult? Function(U8Array64 field0)? seedBytes,
-    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult? Function()? all,
+    TResult? Function(PublicKey field0)? counterparty,
+    TResult? Function(ChannelVisibility field0)? visibility,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? seedFile,
-    TResult Function(U8Array64 field0)? seedBytes,
-    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult Function()? all,
+    TResult Function(PublicKey field0)? counterparty,
+    TResult Function(ChannelVisibility field0)? visibility,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
-    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
-    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
+    required TResult Function(ChannelFilter_All value) all,
+    required TResult Function(ChannelFilter_Counterparty value) counterparty,
+    required TResult Function(ChannelFilter_Visibility value) visibility,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult? Function(ChannelFilter_All value)? all,
+    TResult? Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult? Function(ChannelFilter_Visibility value)? visibility,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult Function(ChannelFilter_All value)? all,
+    TResult Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult Function(ChannelFilter_Visibility value)? visibility,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $EntropySourceConfigCopyWith<$Res> {
-  factory $EntropySourceConfigCopyWith(EntropySourceConfig value, $Res Function(EntropySourceConfig) then) = _$EntropySourceConfigCopyWithImpl<$Res, EntropySourceConfig>;
+abstract class $ChannelFilterCopyWith<$Res> {
+  factory $ChannelFilterCopyWith(ChannelFilter value, $Res Function(ChannelFilter) then) = _$ChannelFilterCopyWithImpl<$Res, ChannelFilter>;
 }
 
 /// @nodoc
-class _$EntropySourceConfigCopyWithImpl<$Res, $Val extends EntropySourceConfig> implements $EntropySourceConfigCopyWith<$Res> {
-  _$EntropySourceConfigCopyWithImpl(this._value, this._then);
+class _$ChannelFilterCopyWithImpl<$Res, $Val extends ChannelFilter> implements $ChannelFilterCopyWith<$Res> {
+  _$ChannelFilterCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -267,86 +267,63 @@ class _$EntropySourceConfigCopyWithImpl<$Res, $Val extends EntropySourceConfig>
 }
 
 /// @nodoc
-abstract class _$$EntropySourceConfig_SeedFileCopyWith<$Res> {
-  factory _$$EntropySourceConfig_SeedFileCopyWith(_$EntropySourceConfig_SeedFile value, $Res Function(_$EntropySourceConfig_SeedFile) then) = __$$EntropySourceConfig_SeedFileCopyWithImpl<$Res>;
-  @useResult
-  $Res call({String field0});
+abstract class _$$ChannelFilter_AllCopyWith<$Res> {
+  factory _$$ChannelFilter_AllCopyWith(_$ChannelFilter_All value, $Res Function(_$ChannelFilter_All) then) = __$$ChannelFilter_AllCopyWithImpl<$Res>;
 }
 
 /// @nodoc
-class __$$EntropySourceConfig_SeedFileCopyWithImpl<$Res> extends _$EntropySourceConfigCopyWithImpl<$Res, _$EntropySourceConfig_SeedFile> implements _$$EntropySourceConfig_SeedFileCopyWith<$Res> {
-  __$$EntropySourceConfig_SeedFileCopyWithImpl(_$EntropySourceConfig_SeedFile _value, $Res Function(_$EntropySourceConfig_SeedFile) _then) : super(_value, _then);
-
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? field0 = null,
-  }) {
-    return _then(_$EntropySourceConfig_SeedFile(
-      null == field0
-          ? _value.field0
-          : field0 // ignore: cast_nullable_to_non_nullable
-              as String,
-    ));
-  }
+class __$$ChannelFilter_AllCopyWithImpl<$Res> extends _$ChannelFilterCopyWithImpl<$Res, _$ChannelFilter_All> implements _$$ChannelFilter_AllCopyWith<$Res> {
+  __$$ChannelFilter_AllCopyWithImpl(_$ChannelFilter_All _value, $Res Function(_$ChannelFilter_All) _then) : super(_value, _then);
 }
 
 /// @nodoc
 
-class _$EntropySourceConfig_SeedFile implements EntropySourceConfig_SeedFile {
-  const _$EntropySourceConfig_SeedFile(this.field0);
-
-  @override
-  final String field0;
+class _$ChannelFilter_All implements ChannelFilter_All {
+  const _$ChannelFilter_All();
 
   @override
   String toString() {
-    return 'EntropySourceConfig.seedFile(field0: $field0)';
+    return 'ChannelFilter.all()';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$EntropySourceConfig_SeedFile && (identical(other.field0, field0) || other.field0 == field0));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$ChannelFilter_All);
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, field0);
-
-  @JsonKey(ignore: true)
-  @override
-  @pragma('vm:prefer-inline')
-  _$$EntropySourceConfig_SeedFileCopyWith<_$EntropySourceConfig_SeedFile> get copyWith => __$$EntropySourceConfig_SeedFileCopyWithImpl<_$EntropySourceConfig_SeedFile>(this, _$identity);
+  int get hashCode => runtimeType.hashCode;
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) seedFile,
-    required TResult Function(U8Array64 field0) seedBytes,
-    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
+    required TResult Function() all,
+    required TResult Function(PublicKey field0) counterparty,
+    required TResult Function(ChannelVisibility field0) visibility,
   }) {
-    return seedFile(field0);
+    return all();
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? seedFile,
-    TResult? Function(U8Array64 field0)? seedBytes,
-    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult? Function()? all,
+    TResult? Function(PublicKey field0)? counterparty,
+    TResult? Function(ChannelVisibility field0)? visibility,
   }) {
-    return seedFile?.call(field0);
+    return all?.call();
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? seedFile,
-    TResult Function(U8Array64 field0)? seedBytes,
-    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult Function()? all,
+    TResult Function(PublicKey field0)? counterparty,
+    TResult Function(ChannelVisibility field0)? visibility,
     required TResult orElse(),
   }) {
-    if (seedFile != null) {
-      return seedFile(field0);
+    if (all != null) {
+      return all();
     }
     return orElse();
   }
@@ -354,127 +331,123 @@ class _$EntropySourceConfig_SeedFile implements EntropySourceConfig_SeedFile {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
-    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
-    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
+    required TResult Function(ChannelFilter_All value) all,
+    required TResult Function(ChannelFilter_Counterparty value) counterparty,
+    required TResult Function(ChannelFilter_Visibility value) visibility,
   }) {
-    return seedFile(this);
+    return all(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult? Function(ChannelFilter_All value)? all,
+    TResult? Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult? Function(ChannelFilter_Visibility value)? visibility,
   }) {
-    return seedFile?.call(this);
+    return all?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult Function(ChannelFilter_All value)? all,
+    TResult Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult Function(ChannelFilter_Visibility value)? visibility,
     required TResult orElse(),
   }) {
-    if (seedFile != null) {
-      return seedFile(this);
+    if (all != null) {
+      return all(this);
     }
     return orElse();
   }
 }
 
-abstract class EntropySourceConfig_SeedFile implements EntropySourceConfig {
-  const factory EntropySourceConfig_SeedFile(final String field0) = _$EntropySourceConfig_SeedFile;
-
-  String get field0;
-  @JsonKey(ignore: true)
-  _$$EntropySourceConfig_SeedFileCopyWith<_$EntropySourceConfig_SeedFile> get copyWith => throw _privateConstructorUsedError;
+abstract class ChannelFilter_All implements ChannelFilter {
+  const factory ChannelFilter_All() = _$ChannelFilter_All;
 }
 
 /// @nodoc
-abstract class _$$EntropySourceConfig_SeedBytesCopyWith<$Res> {
-  factory _$$EntropySourceConfig_SeedBytesCopyWith(_$EntropySourceConfig_SeedBytes value, $Res Function(_$EntropySourceConfig_SeedBytes) then) = __$$EntropySourceConfig_SeedBytesCopyWithImpl<$Res>;
+abstract class _$$ChannelFilter_CounterpartyCopyWith<$Res> {
+  factory _$$ChannelFilter_CounterpartyCopyWith(_$ChannelFilter_Counterparty value, $Res Function(_$ChannelFilter_Counterparty) then) = __$$ChannelFilter_CounterpartyCopyWithImpl<$Res>;
   @useResult
-  $Res call({U8Array64 field0});
+  $Res call({PublicKey field0});
 }
 
 /// @nodoc
-class __$$EntropySourceConfig_SeedBytesCopyWithImpl<$Res> extends _$EntropySourceConfigCopyWithImpl<$Res, _$EntropySourceConfig_SeedBytes> implements _$$EntropySourceConfig_SeedBytesCopyWith<$Res> {
-  __$$EntropySourceConfig_SeedBytesCopyWithImpl(_$EntropySourceConfig_SeedBytes _value, $Res Function(_$EntropySourceConfig_SeedBytes) _then) : super(_value, _then);
+class __$$ChannelFilter_CounterpartyCopyWithImpl<$Res> extends _$ChannelFilterCopyWithImpl<$Res, _$ChannelFilter_Counterparty> implements _$$ChannelFilter_CounterpartyCopyWith<$Res> {
+  __$$ChannelFilter_CounterpartyCopyWithImpl(_$ChannelFilter_Counterparty _value, $Res Function(_$ChannelFilter_Counterparty) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
     Object? field0 = null,
   }) {
-    return _then(_$EntropySourceConfig_SeedBytes(
+    return _then(_$ChannelFilter_Counterparty(
       null == field0
           ? _value.field0
           : field0 // ignore: cast_nullable_to_non_nullable
-              as U8Array64,
+              as PublicKey,
     ));
   }
 }
 
 /// @nodoc
 
-class _$EntropySourceConfig_SeedBytes implements EntropySourceConfig_SeedBytes {
-  const _$EntropySourceConfig_SeedBytes(this.field0);
+class _$ChannelFilter_Counterparty implements ChannelFilter_Counterparty {
+  const _$ChannelFilter_Counterparty(this.field0);
 
   @override
-  final U8Array64 field0;
+  final PublicKey field0;
 
   @override
   String toString() {
-    return 'EntropySourceConfig.seedBytes(field0: $field0)';
+    return 'ChannelFilter.counterparty(field0: $field0)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$EntropySourceConfig_SeedBytes && const DeepCollectionEquality().equals(other.field0, field0));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$ChannelFilter_Counterparty && (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(field0));
+  int get hashCode => Object.hash(runtimeType, field0);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$EntropySourceConfig_SeedBytesCopyWith<_$EntropySourceConfig_SeedBytes> get copyWith => __$$EntropySourceConfig_SeedBytesCopyWithImpl<_$EntropySourceConfig_SeedBytes>(this, _$identity);
+  _$$ChannelFilter_CounterpartyCopyWith<_$ChannelFilter_Counterparty> get copyWith => __$$ChannelFilter_CounterpartyCopyWithImpl<_$ChannelFilter_Counterparty>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) seedFile,
-    required TResult Function(U8Array64 field0) seedBytes,
-    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
+    required TResult Function() all,
+    required TResult Function(PublicKey field0) counterparty,
+    required TResult Function(ChannelVisibility field0) visibility,
   }) {
-    return seedBytes(field0);
+    return counterparty(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? seedFile,
-    TResult? Function(U8Array64 field0)? seedBytes,
-    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult? Function()? all,
+    TResult? Function(PublicKey field0)? counterparty,
+    TResult? Function(ChannelVisibility field0)? visibility,
   }) {
-    return seedBytes?.call(field0);
+    return counterparty?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? seedFile,
-    TResult Function(U8Array64 field0)? seedBytes,
-    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult Function()? all,
+    TResult Function(PublicKey field0)? counterparty,
+    TResult Function(ChannelVisibility field0)? visibility,
     required TResult orElse(),
   }) {
-    if (seedBytes != null) {
-      return seedBytes(field0);
+    if (counterparty != null) {
+      return counterparty(field0);
     }
     return orElse();
   }
@@ -482,141 +455,127 @@ class _$EntropySourceConfig_SeedBytes implements EntropySourceConfig_SeedBytes {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
-    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
-    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
+    required TResult Function(ChannelFilter_All value) all,
+    required TResult Function(ChannelFilter_Counterparty value) counterparty,
+    required TResult Function(ChannelFilter_Visibility value) visibility,
   }) {
-    return seedBytes(this);
+    return counterparty(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult? Function(ChannelFilter_All value)? all,
+    TResult? Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult? Function(ChannelFilter_Visibility value)? visibility,
   }) {
-    return seedBytes?.call(this);
+    return counterparty?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult Function(ChannelFilter_All value)? all,
+    TResult Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult Function(ChannelFilter_Visibility value)? visibility,
     required TResult orElse(),
   }) {
-    if (seedBytes != null) {
-      return seedBytes(this);
+    if (counterparty != null) {
+      return counterparty(this);
     }
     return orElse();
   }
 }
 
-abstract class EntropySourceConfig_SeedBytes implements EntropySourceConfig {
-  const factory EntropySourceConfig_SeedBytes(final U8Array64 field0) = _$EntropySourceConfig_SeedBytes;
+abstract class ChannelFilter_Counterparty implements ChannelFilter {
+  const factory ChannelFilter_Counterparty(final PublicKey field0) = _$ChannelFilter_Counterparty;
 
-  U8Array64 get field0;
+  PublicKey get field0;
   @JsonKey(ignore: true)
-  _$$EntropySourceConfig_SeedBytesCopyWith<_$EntropySourceConfig_SeedBytes> get copyWith => throw _privateConstructorUsedError;
+  _$$ChannelFilter_CounterpartyCopyWith<_$ChannelFilter_Counterparty> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$EntropySourceConfig_Bip39MnemonicCopyWith<$Res> {
-  factory _$$EntropySourceConfig_Bip39MnemonicCopyWith(_$EntropySourceConfig_Bip39Mnemonic value, $Res Function(_$EntropySourceConfig_Bip39Mnemonic) then) =
-      __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl<$Res>;
+abstract class _$$ChannelFilter_VisibilityCopyWith<$Res> {
+  factory _$$ChannelFilter_VisibilityCopyWith(_$ChannelFilter_Visibility value, $Res Function(_$ChannelFilter_Visibility) then) = __$$ChannelFilter_VisibilityCopyWithImpl<$Res>;
   @useResult
-  $Res call({Mnemonic mnemonic, String? passphrase});
+  $Res call({ChannelVisibility field0});
 }
 
 /// @nodoc
-class __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl<$Res> extends _$EntropySourceConfigCopyWithImpl<$Res, _$EntropySourceConfig_Bip39Mnemonic>
-    implements _$$EntropySourceConfig_Bip39MnemonicCopyWith<$Res> {
-  __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl(_$EntropySourceConfig_Bip39Mnemonic _value, $Res Function(_$EntropySourceConfig_Bip39Mnemonic) _then) : super(_value, _then);
+class __$$ChannelFilter_VisibilityCopyWithImpl<$Res> extends _$ChannelFilterCopyWithImpl<$Res, _$ChannelFilter_Visibility> implements _$$ChannelFilter_VisibilityCopyWith<$Res> {
+  __$$ChannelFilter_VisibilityCopyWithImpl(_$ChannelFilter_Visibility _value, $Res Function(_$ChannelFilter_Visibility) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? mnemonic = null,
-    Object? passphrase = freezed,
+    Object? field0 = null,
   }) {
-    return _then(_$EntropySourceConfig_Bip39Mnemonic(
-      mnemonic: null == mnemonic
-          ? _value.mnemonic
-          : mnemonic // ignore: cast_nullable_to_non_nullable
-              as Mnemonic,
-      passphrase: freezed == passphrase
-          ? _value.passphrase
-          : passphrase // ignore: cast_nullable_to_non_nullable
-              as String?,
+    return _then(_$ChannelFilter_Visibility(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as ChannelVisibility,
     ));
   }
 }
 
 /// @nodoc
 
-class _$EntropySourceConfig_Bip39Mnemonic implements EntropySourceConfig_Bip39Mnemonic {
-  const _$EntropySourceConfig_Bip39Mnemonic({required this.mnemonic, this.passphrase});
+class _$ChannelFilter_Visibility implements ChannelFilter_Visibility {
+  const _$ChannelFilter_Visibility(this.field0);
 
   @override
-  final Mnemonic mnemonic;
-  @override
-  final String? passphrase;
+  final ChannelVisibility field0;
 
   @override
   String toString() {
-    return 'EntropySourceConfig.bip39Mnemonic(mnemonic: $mnemonic, passphrase: $passphrase)';
+    return 'ChannelFilter.visibility(field0: $field0)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$EntropySourceConfig_Bip39Mnemonic &&
-            (identical(other.mnemonic, mnemonic) || other.mnemonic == mnemonic) &&
-            (identical(other.passphrase, passphrase) || other.passphrase == passphrase));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$ChannelFilter_Visibility && (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, mnemonic, passphrase);
+  int get hashCode => Object.hash(runtimeType, field0);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$EntropySourceConfig_Bip39MnemonicCopyWith<_$EntropySourceConfig_Bip39Mnemonic> get copyWith =>
-      __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl<_$EntropySourceConfig_Bip39Mnemonic>(this, _$identity);
+  _$$ChannelFilter_VisibilityCopyWith<_$ChannelFilter_Visibility> get copyWith => __$$ChannelFilter_VisibilityCopyWithImpl<_$ChannelFilter_Visibility>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) seedFile,
-    required TResult Function(U8Array64 field0) seedBytes,
-    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
+    required TResult Function() all,
+    required TResult Function(PublicKey field0) counterparty,
+    required TResult Function(ChannelVisibility field0) visibility,
   }) {
-    return bip39Mnemonic(mnemonic, passphrase);
+    return visibility(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? seedFile,
-    TResult? Function(U8Array64 field0)? seedBytes,
-    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult? Function()? all,
+    TResult? Function(PublicKey field0)? counterparty,
+    TResult? Function(ChannelVisibility field0)? visibility,
   }) {
-    return bip39Mnemonic?.call(mnemonic, passphrase);
+    return visibility?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? seedFile,
-    TResult Function(U8Array64 field0)? seedBytes,
-    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    TResult Function()? all,
+    TResult Function(PublicKey field0)? counterparty,
+    TResult Function(ChannelVisibility field0)? visibility,
     required TResult orElse(),
   }) {
-    if (bip39Mnemonic != null) {
-      return bip39Mnemonic(mnemonic, passphrase);
+    if (visibility != null) {
+      return visibility(field0);
     }
     return orElse();
   }
@@ -624,121 +583,102 @@ class _$EntropySourceConfig_Bip39Mnemonic implements EntropySourceConfig_Bip39Mn
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
-    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
-    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
+    required TResult Function(ChannelFilter_All value) all,
+    required TResult Function(ChannelFilter_Counterparty value) counterparty,
+    required TResult Function(ChannelFilter_Visibility value) visibility,
   }) {
-    return bip39Mnemonic(this);
+    return visibility(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult? Function(ChannelFilter_All value)? all,
+    TResult? Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult? Function(ChannelFilter_Visibility value)? visibility,
   }) {
-    return bip39Mnemonic?.call(this);
+    return visibility?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
-    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
-    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    TResult Function(ChannelFilter_All value)? all,
+    TResult Function(ChannelFilter_Counterparty value)? counterparty,
+    TResult Function(ChannelFilter_Visibility value)? visibility,
     required TResult orElse(),
   }) {
-    if (bip39Mnemonic != null) {
-      return bip39Mnemonic(this);
+    if (visibility != null) {
+      return visibility(this);
     }
     return orElse();
   }
 }
 
-abstract class EntropySourceConfig_Bip39Mnemonic implements EntropySourceConfig {
-  const factory EntropySourceConfig_Bip39Mnemonic({required final Mnemonic mnemonic, final String? passphrase}) = _$EntropySourceConfig_Bip39Mnemonic;
+abstract class ChannelFilter_Visibility implements ChannelFilter {
+  const factory ChannelFilter_Visibility(final ChannelVisibility field0) = _$ChannelFilter_Visibility;
 
-  Mnemonic get mnemonic;
-  String? get passphrase;
+  ChannelVisibility get field0;
   @JsonKey(ignore: true)
-  _$$EntropySourceConfig_Bip39MnemonicCopyWith<_$EntropySourceConfig_Bip39Mnemonic> get copyWith => throw _privateConstructorUsedError;
+  _$$ChannelFilter_VisibilityCopyWith<_$ChannelFilter_Visibility> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$Event {
+mixin _$EntropySourceConfig {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(PaymentHash paymentHash) paymentSuccessful,
-    required TResult Function(PaymentHash paymentHash) paymentFailed,
-    required TResult Function(PaymentHash paymentHash, int amountMsat) paymentReceived,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelReady,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
+    required TResult Function(String field0) seedFile,
+    required TResult Function(U8Array64 field0) seedBytes,
+    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(PaymentHash paymentHash)? paymentSuccessful,
-    TResult? Function(PaymentHash paymentHash)? paymentFailed,
-    TResult? Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    TResult? Function(String field0)? seedFile,
+    TResult? Function(U8Array64 field0)? seedBytes,
+    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(PaymentHash paymentHash)? paymentSuccessful,
-    TResult Function(PaymentHash paymentHash)? paymentFailed,
-    TResult Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    TResult Function(String field0)? seedFile,
+    TResult Function(U8Array64 field0)? seedBytes,
+    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(Event_PaymentSuccessful value) paymentSuccessful,
-    required TResult Function(Event_PaymentFailed value) paymentFailed,
-    required TResult Function(Event_PaymentReceived value) paymentReceived,
-    required TResult Function(Event_ChannelReady value) channelReady,
-    required TResult Function(Event_ChannelClosed value) channelClosed,
-    required TResult Function(Event_ChannelPending value) channelPending,
+    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
+    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
+    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(Event_PaymentSuccessful value)? paymentSuccessful,
-    TResult? Function(Event_PaymentFailed value)? paymentFailed,
-    TResult? Function(Event_PaymentReceived value)? paymentReceived,
-    TResult? Function(Event_ChannelReady value)? channelReady,
-    TResult? Function(Event_ChannelClosed value)? channelClosed,
-    TResult? Function(Event_ChannelPending value)? channelPending,
+    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(Event_PaymentSuccessful value)? paymentSuccessful,
-    TResult Function(Event_PaymentFailed value)? paymentFailed,
-    TResult Function(Event_PaymentReceived value)? paymentReceived,
-    TResult Function(Event_ChannelReady value)? channelReady,
-    TResult Function(Event_ChannelClosed value)? channelClosed,
-    TResult Function(Event_ChannelPending value)? channelPending,
-    required TResult orElse(),
+    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $EventCopyWith<$Res> {
-  factory $EventCopyWith(Event value, $Res Function(Event) then) = _$EventCopyWithImpl<$Res, Event>;
+abstract class $EntropySourceConfigCopyWith<$Res> {
+  factory $EntropySourceConfigCopyWith(EntropySourceConfig value, $Res Function(EntropySourceConfig) then) = _$EntropySourceConfigCopyWithImpl<$Res, EntropySourceConfig>;
 }
 
 /// @nodoc
-class _$EventCopyWithImpl<$Res, $Val extends Event> implements $EventCopyWith<$Res> {
-  _$EventCopyWithImpl(this._value, this._then);
+class _$EntropySourceConfigCopyWithImpl<$Res, $Val extends EntropySourceConfig> implements $EntropySourceConfigCopyWith<$Res> {
+  _$EntropySourceConfigCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -747,96 +687,86 @@ class _$EventCopyWithImpl<$Res, $Val extends Event> implements $EventCopyWith<$R
 }
 
 /// @nodoc
-abstract class _$$Event_PaymentSuccessfulCopyWith<$Res> {
-  factory _$$Event_PaymentSuccessfulCopyWith(_$Event_PaymentSuccessful value, $Res Function(_$Event_PaymentSuccessful) then) = __$$Event_PaymentSuccessfulCopyWithImpl<$Res>;
+abstract class _$$EntropySourceConfig_SeedFileCopyWith<$Res> {
+  factory _$$EntropySourceConfig_SeedFileCopyWith(_$EntropySourceConfig_SeedFile value, $Res Function(_$EntropySourceConfig_SeedFile) then) = __$$EntropySourceConfig_SeedFileCopyWithImpl<$Res>;
   @useResult
-  $Res call({PaymentHash paymentHash});
+  $Res call({String field0});
 }
 
 /// @nodoc
-class __$$Event_PaymentSuccessfulCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_PaymentSuccessful> implements _$$Event_PaymentSuccessfulCopyWith<$Res> {
-  __$$Event_PaymentSuccessfulCopyWithImpl(_$Event_PaymentSuccessful _value, $Res Function(_$Event_PaymentSuccessful) _then) : super(_value, _then);
+class __$$EntropySourceConfig_SeedFileCopyWithImpl<$Res> extends _$EntropySourceConfigCopyWithImpl<$Res, _$EntropySourceConfig_SeedFile> implements _$$EntropySourceConfig_SeedFileCopyWith<$Res> {
+  __$$EntropySourceConfig_SeedFileCopyWithImpl(_$EntropySourceConfig_SeedFile _value, $Res Function(_$EntropySourceConfig_SeedFile) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? paymentHash = null,
+    Object? field0 = null,
   }) {
-    return _then(_$Event_PaymentSuccessful(
-      paymentHash: null == paymentHash
-          ? _value.paymentHash
-          : paymentHash // ignore: cast_nullable_to_non_nullable
-              as PaymentHash,
+    return _then(_$EntropySourceConfig_SeedFile(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$Event_PaymentSuccessful implements Event_PaymentSuccessful {
-  const _$Event_PaymentSuccessful({required this.paymentHash});
+class _$EntropySourceConfig_SeedFile implements EntropySourceConfig_SeedFile {
+  const _$EntropySourceConfig_SeedFile(this.field0);
 
-  /// The hash of the payment.
   @override
-  final PaymentHash paymentHash;
+  final String field0;
 
   @override
   String toString() {
-    return 'Event.paymentSuccessful(paymentHash: $paymentHash)';
+    return 'EntropySourceConfig.seedFile(field0: $field0)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$Event_PaymentSuccessful && (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$EntropySourceConfig_SeedFile && (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, paymentHash);
+  int get hashCode => Object.hash(runtimeType, field0);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$Event_PaymentSuccessfulCopyWith<_$Event_PaymentSuccessful> get copyWith => __$$Event_PaymentSuccessfulCopyWithImpl<_$Event_PaymentSuccessful>(this, _$identity);
+  _$$EntropySourceConfig_SeedFileCopyWith<_$EntropySourceConfig_SeedFile> get copyWith => __$$EntropySourceConfig_SeedFileCopyWithImpl<_$EntropySourceConfig_SeedFile>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(PaymentHash paymentHash) paymentSuccessful,
-    required TResult Function(PaymentHash paymentHash) paymentFailed,
-    required TResult Function(PaymentHash paymentHash, int amountMsat) paymentReceived,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelReady,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
+    required TResult Function(String field0) seedFile,
+    required TResult Function(U8Array64 field0) seedBytes,
+    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
   }) {
-    return paymentSuccessful(paymentHash);
+    return seedFile(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(PaymentHash paymentHash)? paymentSuccessful,
-    TResult? Function(PaymentHash paymentHash)? paymentFailed,
-    TResult? Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    TResult? Function(String field0)? seedFile,
+    TResult? Function(U8Array64 field0)? seedBytes,
+    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
   }) {
-    return paymentSuccessful?.call(paymentHash);
+    return seedFile?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(PaymentHash paymentHash)? paymentSuccessful,
-    TResult Function(PaymentHash paymentHash)? paymentFailed,
-    TResult Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    TResult Function(String field0)? seedFile,
+    TResult Function(U8Array64 field0)? seedBytes,
+    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
     required TResult orElse(),
   }) {
-    if (paymentSuccessful != null) {
-      return paymentSuccessful(paymentHash);
+    if (seedFile != null) {
+      return seedFile(field0);
     }
     return orElse();
   }
@@ -844,147 +774,127 @@ class _$Event_PaymentSuccessful implements Event_PaymentSuccessful {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(Event_PaymentSuccessful value) paymentSuccessful,
-    required TResult Function(Event_PaymentFailed value) paymentFailed,
-    required TResult Function(Event_PaymentReceived value) paymentReceived,
-    required TResult Function(Event_ChannelReady value) channelReady,
-    required TResult Function(Event_ChannelClosed value) channelClosed,
-    required TResult Function(Event_ChannelPending value) channelPending,
+    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
+    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
+    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
   }) {
-    return paymentSuccessful(this);
+    return seedFile(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(Event_PaymentSuccessful value)? paymentSuccessful,
-    TResult? Function(Event_PaymentFailed value)? paymentFailed,
-    TResult? Function(Event_PaymentReceived value)? paymentReceived,
-    TResult? Function(Event_ChannelReady value)? channelReady,
-    TResult? Function(Event_ChannelClosed value)? channelClosed,
-    TResult? Function(Event_ChannelPending value)? channelPending,
+    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
   }) {
-    return paymentSuccessful?.call(this);
+    return seedFile?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(Event_PaymentSuccessful value)? paymentSuccessful,
-    TResult Function(Event_PaymentFailed value)? paymentFailed,
-    TResult Function(Event_PaymentReceived value)? paymentReceived,
-    TResult Function(Event_ChannelReady value)? channelReady,
-    TResult Function(Event_ChannelClosed value)? channelClosed,
-    TResult Function(Event_ChannelPending value)? channelPending,
+    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
     required TResult orElse(),
   }) {
-    if (paymentSuccessful != null) {
-      return paymentSuccessful(this);
+    if (seedFile != null) {
+      return seedFile(this);
     }
     return orElse();
   }
 }
 
-abstract class Event_PaymentSuccessful implements Event {
-  const factory Event_PaymentSuccessful({required final PaymentHash paymentHash}) = _$Event_PaymentSuccessful;
+abstract class EntropySourceConfig_SeedFile implements EntropySourceConfig {
+  const factory EntropySourceConfig_SeedFile(final String field0) = _$EntropySourceConfig_SeedFile;
 
-  /// The hash of the payment.
-  PaymentHash get paymentHash;
+  String get field0;
   @JsonKey(ignore: true)
-  _$$Event_PaymentSuccessfulCopyWith<_$Event_PaymentSuccessful> get copyWith => throw _privateConstructorUsedError;
+  _$$EntropySourceConfig_SeedFileCopyWith<_$EntropySourceConfig_SeedFile> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$Event_PaymentFailedCopyWith<$Res> {
-  factory _$$Event_PaymentFailedCopyWith(_$Event_PaymentFailed value, $Res Function(_$Event_PaymentFailed) then) = __$$Event_PaymentFailedCopyWithImpl<$Res>;
+abstract class _$$EntropySourceConfig_SeedBytesCopyWith<$Res> {
+  factory _$$EntropySourceConfig_SeedBytesCopyWith(_$EntropySourceConfig_SeedBytes value, $Res Function(_$EntropySourceConfig_SeedBytes) then) = __$$EntropySourceConfig_SeedBytesCopyWithImpl<$Res>;
   @useResult
-  $Res call({PaymentHash paymentHash});
+  $Res call({U8Array64 field0});
 }
 
 /// @nodoc
-class __$$Event_PaymentFailedCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_PaymentFailed> implements _$$Event_PaymentFailedCopyWith<$Res> {
-  __$$Event_PaymentFailedCopyWithImpl(_$Event_PaymentFailed _value, $Res Function(_$Event_PaymentFailed) _then) : super(_value, _then);
+class __$$EntropySourceConfig_SeedBytesCopyWithImpl<$Res> extends _$EntropySourceConfigCopyWithImpl<$Res, _$EntropySourceConfig_SeedBytes> implements _$$EntropySourceConfig_SeedBytesCopyWith<$Res> {
+  __$$EntropySourceConfig_SeedBytesCopyWithImpl(_$EntropySourceConfig_SeedBytes _value, $Res Function(_$EntropySourceConfig_SeedBytes) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? paymentHash = null,
+    Object? field0 = null,
   }) {
-    return _then(_$Event_PaymentFailed(
-      paymentHash: null == paymentHash
-          ? _value.paymentHash
-          : paymentHash // ignore: cast_nullable_to_non_nullable
-              as PaymentHash,
+    return _then(_$EntropySourceConfig_SeedBytes(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as U8Array64,
     ));
   }
 }
 
 /// @nodoc
 
-class _$Event_PaymentFailed implements Event_PaymentFailed {
-  const _$Event_PaymentFailed({required this.paymentHash});
+class _$EntropySourceConfig_SeedBytes implements EntropySourceConfig_SeedBytes {
+  const _$EntropySourceConfig_SeedBytes(this.field0);
 
-  /// The hash of the payment.
   @override
-  final PaymentHash paymentHash;
+  final U8Array64 field0;
 
   @override
   String toString() {
-    return 'Event.paymentFailed(paymentHash: $paymentHash)';
+    return 'EntropySourceConfig.seedBytes(field0: $field0)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$Event_PaymentFailed && (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$EntropySourceConfig_SeedBytes && const DeepCollectionEquality().equals(other.field0, field0));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, paymentHash);
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(field0));
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$Event_PaymentFailedCopyWith<_$Event_PaymentFailed> get copyWith => __$$Event_PaymentFailedCopyWithImpl<_$Event_PaymentFailed>(this, _$identity);
+  _$$EntropySourceConfig_SeedBytesCopyWith<_$EntropySourceConfig_SeedBytes> get copyWith => __$$EntropySourceConfig_SeedBytesCopyWithImpl<_$EntropySourceConfig_SeedBytes>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(PaymentHash paymentHash) paymentSuccessful,
-    required TResult Function(PaymentHash paymentHash) paymentFailed,
-    required TResult Function(PaymentHash paymentHash, int amountMsat) paymentReceived,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelReady,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
-    required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
+    required TResult Function(String field0) seedFile,
+    required TResult Function(U8Array64 field0) seedBytes,
+    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
   }) {
-    return paymentFailed(paymentHash);
+    return seedBytes(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(PaymentHash paymentHash)? paymentSuccessful,
-    TResult? Function(PaymentHash paymentHash)? paymentFailed,
-    TResult? Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
-    TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    TResult? Function(String field0)? seedFile,
+    TResult? Function(U8Array64 field0)? seedBytes,
+    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
   }) {
-    return paymentFailed?.call(paymentHash);
+    return seedBytes?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(PaymentHash paymentHash)? paymentSuccessful,
-    TResult Function(PaymentHash paymentHash)? paymentFailed,
-    TResult Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
-    TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    TResult Function(String field0)? seedFile,
+    TResult Function(U8Array64 field0)? seedBytes,
+    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
     required TResult orElse(),
   }) {
-    if (paymentFailed != null) {
-      return paymentFailed(paymentHash);
+    if (seedBytes != null) {
+      return seedBytes(field0);
     }
     return orElse();
   }
@@ -992,122 +902,191 @@ class _$Event_PaymentFailed implements Event_PaymentFailed {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(Event_PaymentSuccessful value) paymentSuccessful,
-    required TResult Function(Event_PaymentFailed value) paymentFailed,
-    required TResult Function(Event_PaymentReceived value) paymentReceived,
-    required TResult Function(Event_ChannelReady value) channelReady,
-    required TResult Function(Event_ChannelClosed value) channelClosed,
-    required TResult Function(Event_ChannelPending value) channelPending,
+    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
+    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
+    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
   }) {
-    return paymentFailed(this);
+    return seedBytes(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(Event_PaymentSuccessful value)? paymentSuccessful,
-    TResult? Function(Event_PaymentFailed value)? paymentFailed,
-    TResult? Function(Event_PaymentReceived value)? paymentReceived,
-    TResult? Function(Event_ChannelReady value)? channelReady,
-    TResult? Function(Event_ChannelClosed value)? channelClosed,
-    TResult? Function(Event_ChannelPending value)? channelPending,
+    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
   }) {
-    return paymentFailed?.call(this);
+    return seedBytes?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(Event_PaymentSuccessful value)? paymentSuccessful,
-    TResult Function(Event_PaymentFailed value)? paymentFailed,
-    TResult Function(Event_PaymentReceived value)? paymentReceived,
-    TResult Function(Event_ChannelReady value)? channelReady,
-    TResult Function(Event_ChannelClosed value)? channelClosed,
-    TResult Function(Event_ChannelPending value)? channelPending,
+    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
     required TResult orElse(),
   }) {
-    if (paymentFailed != null) {
-      return paymentFailed(this);
+    if (seedBytes != null) {
+      return seedBytes(this);
     }
     return orElse();
   }
 }
 
-abstract class Event_PaymentFailed implements Event {
-  const factory Event_PaymentFailed({required final PaymentHash paymentHash}) = _$Event_PaymentFailed;
+abstract class EntropySourceConfig_SeedBytes implements EntropySourceConfig {
+  const factory EntropySourceConfig_SeedBytes(final U8Array64 field0) = _$EntropySourceConfig_SeedBytes;
 
-  /// The hash of the payment.
-  PaymentHash get paymentHash;
+  U8Array64 get field0;
   @JsonKey(ignore: true)
-  _$$Event_PaymentFailedCopyWith<_$Event_PaymentFailed> get copyWith => throw _privateConstructorUsedError;
+  _$$EntropySourceConfig_SeedBytesCopyWith<_$EntropySourceConfig_SeedBytes> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$Event_PaymentReceivedCopyWith<$Res> {
-  factory _$$Event_PaymentReceivedCopyWith(_$Event_PaymentReceived value, $Res Function(_$Event_PaymentReceived) then) = __$$Event_PaymentReceivedCopyWithImpl<$Res>;
+abstract class _$$EntropySourceConfig_Bip39MnemonicCopyWith<$Res> {
+  factory _$$EntropySourceConfig_Bip39MnemonicCopyWith(_$EntropySourceConfig_Bip39Mnemonic value, $Res Function(_$EntropySourceConfig_Bip39Mnemonic) then) =
+      __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl<$Res>;
   @useResult
-  $Res call({PaymentHash paymentHash, int amountMsat});
+  $Res call({Mnemonic mnemonic, String? passphrase});
 }
 
 /// @nodoc
-class __$$Event_PaymentReceivedCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_PaymentReceived> implements _$$Event_PaymentReceivedCopyWith<$Res> {
-  __$$Event_PaymentReceivedCopyWithImpl(_$Event_PaymentReceived _value, $Res Function(_$Event_PaymentReceived) _then) : super(_value, _then);
+class __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl<$Res> extends _$EntropySourceConfigCopyWithImpl<$Res, _$EntropySourceConfig_Bip39Mnemonic>
+    implements _$$EntropySourceConfig_Bip39MnemonicCopyWith<$Res> {
+  __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl(_$EntropySourceConfig_Bip39Mnemonic _value, $Res Function(_$EntropySourceConfig_Bip39Mnemonic) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? paymentHash = null,
-    Object? amountMsat = null,
+    Object? mnemonic = null,
+    Object? passphrase = freezed,
   }) {
-    return _then(_$Event_PaymentReceived(
-      paymentHash: null == paymentHash
-          ? _value.paymentHash
-          : paymentHash // ignore: cast_nullable_to_non_nullable
-              as PaymentHash,
-      amountMsat: null == amountMsat
-          ? _value.amountMsat
-          : amountMsat // ignore: cast_nullable_to_non_nullable
-              as int,
+    return _then(_$EntropySourceConfig_Bip39Mnemonic(
+      mnemonic: null == mnemonic
+          ? _value.mnemonic
+          : mnemonic // ignore: cast_nullable_to_non_nullable
+              as Mnemonic,
+      passphrase: freezed == passphrase
+          ? _value.passphrase
+          : passphrase // ignore: cast_nullable_to_non_nullable
+              as String?,
     ));
   }
 }
 
 /// @nodoc
 
-class _$Event_PaymentReceived implements Event_PaymentReceived {
-  const _$Event_PaymentReceived({required this.paymentHash, required this.amountMsat});
+class _$EntropySourceConfig_Bip39Mnemonic implements EntropySourceConfig_Bip39Mnemonic {
+  const _$EntropySourceConfig_Bip39Mnemonic({required this.mnemonic, this.passphrase});
 
-  /// The hash of the payment.
   @override
-  final PaymentHash paymentHash;
-
-  /// The value, in thousandths of a satoshi, that has been received.
+  final Mnemonic mnemonic;
   @override
-  final int amountMsat;
+  final String? passphrase;
 
   @override
   String toString() {
-    return 'Event.paymentReceived(paymentHash: $paymentHash, amountMsat: $amountMsat)';
+    return 'EntropySourceConfig.bip39Mnemonic(mnemonic: $mnemonic, passphrase: $passphrase)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$Event_PaymentReceived &&
-            (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash) &&
-            (identical(other.amountMsat, amountMsat) || other.amountMsat == amountMsat));
+            other is _$EntropySourceConfig_Bip39Mnemonic &&
+            (identical(other.mnemonic, mnemonic) || other.mnemonic == mnemonic) &&
+            (identical(other.passphrase, passphrase) || other.passphrase == passphrase));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, paymentHash, amountMsat);
+  int get hashCode => Object.hash(runtimeType, mnemonic, passphrase);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$Event_PaymentReceivedCopyWith<_$Event_PaymentReceived> get copyWith => __$$Event_PaymentReceivedCopyWithImpl<_$Event_PaymentReceived>(this, _$identity);
+  _$$EntropySourceConfig_Bip39MnemonicCopyWith<_$EntropySourceConfig_Bip39Mnemonic> get copyWith =>
+      __$$EntropySourceConfig_Bip39MnemonicCopyWithImpl<_$EntropySourceConfig_Bip39Mnemonic>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String field0) seedFile,
+    required TResult Function(U8Array64 field0) seedBytes,
+    required TResult Function(Mnemonic mnemonic, String? passphrase) bip39Mnemonic,
+  }) {
+    return bip39Mnemonic(mnemonic, passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String field0)? seedFile,
+    TResult? Function(U8Array64 field0)? seedBytes,
+    TResult? Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+  }) {
+    return bip39Mnemonic?.call(mnemonic, passphrase);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String field0)? seedFile,
+    TResult Function(U8Array64 field0)? seedBytes,
+    TResult Function(Mnemonic mnemonic, String? passphrase)? bip39Mnemonic,
+    required TResult orElse(),
+  }) {
+    if (bip39Mnemonic != null) {
+      return bip39Mnemonic(mnemonic, passphrase);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(EntropySourceConfig_SeedFile value) seedFile,
+    required TResult Function(EntropySourceConfig_SeedBytes value) seedBytes,
+    required TResult Function(EntropySourceConfig_Bip39Mnemonic value) bip39Mnemonic,
+  }) {
+    return bip39Mnemonic(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult? Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult? Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+  }) {
+    return bip39Mnemonic?.call(this);
+  }
 
   @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(EntropySourceConfig_SeedFile value)? seedFile,
+    TResult Function(EntropySourceConfig_SeedBytes value)? seedBytes,
+    TResult Function(EntropySourceConfig_Bip39Mnemonic value)? bip39Mnemonic,
+    required TResult orElse(),
+  }) {
+    if (bip39Mnemonic != null) {
+      return bip39Mnemonic(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class EntropySourceConfig_Bip39Mnemonic implements EntropySourceConfig {
+  const factory EntropySourceConfig_Bip39Mnemonic({required final Mnemonic mnemonic, final String? passphrase}) = _$EntropySourceConfig_Bip39Mnemonic;
+
+  Mnemonic get mnemonic;
+  String? get passphrase;
+  @JsonKey(ignore: true)
+  _$$EntropySourceConfig_Bip39MnemonicCopyWith<_$EntropySourceConfig_Bip39Mnemonic> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$Event {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
     required TResult Function(PaymentHash paymentHash) paymentSuccessful,
@@ -1116,11 +1095,8 @@ class _$Event_PaymentReceived implements Event_PaymentReceived {
     required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelReady,
     required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
     required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
-  }) {
-    return paymentReceived(paymentHash, amountMsat);
-  }
-
-  @override
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
     TResult? Function(PaymentHash paymentHash)? paymentSuccessful,
@@ -1129,11 +1105,8 @@ class _$Event_PaymentReceived implements Event_PaymentReceived {
     TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
     TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
     TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
-  }) {
-    return paymentReceived?.call(paymentHash, amountMsat);
-  }
-
-  @override
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
     TResult Function(PaymentHash paymentHash)? paymentSuccessful,
@@ -1143,14 +1116,8 @@ class _$Event_PaymentReceived implements Event_PaymentReceived {
     TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
     TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
     required TResult orElse(),
-  }) {
-    if (paymentReceived != null) {
-      return paymentReceived(paymentHash, amountMsat);
-    }
-    return orElse();
-  }
-
-  @override
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
     required TResult Function(Event_PaymentSuccessful value) paymentSuccessful,
@@ -1159,11 +1126,8 @@ class _$Event_PaymentReceived implements Event_PaymentReceived {
     required TResult Function(Event_ChannelReady value) channelReady,
     required TResult Function(Event_ChannelClosed value) channelClosed,
     required TResult Function(Event_ChannelPending value) channelPending,
-  }) {
-    return paymentReceived(this);
-  }
-
-  @override
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
     TResult? Function(Event_PaymentSuccessful value)? paymentSuccessful,
@@ -1172,11 +1136,8 @@ class _$Event_PaymentReceived implements Event_PaymentReceived {
     TResult? Function(Event_ChannelReady value)? channelReady,
     TResult? Function(Event_ChannelClosed value)? channelClosed,
     TResult? Function(Event_ChannelPending value)? channelPending,
-  }) {
-    return paymentReceived?.call(this);
-  }
-
-  @override
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
     TResult Function(Event_PaymentSuccessful value)? paymentSuccessful,
@@ -1186,90 +1147,76 @@ class _$Event_PaymentReceived implements Event_PaymentReceived {
     TResult Function(Event_ChannelClosed value)? channelClosed,
     TResult Function(Event_ChannelPending value)? channelPending,
     required TResult orElse(),
-  }) {
-    if (paymentReceived != null) {
-      return paymentReceived(this);
-    }
-    return orElse();
-  }
+  }) =>
+      throw _privateConstructorUsedError;
 }
 
-abstract class Event_PaymentReceived implements Event {
-  const factory Event_PaymentReceived({required final PaymentHash paymentHash, required final int amountMsat}) = _$Event_PaymentReceived;
+/// @nodoc
+abstract class $EventCopyWith<$Res> {
+  factory $EventCopyWith(Event value, $Res Function(Event) then) = _$EventCopyWithImpl<$Res, Event>;
+}
 
-  /// The hash of the payment.
-  PaymentHash get paymentHash;
+/// @nodoc
+class _$EventCopyWithImpl<$Res, $Val extends Event> implements $EventCopyWith<$Res> {
+  _$EventCopyWithImpl(this._value, this._then);
 
-  /// The value, in thousandths of a satoshi, that has been received.
-  int get amountMsat;
-  @JsonKey(ignore: true)
-  _$$Event_PaymentReceivedCopyWith<_$Event_PaymentReceived> get copyWith => throw _privateConstructorUsedError;
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
 }
 
 /// @nodoc
-abstract class _$$Event_ChannelReadyCopyWith<$Res> {
-  factory _$$Event_ChannelReadyCopyWith(_$Event_ChannelReady value, $Res Function(_$Event_ChannelReady) then) = __$$Event_ChannelReadyCopyWithImpl<$Res>;
+abstract class _$$Event_PaymentSuccessfulCopyWith<$Res> {
+  factory _$$Event_PaymentSuccessfulCopyWith(_$Event_PaymentSuccessful value, $Res Function(_$Event_PaymentSuccessful) then) = __$$Event_PaymentSuccessfulCopyWithImpl<$Res>;
   @useResult
-  $Res call({ChannelId channelId, UserChannelId userChannelId});
+  $Res call({PaymentHash paymentHash});
 }
 
 /// @nodoc
-class __$$Event_ChannelReadyCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_ChannelReady> implements _$$Event_ChannelReadyCopyWith<$Res> {
-  __$$Event_ChannelReadyCopyWithImpl(_$Event_ChannelReady _value, $Res Function(_$Event_ChannelReady) _then) : super(_value, _then);
+class __$$Event_PaymentSuccessfulCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_PaymentSuccessful> implements _$$Event_PaymentSuccessfulCopyWith<$Res> {
+  __$$Event_PaymentSuccessfulCopyWithImpl(_$Event_PaymentSuccessful _value, $Res Function(_$Event_PaymentSuccessful) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? channelId = null,
-    Object? userChannelId = null,
+    Object? paymentHash = null,
   }) {
-    return _then(_$Event_ChannelReady(
-      channelId: null == channelId
-          ? _value.channelId
-          : channelId // ignore: cast_nullable_to_non_nullable
-              as ChannelId,
-      userChannelId: null == userChannelId
-          ? _value.userChannelId
-          : userChannelId // ignore: cast_nullable_to_non_nullable
-              as UserChannelId,
+    return _then(_$Event_PaymentSuccessful(
+      paymentHash: null == paymentHash
+          ? _value.paymentHash
+          : paymentHash // ignore: cast_nullable_to_non_nullable
+              as PaymentHash,
     ));
   }
 }
 
 /// @nodoc
 
-class _$Event_ChannelReady implements Event_ChannelReady {
-  const _$Event_ChannelReady({required this.channelId, required this.userChannelId});
-
-  /// The channel_id of the channel.
-  @override
-  final ChannelId channelId;
+class _$Event_PaymentSuccessful implements Event_PaymentSuccessful {
+  const _$Event_PaymentSuccessful({required this.paymentHash});
 
-  /// The user_channel_id of the channel.
+  /// The hash of the payment.
   @override
-  final UserChannelId userChannelId;
+  final PaymentHash paymentHash;
 
   @override
   String toString() {
-    return 'Event.channelReady(channelId: $channelId, userChannelId: $userChannelId)';
+    return 'Event.paymentSuccessful(paymentHash: $paymentHash)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$Event_ChannelReady &&
-            (identical(other.channelId, channelId) || other.channelId == channelId) &&
-            (identical(other.userChannelId, userChannelId) || other.userChannelId == userChannelId));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$Event_PaymentSuccessful && (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, channelId, userChannelId);
+  int get hashCode => Object.hash(runtimeType, paymentHash);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$Event_ChannelReadyCopyWith<_$Event_ChannelReady> get copyWith => __$$Event_ChannelReadyCopyWithImpl<_$Event_ChannelReady>(this, _$identity);
+  _$$Event_PaymentSuccessfulCopyWith<_$Event_PaymentSuccessful> get copyWith => __$$Event_PaymentSuccessfulCopyWithImpl<_$Event_PaymentSuccessful>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1281,7 +1228,7 @@ class _$Event_ChannelReady implements Event_ChannelReady {
     required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
     required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
   }) {
-    return channelReady(channelId, userChannelId);
+    return paymentSuccessful(paymentHash);
   }
 
   @override
@@ -1294,7 +1241,7 @@ class _$Event_ChannelReady implements Event_ChannelReady {
     TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
     TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
   }) {
-    return channelReady?.call(channelId, userChannelId);
+    return paymentSuccessful?.call(paymentHash);
   }
 
   @override
@@ -1308,8 +1255,8 @@ class _$Event_ChannelReady implements Event_ChannelReady {
     TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
     required TResult orElse(),
   }) {
-    if (channelReady != null) {
-      return channelReady(channelId, userChannelId);
+    if (paymentSuccessful != null) {
+      return paymentSuccessful(paymentHash);
     }
     return orElse();
   }
@@ -1324,7 +1271,7 @@ class _$Event_ChannelReady implements Event_ChannelReady {
     required TResult Function(Event_ChannelClosed value) channelClosed,
     required TResult Function(Event_ChannelPending value) channelPending,
   }) {
-    return channelReady(this);
+    return paymentSuccessful(this);
   }
 
   @override
@@ -1337,7 +1284,7 @@ class _$Event_ChannelReady implements Event_ChannelReady {
     TResult? Function(Event_ChannelClosed value)? channelClosed,
     TResult? Function(Event_ChannelPending value)? channelPending,
   }) {
-    return channelReady?.call(this);
+    return paymentSuccessful?.call(this);
   }
 
   @override
@@ -1351,89 +1298,73 @@ class _$Event_ChannelReady implements Event_ChannelReady {
     TResult Function(Event_ChannelPending value)? channelPending,
     required TResult orElse(),
   }) {
-    if (channelReady != null) {
-      return channelReady(this);
-    }
+    if (paymentSuccessful != null) {
+      return paymentSuccessful(this);
+    }
     return orElse();
   }
 }
 
-abstract class Event_ChannelReady implements Event {
-  const factory Event_ChannelReady({required final ChannelId channelId, required final UserChannelId userChannelId}) = _$Event_ChannelReady;
-
-  /// The channel_id of the channel.
-  ChannelId get channelId;
+abstract class Event_PaymentSuccessful implements Event {
+  const factory Event_PaymentSuccessful({required final PaymentHash paymentHash}) = _$Event_PaymentSuccessful;
 
-  /// The user_channel_id of the channel.
-  UserChannelId get userChannelId;
+  /// The hash of the payment.
+  PaymentHash get paymentHash;
   @JsonKey(ignore: true)
-  _$$Event_ChannelReadyCopyWith<_$Event_ChannelReady> get copyWith => throw _privateConstructorUsedError;
+  _$$Event_PaymentSuccessfulCopyWith<_$Event_PaymentSuccessful> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$Event_ChannelClosedCopyWith<$Res> {
-  factory _$$Event_ChannelClosedCopyWith(_$Event_ChannelClosed value, $Res Function(_$Event_ChannelClosed) then) = __$$Event_ChannelClosedCopyWithImpl<$Res>;
+abstract class _$$Event_PaymentFailedCopyWith<$Res> {
+  factory _$$Event_PaymentFailedCopyWith(_$Event_PaymentFailed value, $Res Function(_$Event_PaymentFailed) then) = __$$Event_PaymentFailedCopyWithImpl<$Res>;
   @useResult
-  $Res call({ChannelId channelId, UserChannelId userChannelId});
+  $Res call({PaymentHash paymentHash});
 }
 
 /// @nodoc
-class __$$Event_ChannelClosedCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_ChannelClosed> implements _$$Event_ChannelClosedCopyWith<$Res> {
-  __$$Event_ChannelClosedCopyWithImpl(_$Event_ChannelClosed _value, $Res Function(_$Event_ChannelClosed) _then) : super(_value, _then);
+class __$$Event_PaymentFailedCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_PaymentFailed> implements _$$Event_PaymentFailedCopyWith<$Res> {
+  __$$Event_PaymentFailedCopyWithImpl(_$Event_PaymentFailed _value, $Res Function(_$Event_PaymentFailed) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? channelId = null,
-    Object? userChannelId = null,
+    Object? paymentHash = null,
   }) {
-    return _then(_$Event_ChannelClosed(
-      channelId: null == channelId
-          ? _value.channelId
-          : channelId // ignore: cast_nullable_to_non_nullable
-              as ChannelId,
-      userChannelId: null == userChannelId
-          ? _value.userChannelId
-          : userChannelId // ignore: cast_nullable_to_non_nullable
-              as UserChannelId,
+    return _then(_$Event_PaymentFailed(
+      paymentHash: null == paymentHash
+          ? _value.paymentHash
+          : paymentHash // ignore: cast_nullable_to_non_nullable
+              as PaymentHash,
     ));
   }
 }
 
 /// @nodoc
 
-class _$Event_ChannelClosed implements Event_ChannelClosed {
-  const _$Event_ChannelClosed({required this.channelId, required this.userChannelId});
-
-  /// The channel_id of the channel.
-  @override
-  final ChannelId channelId;
+class _$Event_PaymentFailed implements Event_PaymentFailed {
+  const _$Event_PaymentFailed({required this.paymentHash});
 
-  /// The user_channel_id of the channel.
+  /// The hash of the payment.
   @override
-  final UserChannelId userChannelId;
+  final PaymentHash paymentHash;
 
   @override
   String toString() {
-    return 'Event.channelClosed(channelId: $channelId, userChannelId: $userChannelId)';
+    return 'Event.paymentFailed(paymentHash: $paymentHash)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$Event_ChannelClosed &&
-            (identical(other.channelId, channelId) || other.channelId == channelId) &&
-            (identical(other.userChannelId, userChannelId) || other.userChannelId == userChannelId));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$Event_PaymentFailed && (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, channelId, userChannelId);
+  int get hashCode => Object.hash(runtimeType, paymentHash);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$Event_ChannelClosedCopyWith<_$Event_ChannelClosed> get copyWith => __$$Event_ChannelClosedCopyWithImpl<_$Event_ChannelClosed>(this, _$identity);
+  _$$Event_PaymentFailedCopyWith<_$Event_PaymentFailed> get copyWith => __$$Event_PaymentFailedCopyWithImpl<_$Event_PaymentFailed>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1445,7 +1376,7 @@ class _$Event_ChannelClosed implements Event_ChannelClosed {
     required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
     required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
   }) {
-    return channelClosed(channelId, userChannelId);
+    return paymentFailed(paymentHash);
   }
 
   @override
@@ -1458,7 +1389,7 @@ class _$Event_ChannelClosed implements Event_ChannelClosed {
     TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
     TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
   }) {
-    return channelClosed?.call(channelId, userChannelId);
+    return paymentFailed?.call(paymentHash);
   }
 
   @override
@@ -1472,8 +1403,8 @@ class _$Event_ChannelClosed implements Event_ChannelClosed {
     TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
     required TResult orElse(),
   }) {
-    if (channelClosed != null) {
-      return channelClosed(channelId, userChannelId);
+    if (paymentFailed != null) {
+      return paymentFailed(paymentHash);
     }
     return orElse();
   }
@@ -1488,7 +1419,7 @@ class _$Event_ChannelClosed implements Event_ChannelClosed {
     required TResult Function(Event_ChannelClosed value) channelClosed,
     required TResult Function(Event_ChannelPending value) channelPending,
   }) {
-    return channelClosed(this);
+    return paymentFailed(this);
   }
 
   @override
@@ -1501,7 +1432,7 @@ class _$Event_ChannelClosed implements Event_ChannelClosed {
     TResult? Function(Event_ChannelClosed value)? channelClosed,
     TResult? Function(Event_ChannelPending value)? channelPending,
   }) {
-    return channelClosed?.call(this);
+    return paymentFailed?.call(this);
   }
 
   @override
@@ -1515,119 +1446,86 @@ class _$Event_ChannelClosed implements Event_ChannelClosed {
     TResult Function(Event_ChannelPending value)? channelPending,
     required TResult orElse(),
   }) {
-    if (channelClosed != null) {
-      return channelClosed(this);
+    if (paymentFailed != null) {
+      return paymentFailed(this);
     }
     return orElse();
   }
 }
 
-abstract class Event_ChannelClosed implements Event {
-  const factory Event_ChannelClosed({required final ChannelId channelId, required final UserChannelId userChannelId}) = _$Event_ChannelClosed;
-
-  /// The channel_id of the channel.
-  ChannelId get channelId;
+abstract class Event_PaymentFailed implements Event {
+  const factory Event_PaymentFailed({required final PaymentHash paymentHash}) = _$Event_PaymentFailed;
 
-  /// The user_channel_id of the channel.
-  UserChannelId get userChannelId;
+  /// The hash of the payment.
+  PaymentHash get paymentHash;
   @JsonKey(ignore: true)
-  _$$Event_ChannelClosedCopyWith<_$Event_ChannelClosed> get copyWith => throw _privateConstructorUsedError;
+  _$$Event_PaymentFailedCopyWith<_$Event_PaymentFailed> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$Event_ChannelPendingCopyWith<$Res> {
-  factory _$$Event_ChannelPendingCopyWith(_$Event_ChannelPending value, $Res Function(_$Event_ChannelPending) then) = __$$Event_ChannelPendingCopyWithImpl<$Res>;
+abstract class _$$Event_PaymentReceivedCopyWith<$Res> {
+  factory _$$Event_PaymentReceivedCopyWith(_$Event_PaymentReceived value, $Res Function(_$Event_PaymentReceived) then) = __$$Event_PaymentReceivedCopyWithImpl<$Res>;
   @useResult
-  $Res call({ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo});
+  $Res call({PaymentHash paymentHash, int amountMsat});
 }
 
 /// @nodoc
-class __$$Event_ChannelPendingCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_ChannelPending> implements _$$Event_ChannelPendingCopyWith<$Res> {
-  __$$Event_ChannelPendingCopyWithImpl(_$Event_ChannelPending _value, $Res Function(_$Event_ChannelPending) _then) : super(_value, _then);
+class __$$Event_PaymentReceivedCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_PaymentReceived> implements _$$Event_PaymentReceivedCopyWith<$Res> {
+  __$$Event_PaymentReceivedCopyWithImpl(_$Event_PaymentReceived _value, $Res Function(_$Event_PaymentReceived) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? channelId = null,
-    Object? userChannelId = null,
-    Object? formerTemporaryChannelId = null,
-    Object? counterpartyNodeId = null,
-    Object? fundingTxo = null,
+    Object? paymentHash = null,
+    Object? amountMsat = null,
   }) {
-    return _then(_$Event_ChannelPending(
-      channelId: null == channelId
-          ? _value.channelId
-          : channelId // ignore: cast_nullable_to_non_nullable
-              as ChannelId,
-      userChannelId: null == userChannelId
-          ? _value.userChannelId
-          : userChannelId // ignore: cast_nullable_to_non_nullable
-              as UserChannelId,
-      formerTemporaryChannelId: null == formerTemporaryChannelId
-          ? _value.formerTemporaryChannelId
-          : formerTemporaryChannelId // ignore: cast_nullable_to_non_nullable
-              as ChannelId,
-      counterpartyNodeId: null == counterpartyNodeId
-          ? _value.counterpartyNodeId
-          : counterpartyNodeId // ignore: cast_nullable_to_non_nullable
-              as PublicKey,
-      fundingTxo: null == fundingTxo
-          ? _value.fundingTxo
-          : fundingTxo // ignore: cast_nullable_to_non_nullable
-              as OutPoint,
+    return _then(_$Event_PaymentReceived(
+      paymentHash: null == paymentHash
+          ? _value.paymentHash
+          : paymentHash // ignore: cast_nullable_to_non_nullable
+              as PaymentHash,
+      amountMsat: null == amountMsat
+          ? _value.amountMsat
+          : amountMsat // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$Event_ChannelPending implements Event_ChannelPending {
-  const _$Event_ChannelPending({required this.channelId, required this.userChannelId, required this.formerTemporaryChannelId, required this.counterpartyNodeId, required this.fundingTxo});
-
-  /// The channel_id of the channel.
-  @override
-  final ChannelId channelId;
-
-  /// The user_channel_id of the channel.
-  @override
-  final UserChannelId userChannelId;
-
-  /// The temporary_channel_id this channel used to be known by during channel establishment.
-  @override
-  final ChannelId formerTemporaryChannelId;
+class _$Event_PaymentReceived implements Event_PaymentReceived {
+  const _$Event_PaymentReceived({required this.paymentHash, required this.amountMsat});
 
-  /// The node_id of the channel counterparty.
+  /// The hash of the payment.
   @override
-  final PublicKey counterpartyNodeId;
+  final PaymentHash paymentHash;
 
-  /// The outpoint of the channel's funding transaction.
+  /// The value, in thousandths of a satoshi, that has been received.
   @override
-  final OutPoint fundingTxo;
+  final int amountMsat;
 
   @override
   String toString() {
-    return 'Event.channelPending(channelId: $channelId, userChannelId: $userChannelId, formerTemporaryChannelId: $formerTemporaryChannelId, counterpartyNodeId: $counterpartyNodeId, fundingTxo: $fundingTxo)';
+    return 'Event.paymentReceived(paymentHash: $paymentHash, amountMsat: $amountMsat)';
   }
 
   @override
   bool operator ==(dynamic other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$Event_ChannelPending &&
-            (identical(other.channelId, channelId) || other.channelId == channelId) &&
-            (identical(other.userChannelId, userChannelId) || other.userChannelId == userChannelId) &&
-            (identical(other.formerTemporaryChannelId, formerTemporaryChannelId) || other.formerTemporaryChannelId == formerTemporaryChannelId) &&
-            (identical(other.counterpartyNodeId, counterpartyNodeId) || other.counterpartyNodeId == counterpartyNodeId) &&
-            (identical(other.fundingTxo, fundingTxo) || other.fundingTxo == fundingTxo));
+            other is _$Event_PaymentReceived &&
+            (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash) &&
+            (identical(other.amountMsat, amountMsat) || other.amountMsat == amountMsat));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+  int get hashCode => Object.hash(runtimeType, paymentHash, amountMsat);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$Event_ChannelPendingCopyWith<_$Event_ChannelPending> get copyWith => __$$Event_ChannelPendingCopyWithImpl<_$Event_ChannelPending>(this, _$identity);
+  _$$Event_PaymentReceivedCopyWith<_$Event_PaymentReceived> get copyWith => __$$Event_PaymentReceivedCopyWithImpl<_$Event_PaymentReceived>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1639,7 +1537,7 @@ class _$Event_ChannelPending implements Event_ChannelPending {
     required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
     required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
   }) {
-    return channelPending(channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+    return paymentReceived(paymentHash, amountMsat);
   }
 
   @override
@@ -1652,7 +1550,7 @@ class _$Event_ChannelPending implements Event_ChannelPending {
     TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
     TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
   }) {
-    return channelPending?.call(channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+    return paymentReceived?.call(paymentHash, amountMsat);
   }
 
   @override
@@ -1666,8 +1564,8 @@ class _$Event_ChannelPending implements Event_ChannelPending {
     TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
     required TResult orElse(),
   }) {
-    if (channelPending != null) {
-      return channelPending(channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+    if (paymentReceived != null) {
+      return paymentReceived(paymentHash, amountMsat);
     }
     return orElse();
   }
@@ -1682,7 +1580,7 @@ class _$Event_ChannelPending implements Event_ChannelPending {
     required TResult Function(Event_ChannelClosed value) channelClosed,
     required TResult Function(Event_ChannelPending value) channelPending,
   }) {
-    return channelPending(this);
+    return paymentReceived(this);
   }
 
   @override
@@ -1695,7 +1593,7 @@ class _$Event_ChannelPending implements Event_ChannelPending {
     TResult? Function(Event_ChannelClosed value)? channelClosed,
     TResult? Function(Event_ChannelPending value)? channelPending,
   }) {
-    return channelPending?.call(this);
+    return paymentReceived?.call(this);
   }
 
   @override
@@ -1709,151 +1607,1476 @@ class _$Event_ChannelPending implements Event_ChannelPending {
     TResult Function(Event_ChannelPending value)? channelPending,
     required TResult orElse(),
   }) {
-    if (channelPending != null) {
-      return channelPending(this);
+    if (paymentReceived != null) {
+      return paymentReceived(this);
     }
     return orElse();
   }
 }
 
-abstract class Event_ChannelPending implements Event {
-  const factory Event_ChannelPending(
-      {required final ChannelId channelId,
-      required final UserChannelId userChannelId,
-      required final ChannelId formerTemporaryChannelId,
-      required final PublicKey counterpartyNodeId,
-      required final OutPoint fundingTxo}) = _$Event_ChannelPending;
+abstract class Event_PaymentReceived implements Event {
+  const factory Event_PaymentReceived({required final PaymentHash paymentHash, required final int amountMsat}) = _$Event_PaymentReceived;
 
-  /// The channel_id of the channel.
-  ChannelId get channelId;
+  /// The hash of the payment.
+  PaymentHash get paymentHash;
 
-  /// The user_channel_id of the channel.
-  UserChannelId get userChannelId;
+  /// The value, in thousandths of a satoshi, that has been received.
+  int get amountMsat;
+  @JsonKey(ignore: true)
+  _$$Event_PaymentReceivedCopyWith<_$Event_PaymentReceived> get copyWith => throw _privateConstructorUsedError;
+}
 
-  /// The temporary_channel_id this channel used to be known by during channel establishment.
-  ChannelId get formerTemporaryChannelId;
+/// @nodoc
+abstract class _$$Event_ChannelReadyCopyWith<$Res> {
+  factory _$$Event_ChannelReadyCopyWith(_$Event_ChannelReady value, $Res Function(_$Event_ChannelReady) then) = __$$Event_ChannelReadyCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ChannelId channelId, UserChannelId userChannelId});
+}
 
-  /// The node_id of the channel counterparty.
-  PublicKey get counterpartyNodeId;
+/// @nodoc
+class __$$Event_ChannelReadyCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_ChannelReady> implements _$$Event_ChannelReadyCopyWith<$Res> {
+  __$$Event_ChannelReadyCopyWithImpl(_$Event_ChannelReady _value, $Res Function(_$Event_ChannelReady) _then) : super(_value, _then);
 
-  /// The outpoint of the channel's funding transaction.
-  OutPoint get fundingTxo;
-  @JsonKey(ignore: true)
-  _$$Event_ChannelPendingCopyWith<_$Event_ChannelPending> get copyWith => throw _privateConstructorUsedError;
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? channelId = null,
+    Object? userChannelId = null,
+  }) {
+    return _then(_$Event_ChannelReady(
+      channelId: null == channelId
+          ? _value.channelId
+          : channelId // ignore: cast_nullable_to_non_nullable
+              as ChannelId,
+      userChannelId: null == userChannelId
+          ? _value.userChannelId
+          : userChannelId // ignore: cast_nullable_to_non_nullable
+              as UserChannelId,
+    ));
+  }
 }
 
 /// @nodoc
-mixin _$GossipSourceConfig {
-  @optionalTypeArgs
-  TResult when<TResult extends Object?>({
-    required TResult Function() p2PNetwork,
+
+class _$Event_ChannelReady implements Event_ChannelReady {
+  const _$Event_ChannelReady({required this.channelId, required this.userChannelId});
+
+  /// The channel_id of the channel.
+  @override
+  final ChannelId channelId;
+
+  /// The user_channel_id of the channel.
+  @override
+  final UserChannelId userChannelId;
+
+  @override
+  String toString() {
+    return 'Event.channelReady(channelId: $channelId, userChannelId: $userChannelId)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Event_ChannelReady &&
+            (identical(other.channelId, channelId) || other.channelId == channelId) &&
+            (identical(other.userChannelId, userChannelId) || other.userChannelId == userChannelId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, channelId, userChannelId);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Event_ChannelReadyCopyWith<_$Event_ChannelReady> get copyWith => __$$Event_ChannelReadyCopyWithImpl<_$Event_ChannelReady>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(PaymentHash paymentHash) paymentSuccessful,
+    required TResult Function(PaymentHash paymentHash) paymentFailed,
+    required TResult Function(PaymentHash paymentHash, int amountMsat) paymentReceived,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelReady,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
+  }) {
+    return channelReady(channelId, userChannelId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(PaymentHash paymentHash)? paymentSuccessful,
+    TResult? Function(PaymentHash paymentHash)? paymentFailed,
+    TResult? Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+  }) {
+    return channelReady?.call(channelId, userChannelId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(PaymentHash paymentHash)? paymentSuccessful,
+    TResult Function(PaymentHash paymentHash)? paymentFailed,
+    TResult Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    required TResult orElse(),
+  }) {
+    if (channelReady != null) {
+      return channelReady(channelId, userChannelId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Event_PaymentSuccessful value) paymentSuccessful,
+    required TResult Function(Event_PaymentFailed value) paymentFailed,
+    required TResult Function(Event_PaymentReceived value) paymentReceived,
+    required TResult Function(Event_ChannelReady value) channelReady,
+    required TResult Function(Event_ChannelClosed value) channelClosed,
+    required TResult Function(Event_ChannelPending value) channelPending,
+  }) {
+    return channelReady(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Event_PaymentSuccessful value)? paymentSuccessful,
+    TResult? Function(Event_PaymentFailed value)? paymentFailed,
+    TResult? Function(Event_PaymentReceived value)? paymentReceived,
+    TResult? Function(Event_ChannelReady value)? channelReady,
+    TResult? Function(Event_ChannelClosed value)? channelClosed,
+    TResult? Function(Event_ChannelPending value)? channelPending,
+  }) {
+    return channelReady?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Event_PaymentSuccessful value)? paymentSuccessful,
+    TResult Function(Event_PaymentFailed value)? paymentFailed,
+    TResult Function(Event_PaymentReceived value)? paymentReceived,
+    TResult Function(Event_ChannelReady value)? channelReady,
+    TResult Function(Event_ChannelClosed value)? channelClosed,
+    TResult Function(Event_ChannelPending value)? channelPending,
+    required TResult orElse(),
+  }) {
+    if (channelReady != null) {
+      return channelReady(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Event_ChannelReady implements Event {
+  const factory Event_ChannelReady({required final ChannelId channelId, required final UserChannelId userChannelId}) = _$Event_ChannelReady;
+
+  /// The channel_id of the channel.
+  ChannelId get channelId;
+
+  /// The user_channel_id of the channel.
+  UserChannelId get userChannelId;
+  @JsonKey(ignore: true)
+  _$$Event_ChannelReadyCopyWith<_$Event_ChannelReady> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Event_ChannelClosedCopyWith<$Res> {
+  factory _$$Event_ChannelClosedCopyWith(_$Event_ChannelClosed value, $Res Function(_$Event_ChannelClosed) then) = __$$Event_ChannelClosedCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ChannelId channelId, UserChannelId userChannelId});
+}
+
+/// @nodoc
+class __$$Event_ChannelClosedCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_ChannelClosed> implements _$$Event_ChannelClosedCopyWith<$Res> {
+  __$$Event_ChannelClosedCopyWithImpl(_$Event_ChannelClosed _value, $Res Function(_$Event_ChannelClosed) _then) : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? channelId = null,
+    Object? userChannelId = null,
+  }) {
+    return _then(_$Event_ChannelClosed(
+      channelId: null == channelId
+          ? _value.channelId
+          : channelId // ignore: cast_nullable_to_non_nullable
+              as ChannelId,
+      userChannelId: null == userChannelId
+          ? _value.userChannelId
+          : userChannelId // ignore: cast_nullable_to_non_nullable
+              as UserChannelId,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Event_ChannelClosed implements Event_ChannelClosed {
+  const _$Event_ChannelClosed({required this.channelId, required this.userChannelId});
+
+  /// The channel_id of the channel.
+  @override
+  final ChannelId channelId;
+
+  /// The user_channel_id of the channel.
+  @override
+  final UserChannelId userChannelId;
+
+  @override
+  String toString() {
+    return 'Event.channelClosed(channelId: $channelId, userChannelId: $userChannelId)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Event_ChannelClosed &&
+            (identical(other.channelId, channelId) || other.channelId == channelId) &&
+            (identical(other.userChannelId, userChannelId) || other.userChannelId == userChannelId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, channelId, userChannelId);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Event_ChannelClosedCopyWith<_$Event_ChannelClosed> get copyWith => __$$Event_ChannelClosedCopyWithImpl<_$Event_ChannelClosed>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(PaymentHash paymentHash) paymentSuccessful,
+    required TResult Function(PaymentHash paymentHash) paymentFailed,
+    required TResult Function(PaymentHash paymentHash, int amountMsat) paymentReceived,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelReady,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
+  }) {
+    return channelClosed(channelId, userChannelId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(PaymentHash paymentHash)? paymentSuccessful,
+    TResult? Function(PaymentHash paymentHash)? paymentFailed,
+    TResult? Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+  }) {
+    return channelClosed?.call(channelId, userChannelId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(PaymentHash paymentHash)? paymentSuccessful,
+    TResult Function(PaymentHash paymentHash)? paymentFailed,
+    TResult Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    required TResult orElse(),
+  }) {
+    if (channelClosed != null) {
+      return channelClosed(channelId, userChannelId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Event_PaymentSuccessful value) paymentSuccessful,
+    required TResult Function(Event_PaymentFailed value) paymentFailed,
+    required TResult Function(Event_PaymentReceived value) paymentReceived,
+    required TResult Function(Event_ChannelReady value) channelReady,
+    required TResult Function(Event_ChannelClosed value) channelClosed,
+    required TResult Function(Event_ChannelPending value) channelPending,
+  }) {
+    return channelClosed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Event_PaymentSuccessful value)? paymentSuccessful,
+    TResult? Function(Event_PaymentFailed value)? paymentFailed,
+    TResult? Function(Event_PaymentReceived value)? paymentReceived,
+    TResult? Function(Event_ChannelReady value)? channelReady,
+    TResult? Function(Event_ChannelClosed value)? channelClosed,
+    TResult? Function(Event_ChannelPending value)? channelPending,
+  }) {
+    return channelClosed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Event_PaymentSuccessful value)? paymentSuccessful,
+    TResult Function(Event_PaymentFailed value)? paymentFailed,
+    TResult Function(Event_PaymentReceived value)? paymentReceived,
+    TResult Function(Event_ChannelReady value)? channelReady,
+    TResult Function(Event_ChannelClosed value)? channelClosed,
+    TResult Function(Event_ChannelPending value)? channelPending,
+    required TResult orElse(),
+  }) {
+    if (channelClosed != null) {
+      return channelClosed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Event_ChannelClosed implements Event {
+  const factory Event_ChannelClosed({required final ChannelId channelId, required final UserChannelId userChannelId}) = _$Event_ChannelClosed;
+
+  /// The channel_id of the channel.
+  ChannelId get channelId;
+
+  /// The user_channel_id of the channel.
+  UserChannelId get userChannelId;
+  @JsonKey(ignore: true)
+  _$$Event_ChannelClosedCopyWith<_$Event_ChannelClosed> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$Event_ChannelPendingCopyWith<$Res> {
+  factory _$$Event_ChannelPendingCopyWith(_$Event_ChannelPending value, $Res Function(_$Event_ChannelPending) then) = __$$Event_ChannelPendingCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo});
+}
+
+/// @nodoc
+class __$$Event_ChannelPendingCopyWithImpl<$Res> extends _$EventCopyWithImpl<$Res, _$Event_ChannelPending> implements _$$Event_ChannelPendingCopyWith<$Res> {
+  __$$Event_ChannelPendingCopyWithImpl(_$Event_ChannelPending _value, $Res Function(_$Event_ChannelPending) _then) : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? channelId = null,
+    Object? userChannelId = null,
+    Object? formerTemporaryChannelId = null,
+    Object? counterpartyNodeId = null,
+    Object? fundingTxo = null,
+  }) {
+    return _then(_$Event_ChannelPending(
+      channelId: null == channelId
+          ? _value.channelId
+          : channelId // ignore: cast_nullable_to_non_nullable
+              as ChannelId,
+      userChannelId: null == userChannelId
+          ? _value.userChannelId
+          : userChannelId // ignore: cast_nullable_to_non_nullable
+              as UserChannelId,
+      formerTemporaryChannelId: null == formerTemporaryChannelId
+          ? _value.formerTemporaryChannelId
+          : formerTemporaryChannelId // ignore: cast_nullable_to_non_nullable
+              as ChannelId,
+      counterpartyNodeId: null == counterpartyNodeId
+          ? _value.counterpartyNodeId
+          : counterpartyNodeId // ignore: cast_nullable_to_non_nullable
+              as PublicKey,
+      fundingTxo: null == fundingTxo
+          ? _value.fundingTxo
+          : fundingTxo // ignore: cast_nullable_to_non_nullable
+              as OutPoint,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$Event_ChannelPending implements Event_ChannelPending {
+  const _$Event_ChannelPending({required this.channelId, required this.userChannelId, required this.formerTemporaryChannelId, required this.counterpartyNodeId, required this.fundingTxo});
+
+  /// The channel_id of the channel.
+  @override
+  final ChannelId channelId;
+
+  /// The user_channel_id of the channel.
+  @override
+  final UserChannelId userChannelId;
+
+  /// The temporary_channel_id this channel used to be known by during channel establishment.
+  @override
+  final ChannelId formerTemporaryChannelId;
+
+  /// The node_id of the channel counterparty.
+  @override
+  final PublicKey counterpartyNodeId;
+
+  /// The outpoint of the channel's funding transaction.
+  @override
+  final OutPoint fundingTxo;
+
+  @override
+  String toString() {
+    return 'Event.channelPending(channelId: $channelId, userChannelId: $userChannelId, formerTemporaryChannelId: $formerTemporaryChannelId, counterpartyNodeId: $counterpartyNodeId, fundingTxo: $fundingTxo)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$Event_ChannelPending &&
+            (identical(other.channelId, channelId) || other.channelId == channelId) &&
+            (identical(other.userChannelId, userChannelId) || other.userChannelId == userChannelId) &&
+            (identical(other.formerTemporaryChannelId, formerTemporaryChannelId) || other.formerTemporaryChannelId == formerTemporaryChannelId) &&
+            (identical(other.counterpartyNodeId, counterpartyNodeId) || other.counterpartyNodeId == counterpartyNodeId) &&
+            (identical(other.fundingTxo, fundingTxo) || other.fundingTxo == fundingTxo));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$Event_ChannelPendingCopyWith<_$Event_ChannelPending> get copyWith => __$$Event_ChannelPendingCopyWithImpl<_$Event_ChannelPending>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(PaymentHash paymentHash) paymentSuccessful,
+    required TResult Function(PaymentHash paymentHash) paymentFailed,
+    required TResult Function(PaymentHash paymentHash, int amountMsat) paymentReceived,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelReady,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId) channelClosed,
+    required TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo) channelPending,
+  }) {
+    return channelPending(channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(PaymentHash paymentHash)? paymentSuccessful,
+    TResult? Function(PaymentHash paymentHash)? paymentFailed,
+    TResult? Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
+    TResult? Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+  }) {
+    return channelPending?.call(channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(PaymentHash paymentHash)? paymentSuccessful,
+    TResult Function(PaymentHash paymentHash)? paymentFailed,
+    TResult Function(PaymentHash paymentHash, int amountMsat)? paymentReceived,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelReady,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId)? channelClosed,
+    TResult Function(ChannelId channelId, UserChannelId userChannelId, ChannelId formerTemporaryChannelId, PublicKey counterpartyNodeId, OutPoint fundingTxo)? channelPending,
+    required TResult orElse(),
+  }) {
+    if (channelPending != null) {
+      return channelPending(channelId, userChannelId, formerTemporaryChannelId, counterpartyNodeId, fundingTxo);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(Event_PaymentSuccessful value) paymentSuccessful,
+    required TResult Function(Event_PaymentFailed value) paymentFailed,
+    required TResult Function(Event_PaymentReceived value) paymentReceived,
+    required TResult Function(Event_ChannelReady value) channelReady,
+    required TResult Function(Event_ChannelClosed value) channelClosed,
+    required TResult Function(Event_ChannelPending value) channelPending,
+  }) {
+    return channelPending(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(Event_PaymentSuccessful value)? paymentSuccessful,
+    TResult? Function(Event_PaymentFailed value)? paymentFailed,
+    TResult? Function(Event_PaymentReceived value)? paymentReceived,
+    TResult? Function(Event_ChannelReady value)? channelReady,
+    TResult? Function(Event_ChannelClosed value)? channelClosed,
+    TResult? Function(Event_ChannelPending value)? channelPending,
+  }) {
+    return channelPending?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(Event_PaymentSuccessful value)? paymentSuccessful,
+    TResult Function(Event_PaymentFailed value)? paymentFailed,
+    TResult Function(Event_PaymentReceived value)? paymentReceived,
+    TResult Function(Event_ChannelReady value)? channelReady,
+    TResult Function(Event_ChannelClosed value)? channelClosed,
+    TResult Function(Event_ChannelPending value)? channelPending,
+    required TResult orElse(),
+  }) {
+    if (channelPending != null) {
+      return channelPending(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class Event_ChannelPending implements Event {
+  const factory Event_ChannelPending(
+      {required final ChannelId channelId,
+      required final UserChannelId userChannelId,
+      required final ChannelId formerTemporaryChannelId,
+      required final PublicKey counterpartyNodeId,
+      required final OutPoint fundingTxo}) = _$Event_ChannelPending;
+
+  /// The channel_id of the channel.
+  ChannelId get channelId;
+
+  /// The user_channel_id of the channel.
+  UserChannelId get userChannelId;
+
+  /// The temporary_channel_id this channel used to be known by during channel establishment.
+  ChannelId get formerTemporaryChannelId;
+
+  /// The node_id of the channel counterparty.
+  PublicKey get counterpartyNodeId;
+
+  /// The outpoint of the channel's funding transaction.
+  OutPoint get fundingTxo;
+  @JsonKey(ignore: true)
+  _$$Event_ChannelPendingCopyWith<_$Event_ChannelPending> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$GossipSourceConfig {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() p2PNetwork,
+    required TResult Function(String field0) rapidGossipSync,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? p2PNetwork,
+    TResult? Function(String field0)? rapidGossipSync,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? p2PNetwork,
+    TResult Function(String field0)? rapidGossipSync,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GossipSourceConfig_P2PNetwork value) p2PNetwork,
+    required TResult Function(GossipSourceConfig_RapidGossipSync value) rapidGossipSync,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
+    TResult? Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
+    TResult Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $GossipSourceConfigCopyWith<$Res> {
+  factory $GossipSourceConfigCopyWith(GossipSourceConfig value, $Res Function(GossipSourceConfig) then) = _$GossipSourceConfigCopyWithImpl<$Res, GossipSourceConfig>;
+}
+
+/// @nodoc
+class _$GossipSourceConfigCopyWithImpl<$Res, $Val extends GossipSourceConfig> implements $GossipSourceConfigCopyWith<$Res> {
+  _$GossipSourceConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$GossipSourceConfig_P2PNetworkCopyWith<$Res> {
+  factory _$$GossipSourceConfig_P2PNetworkCopyWith(_$GossipSourceConfig_P2PNetwork value, $Res Function(_$GossipSourceConfig_P2PNetwork) then) = __$$GossipSourceConfig_P2PNetworkCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$GossipSourceConfig_P2PNetworkCopyWithImpl<$Res> extends _$GossipSourceConfigCopyWithImpl<$Res, _$GossipSourceConfig_P2PNetwork> implements _$$GossipSourceConfig_P2PNetworkCopyWith<$Res> {
+  __$$GossipSourceConfig_P2PNetworkCopyWithImpl(_$GossipSourceConfig_P2PNetwork _value, $Res Function(_$GossipSourceConfig_P2PNetwork) _then) : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$GossipSourceConfig_P2PNetwork implements GossipSourceConfig_P2PNetwork {
+  const _$GossipSourceConfig_P2PNetwork();
+
+  @override
+  String toString() {
+    return 'GossipSourceConfig.p2PNetwork()';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$GossipSourceConfig_P2PNetwork);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() p2PNetwork,
+    required TResult Function(String field0) rapidGossipSync,
+  }) {
+    return p2PNetwork();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? p2PNetwork,
+    TResult? Function(String field0)? rapidGossipSync,
+  }) {
+    return p2PNetwork?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? p2PNetwork,
+    TResult Function(String field0)? rapidGossipSync,
+    required TResult orElse(),
+  }) {
+    if (p2PNetwork != null) {
+      return p2PNetwork();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GossipSourceConfig_P2PNetwork value) p2PNetwork,
+    required TResult Function(GossipSourceConfig_RapidGossipSync value) rapidGossipSync,
+  }) {
+    return p2PNetwork(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
+    TResult? Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+  }) {
+    return p2PNetwork?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
+    TResult Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    required TResult orElse(),
+  }) {
+    if (p2PNetwork != null) {
+      return p2PNetwork(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GossipSourceConfig_P2PNetwork implements GossipSourceConfig {
+  const factory GossipSourceConfig_P2PNetwork() = _$GossipSourceConfig_P2PNetwork;
+}
+
+/// @nodoc
+abstract class _$$GossipSourceConfig_RapidGossipSyncCopyWith<$Res> {
+  factory _$$GossipSourceConfig_RapidGossipSyncCopyWith(_$GossipSourceConfig_RapidGossipSync value, $Res Function(_$GossipSourceConfig_RapidGossipSync) then) =
+      __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl<$Res> extends _$GossipSourceConfigCopyWithImpl<$Res, _$GossipSourceConfig_RapidGossipSync>
+    implements _$$GossipSourceConfig_RapidGossipSyncCopyWith<$Res> {
+  __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl(_$GossipSourceConfig_RapidGossipSync _value, $Res Function(_$GossipSourceConfig_RapidGossipSync) _then) : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$GossipSourceConfig_RapidGossipSync(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$GossipSourceConfig_RapidGossipSync implements GossipSourceConfig_RapidGossipSync {
+  const _$GossipSourceConfig_RapidGossipSync(this.field0);
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'GossipSourceConfig.rapidGossipSync(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$GossipSourceConfig_RapidGossipSync && (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$GossipSourceConfig_RapidGossipSyncCopyWith<_$GossipSourceConfig_RapidGossipSync> get copyWith =>
+      __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl<_$GossipSourceConfig_RapidGossipSync>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() p2PNetwork,
     required TResult Function(String field0) rapidGossipSync,
+  }) {
+    return rapidGossipSync(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? p2PNetwork,
+    TResult? Function(String field0)? rapidGossipSync,
+  }) {
+    return rapidGossipSync?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? p2PNetwork,
+    TResult Function(String field0)? rapidGossipSync,
+    required TResult orElse(),
+  }) {
+    if (rapidGossipSync != null) {
+      return rapidGossipSync(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GossipSourceConfig_P2PNetwork value) p2PNetwork,
+    required TResult Function(GossipSourceConfig_RapidGossipSync value) rapidGossipSync,
+  }) {
+    return rapidGossipSync(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
+    TResult? Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+  }) {
+    return rapidGossipSync?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
+    TResult Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    required TResult orElse(),
+  }) {
+    if (rapidGossipSync != null) {
+      return rapidGossipSync(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class GossipSourceConfig_RapidGossipSync implements GossipSourceConfig {
+  const factory GossipSourceConfig_RapidGossipSync(final String field0) = _$GossipSourceConfig_RapidGossipSync;
+
+  String get field0;
+  @JsonKey(ignore: true)
+  _$$GossipSourceConfig_RapidGossipSyncCopyWith<_$GossipSourceConfig_RapidGossipSync> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$NetAddress {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String addr, int port) iPv4,
+    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(U8Array12 addr) onionV2,
+    required TResult Function(U8Array32 pubkey, int checksum, int version, int port) onionV3,
+    required TResult Function(String host, int port) hostname,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String addr, int port)? iPv4,
+    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(U8Array12 addr)? onionV2,
+    TResult? Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult? Function(String host, int port)? hostname,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String addr, int port)? iPv4,
+    TResult Function(String addr, int port)? iPv6,
+    TResult Function(U8Array12 addr)? onionV2,
+    TResult Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult Function(String host, int port)? hostname,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NetAddress_IPv4 value) iPv4,
+    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(NetAddress_OnionV2 value) onionV2,
+    required TResult Function(NetAddress_OnionV3 value) onionV3,
+    required TResult Function(NetAddress_Hostname value) hostname,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NetAddress_IPv4 value)? iPv4,
+    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(NetAddress_OnionV2 value)? onionV2,
+    TResult? Function(NetAddress_OnionV3 value)? onionV3,
+    TResult? Function(NetAddress_Hostname value)? hostname,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NetAddress_IPv4 value)? iPv4,
+    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(NetAddress_OnionV2 value)? onionV2,
+    TResult Function(NetAddress_OnionV3 value)? onionV3,
+    TResult Function(NetAddress_Hostname value)? hostname,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $NetAddressCopyWith<$Res> {
+  factory $NetAddressCopyWith(NetAddress value, $Res Function(NetAddress) then) = _$NetAddressCopyWithImpl<$Res, NetAddress>;
+}
+
+/// @nodoc
+class _$NetAddressCopyWithImpl<$Res, $Val extends NetAddress> implements $NetAddressCopyWith<$Res> {
+  _$NetAddressCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$NetAddress_IPv4CopyWith<$Res> {
+  factory _$$NetAddress_IPv4CopyWith(_$NetAddress_IPv4 value, $Res Function(_$NetAddress_IPv4) then) = __$$NetAddress_IPv4CopyWithImpl<$Res>;
+  @useResult
+  $Res call({String addr, int port});
+}
+
+/// @nodoc
+class __$$NetAddress_IPv4CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Res, _$NetAddress_IPv4> implements _$$NetAddress_IPv4CopyWith<$Res> {
+  __$$NetAddress_IPv4CopyWithImpl(_$NetAddress_IPv4 _value, $Res Function(_$NetAddress_IPv4) _then) : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? addr = null,
+    Object? port = null,
+  }) {
+    return _then(_$NetAddress_IPv4(
+      addr: null == addr
+          ? _value.addr
+          : addr // ignore: cast_nullable_to_non_nullable
+              as String,
+      port: null == port
+          ? _value.port
+          : port // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$NetAddress_IPv4 implements NetAddress_IPv4 {
+  const _$NetAddress_IPv4({required this.addr, required this.port});
+
+  @override
+  final String addr;
+  @override
+  final int port;
+
+  @override
+  String toString() {
+    return 'NetAddress.iPv4(addr: $addr, port: $port)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$NetAddress_IPv4 && (identical(other.addr, addr) || other.addr == addr) && (identical(other.port, port) || other.port == port));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, addr, port);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NetAddress_IPv4CopyWith<_$NetAddress_IPv4> get copyWith => __$$NetAddress_IPv4CopyWithImpl<_$NetAddress_IPv4>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String addr, int port) iPv4,
+    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(U8Array12 addr) onionV2,
+    required TResult Function(U8Array32 pubkey, int checksum, int version, int port) onionV3,
+    required TResult Function(String host, int port) hostname,
+  }) {
+    return iPv4(addr, port);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String addr, int port)? iPv4,
+    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(U8Array12 addr)? onionV2,
+    TResult? Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult? Function(String host, int port)? hostname,
+  }) {
+    return iPv4?.call(addr, port);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String addr, int port)? iPv4,
+    TResult Function(String addr, int port)? iPv6,
+    TResult Function(U8Array12 addr)? onionV2,
+    TResult Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult Function(String host, int port)? hostname,
+    required TResult orElse(),
+  }) {
+    if (iPv4 != null) {
+      return iPv4(addr, port);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NetAddress_IPv4 value) iPv4,
+    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(NetAddress_OnionV2 value) onionV2,
+    required TResult Function(NetAddress_OnionV3 value) onionV3,
+    required TResult Function(NetAddress_Hostname value) hostname,
+  }) {
+    return iPv4(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NetAddress_IPv4 value)? iPv4,
+    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(NetAddress_OnionV2 value)? onionV2,
+    TResult? Function(NetAddress_OnionV3 value)? onionV3,
+    TResult? Function(NetAddress_Hostname value)? hostname,
+  }) {
+    return iPv4?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NetAddress_IPv4 value)? iPv4,
+    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(NetAddress_OnionV2 value)? onionV2,
+    TResult Function(NetAddress_OnionV3 value)? onionV3,
+    TResult Function(NetAddress_Hostname value)? hostname,
+    required TResult orElse(),
+  }) {
+    if (iPv4 != null) {
+      return iPv4(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NetAddress_IPv4 implements NetAddress {
+  const factory NetAddress_IPv4({required final String addr, required final int port}) = _$NetAddress_IPv4;
+
+  String get addr;
+  int get port;
+  @JsonKey(ignore: true)
+  _$$NetAddress_IPv4CopyWith<_$NetAddress_IPv4> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$NetAddress_IPv6CopyWith<$Res> {
+  factory _$$NetAddress_IPv6CopyWith(_$NetAddress_IPv6 value, $Res Function(_$NetAddress_IPv6) then) = __$$NetAddress_IPv6CopyWithImpl<$Res>;
+  @useResult
+  $Res call({String addr, int port});
+}
+
+/// @nodoc
+class __$$NetAddress_IPv6CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Res, _$NetAddress_IPv6> implements _$$NetAddress_IPv6CopyWith<$Res> {
+  __$$NetAddress_IPv6CopyWithImpl(_$NetAddress_IPv6 _value, $Res Function(_$NetAddress_IPv6) _then) : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? addr = null,
+    Object? port = null,
+  }) {
+    return _then(_$NetAddress_IPv6(
+      addr: null == addr
+          ? _value.addr
+          : addr // ignore: cast_nullable_to_non_nullable
+              as String,
+      port: null == port
+          ? _value.port
+          : port // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$NetAddress_IPv6 implements NetAddress_IPv6 {
+  const _$NetAddress_IPv6({required this.addr, required this.port});
+
+  @override
+  final String addr;
+  @override
+  final int port;
+
+  @override
+  String toString() {
+    return 'NetAddress.iPv6(addr: $addr, port: $port)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$NetAddress_IPv6 && (identical(other.addr, addr) || other.addr == addr) && (identical(other.port, port) || other.port == port));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, addr, port);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NetAddress_IPv6CopyWith<_$NetAddress_IPv6> get copyWith => __$$NetAddress_IPv6CopyWithImpl<_$NetAddress_IPv6>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String addr, int port) iPv4,
+    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(U8Array12 addr) onionV2,
+    required TResult Function(U8Array32 pubkey, int checksum, int version, int port) onionV3,
+    required TResult Function(String host, int port) hostname,
+  }) {
+    return iPv6(addr, port);
+  }
+
+  @override
+  @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? p2PNetwork,
-    TResult? Function(String field0)? rapidGossipSync,
-  }) =>
-      throw _privateConstructorUsedError;
+    TResult? Function(String addr, int port)? iPv4,
+    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(U8Array12 addr)? onionV2,
+    TResult? Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult? Function(String host, int port)? hostname,
+  }) {
+    return iPv6?.call(addr, port);
+  }
+
+  @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? p2PNetwork,
-    TResult Function(String field0)? rapidGossipSync,
+    TResult Function(String addr, int port)? iPv4,
+    TResult Function(String addr, int port)? iPv6,
+    TResult Function(U8Array12 addr)? onionV2,
+    TResult Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult Function(String host, int port)? hostname,
     required TResult orElse(),
-  }) =>
-      throw _privateConstructorUsedError;
+  }) {
+    if (iPv6 != null) {
+      return iPv6(addr, port);
+    }
+    return orElse();
+  }
+
+  @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GossipSourceConfig_P2PNetwork value) p2PNetwork,
-    required TResult Function(GossipSourceConfig_RapidGossipSync value) rapidGossipSync,
-  }) =>
-      throw _privateConstructorUsedError;
+    required TResult Function(NetAddress_IPv4 value) iPv4,
+    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(NetAddress_OnionV2 value) onionV2,
+    required TResult Function(NetAddress_OnionV3 value) onionV3,
+    required TResult Function(NetAddress_Hostname value) hostname,
+  }) {
+    return iPv6(this);
+  }
+
+  @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
-    TResult? Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
-  }) =>
-      throw _privateConstructorUsedError;
+    TResult? Function(NetAddress_IPv4 value)? iPv4,
+    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(NetAddress_OnionV2 value)? onionV2,
+    TResult? Function(NetAddress_OnionV3 value)? onionV3,
+    TResult? Function(NetAddress_Hostname value)? hostname,
+  }) {
+    return iPv6?.call(this);
+  }
+
+  @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
-    TResult Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    TResult Function(NetAddress_IPv4 value)? iPv4,
+    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(NetAddress_OnionV2 value)? onionV2,
+    TResult Function(NetAddress_OnionV3 value)? onionV3,
+    TResult Function(NetAddress_Hostname value)? hostname,
     required TResult orElse(),
-  }) =>
-      throw _privateConstructorUsedError;
+  }) {
+    if (iPv6 != null) {
+      return iPv6(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NetAddress_IPv6 implements NetAddress {
+  const factory NetAddress_IPv6({required final String addr, required final int port}) = _$NetAddress_IPv6;
+
+  String get addr;
+  int get port;
+  @JsonKey(ignore: true)
+  _$$NetAddress_IPv6CopyWith<_$NetAddress_IPv6> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $GossipSourceConfigCopyWith<$Res> {
-  factory $GossipSourceConfigCopyWith(GossipSourceConfig value, $Res Function(GossipSourceConfig) then) = _$GossipSourceConfigCopyWithImpl<$Res, GossipSourceConfig>;
+abstract class _$$NetAddress_OnionV2CopyWith<$Res> {
+  factory _$$NetAddress_OnionV2CopyWith(_$NetAddress_OnionV2 value, $Res Function(_$NetAddress_OnionV2) then) = __$$NetAddress_OnionV2CopyWithImpl<$Res>;
+  @useResult
+  $Res call({U8Array12 addr});
 }
 
 /// @nodoc
-class _$GossipSourceConfigCopyWithImpl<$Res, $Val extends GossipSourceConfig> implements $GossipSourceConfigCopyWith<$Res> {
-  _$GossipSourceConfigCopyWithImpl(this._value, this._then);
+class __$$NetAddress_OnionV2CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Res, _$NetAddress_OnionV2> implements _$$NetAddress_OnionV2CopyWith<$Res> {
+  __$$NetAddress_OnionV2CopyWithImpl(_$NetAddress_OnionV2 _value, $Res Function(_$NetAddress_OnionV2) _then) : super(_value, _then);
 
-  // ignore: unused_field
-  final $Val _value;
-  // ignore: unused_field
-  final $Res Function($Val) _then;
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? addr = null,
+  }) {
+    return _then(_$NetAddress_OnionV2(
+      addr: null == addr
+          ? _value.addr
+          : addr // ignore: cast_nullable_to_non_nullable
+              as U8Array12,
+    ));
+  }
 }
 
 /// @nodoc
-abstract class _$$GossipSourceConfig_P2PNetworkCopyWith<$Res> {
-  factory _$$GossipSourceConfig_P2PNetworkCopyWith(_$GossipSourceConfig_P2PNetwork value, $Res Function(_$GossipSourceConfig_P2PNetwork) then) = __$$GossipSourceConfig_P2PNetworkCopyWithImpl<$Res>;
+
+class _$NetAddress_OnionV2 implements NetAddress_OnionV2 {
+  const _$NetAddress_OnionV2({required this.addr});
+
+  @override
+  final U8Array12 addr;
+
+  @override
+  String toString() {
+    return 'NetAddress.onionV2(addr: $addr)';
+  }
+
+  @override
+  bool operator ==(dynamic other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$NetAddress_OnionV2 && const DeepCollectionEquality().equals(other.addr, addr));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(addr));
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NetAddress_OnionV2CopyWith<_$NetAddress_OnionV2> get copyWith => __$$NetAddress_OnionV2CopyWithImpl<_$NetAddress_OnionV2>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String addr, int port) iPv4,
+    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(U8Array12 addr) onionV2,
+    required TResult Function(U8Array32 pubkey, int checksum, int version, int port) onionV3,
+    required TResult Function(String host, int port) hostname,
+  }) {
+    return onionV2(addr);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String addr, int port)? iPv4,
+    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(U8Array12 addr)? onionV2,
+    TResult? Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult? Function(String host, int port)? hostname,
+  }) {
+    return onionV2?.call(addr);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String addr, int port)? iPv4,
+    TResult Function(String addr, int port)? iPv6,
+    TResult Function(U8Array12 addr)? onionV2,
+    TResult Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult Function(String host, int port)? hostname,
+    required TResult orElse(),
+  }) {
+    if (onionV2 != null) {
+      return onionV2(addr);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NetAddress_IPv4 value) iPv4,
+    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(NetAddress_OnionV2 value) onionV2,
+    required TResult Function(NetAddress_OnionV3 value) onionV3,
+    required TResult Function(NetAddress_Hostname value) hostname,
+  }) {
+    return onionV2(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NetAddress_IPv4 value)? iPv4,
+    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(NetAddress_OnionV2 value)? onionV2,
+    TResult? Function(NetAddress_OnionV3 value)? onionV3,
+    TResult? Function(NetAddress_Hostname value)? hostname,
+  }) {
+    return onionV2?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NetAddress_IPv4 value)? iPv4,
+    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(NetAddress_OnionV2 value)? onionV2,
+    TResult Function(NetAddress_OnionV3 value)? onionV3,
+    TResult Function(NetAddress_Hostname value)? hostname,
+    required TResult orElse(),
+  }) {
+    if (onionV2 != null) {
+      return onionV2(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NetAddress_OnionV2 implements NetAddress {
+  const factory NetAddress_OnionV2({required final U8Array12 addr}) = _$NetAddress_OnionV2;
+
+  U8Array12 get addr;
+  @JsonKey(ignore: true)
+  _$$NetAddress_OnionV2CopyWith<_$NetAddress_OnionV2> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-class __$$GossipSourceConfig_P2PNetworkCopyWithImpl<$Res> extends _$GossipSourceConfigCopyWithImpl<$Res, _$GossipSourceConfig_P2PNetwork> implements _$$GossipSourceConfig_P2PNetworkCopyWith<$Res> {
-  __$$GossipSourceConfig_P2PNetworkCopyWithImpl(_$GossipSourceConfig_P2PNetwork _value, $Res Function(_$GossipSourceConfig_P2PNetwork) _then) : super(_value, _then);
+abstract class _$$NetAddress_OnionV3CopyWith<$Res> {
+  factory _$$NetAddress_OnionV3CopyWith(_$NetAddress_OnionV3 value, $Res Function(_$NetAddress_OnionV3) then) = __$$NetAddress_OnionV3CopyWithImpl<$Res>;
+  @useResult
+  $Res call({U8Array32 pubkey, int checksum, int version, int port});
 }
 
 /// @nodoc
+class __$$NetAddress_OnionV3CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Res, _$NetAddress_OnionV3> implements _$$NetAddress_OnionV3CopyWith<$Res> {
+  __$$NetAddress_OnionV3CopyWithImpl(_$NetAddress_OnionV3 _value, $Res Function(_$NetAddress_OnionV3) _then) : super(_value, _then);
 
-class _$GossipSourceConfig_P2PNetwork implements GossipSourceConfig_P2PNetwork {
-  const _$GossipSourceConfig_P2PNetwork();
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? pubkey = null,
+    Object? checksum = null,
+    Object? version = null,
+    Object? port = null,
+  }) {
+    return _then(_$NetAddress_OnionV3(
+      pubkey: null == pubkey
+          ? _value.pubkey
+          : pubkey // ignore: cast_nullable_to_non_nullable
+              as U8Array32,
+      checksum: null == checksum
+          ? _value.checksum
+          : checksum // ignore: cast_nullable_to_non_nullable
+              as int,
+      version: null == version
+          ? _value.version
+          : version // ignore: cast_nullable_to_non_nullable
+              as int,
+      port: null == port
+          ? _value.port
+          : port // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$NetAddress_OnionV3 implements NetAddress_OnionV3 {
+  const _$NetAddress_OnionV3({required this.pubkey, required this.checksum, required this.version, required this.port});
+
+  ///The ed25519 long-term public key of the peer.
+  ///
+  @override
+  final U8Array32 pubkey;
+
+  ///The checksum of the pubkey and version, as included in the onion address.
+  ///
+  @override
+  final int checksum;
+
+  ///The version byte, as defined by the Tor Onion v3 spec.
+  ///
+  @override
+  final int version;
+
+  ///The port on which the node is listening.
+  ///
+  @override
+  final int port;
 
   @override
   String toString() {
-    return 'GossipSourceConfig.p2PNetwork()';
+    return 'NetAddress.onionV3(pubkey: $pubkey, checksum: $checksum, version: $version, port: $port)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$GossipSourceConfig_P2PNetwork);
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NetAddress_OnionV3 &&
+            const DeepCollectionEquality().equals(other.pubkey, pubkey) &&
+            (identical(other.checksum, checksum) || other.checksum == checksum) &&
+            (identical(other.version, version) || other.version == version) &&
+            (identical(other.port, port) || other.port == port));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
+  int get hashCode => Object.hash(runtimeType, const DeepCollectionEquality().hash(pubkey), checksum, version, port);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NetAddress_OnionV3CopyWith<_$NetAddress_OnionV3> get copyWith => __$$NetAddress_OnionV3CopyWithImpl<_$NetAddress_OnionV3>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() p2PNetwork,
-    required TResult Function(String field0) rapidGossipSync,
+    required TResult Function(String addr, int port) iPv4,
+    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(U8Array12 addr) onionV2,
+    required TResult Function(U8Array32 pubkey, int checksum, int version, int port) onionV3,
+    required TResult Function(String host, int port) hostname,
   }) {
-    return p2PNetwork();
+    return onionV3(pubkey, checksum, version, port);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? p2PNetwork,
-    TResult? Function(String field0)? rapidGossipSync,
+    TResult? Function(String addr, int port)? iPv4,
+    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(U8Array12 addr)? onionV2,
+    TResult? Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult? Function(String host, int port)? hostname,
   }) {
-    return p2PNetwork?.call();
+    return onionV3?.call(pubkey, checksum, version, port);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? p2PNetwork,
-    TResult Function(String field0)? rapidGossipSync,
+    TResult Function(String addr, int port)? iPv4,
+    TResult Function(String addr, int port)? iPv6,
+    TResult Function(U8Array12 addr)? onionV2,
+    TResult Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult Function(String host, int port)? hostname,
     required TResult orElse(),
   }) {
-    if (p2PNetwork != null) {
-      return p2PNetwork();
+    if (onionV3 != null) {
+      return onionV3(pubkey, checksum, version, port);
     }
     return orElse();
   }
@@ -1861,120 +3084,161 @@ class _$GossipSourceConfig_P2PNetwork implements GossipSourceConfig_P2PNetwork {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GossipSourceConfig_P2PNetwork value) p2PNetwork,
-    required TResult Function(GossipSourceConfig_RapidGossipSync value) rapidGossipSync,
+    required TResult Function(NetAddress_IPv4 value) iPv4,
+    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(NetAddress_OnionV2 value) onionV2,
+    required TResult Function(NetAddress_OnionV3 value) onionV3,
+    required TResult Function(NetAddress_Hostname value) hostname,
   }) {
-    return p2PNetwork(this);
+    return onionV3(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
-    TResult? Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    TResult? Function(NetAddress_IPv4 value)? iPv4,
+    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(NetAddress_OnionV2 value)? onionV2,
+    TResult? Function(NetAddress_OnionV3 value)? onionV3,
+    TResult? Function(NetAddress_Hostname value)? hostname,
   }) {
-    return p2PNetwork?.call(this);
+    return onionV3?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
-    TResult Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    TResult Function(NetAddress_IPv4 value)? iPv4,
+    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(NetAddress_OnionV2 value)? onionV2,
+    TResult Function(NetAddress_OnionV3 value)? onionV3,
+    TResult Function(NetAddress_Hostname value)? hostname,
     required TResult orElse(),
   }) {
-    if (p2PNetwork != null) {
-      return p2PNetwork(this);
+    if (onionV3 != null) {
+      return onionV3(this);
     }
     return orElse();
   }
 }
 
-abstract class GossipSourceConfig_P2PNetwork implements GossipSourceConfig {
-  const factory GossipSourceConfig_P2PNetwork() = _$GossipSourceConfig_P2PNetwork;
+abstract class NetAddress_OnionV3 implements NetAddress {
+  const factory NetAddress_OnionV3({required final U8Array32 pubkey, required final int checksum, required final int version, required final int port}) = _$NetAddress_OnionV3;
+
+  ///The ed25519 long-term public key of the peer.
+  ///
+  U8Array32 get pubkey;
+
+  ///The checksum of the pubkey and version, as included in the onion address.
+  ///
+  int get checksum;
+
+  ///The version byte, as defined by the Tor Onion v3 spec.
+  ///
+  int get version;
+
+  ///The port on which the node is listening.
+  ///
+  int get port;
+  @JsonKey(ignore: true)
+  _$$NetAddress_OnionV3CopyWith<_$NetAddress_OnionV3> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$GossipSourceConfig_RapidGossipSyncCopyWith<$Res> {
-  factory _$$GossipSourceConfig_RapidGossipSyncCopyWith(_$GossipSourceConfig_RapidGossipSync value, $Res Function(_$GossipSourceConfig_RapidGossipSync) then) =
-      __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl<$Res>;
+abstract class _$$NetAddress_HostnameCopyWith<$Res> {
+  factory _$$NetAddress_HostnameCopyWith(_$NetAddress_Hostname value, $Res Function(_$NetAddress_Hostname) then) = __$$NetAddress_HostnameCopyWithImpl<$Res>;
   @useResult
-  $Res call({String field0});
+  $Res call({String host, int port});
 }
 
 /// @nodoc
-class __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl<$Res> extends _$GossipSourceConfigCopyWithImpl<$Res, _$GossipSourceConfig_RapidGossipSync>
-    implements _$$GossipSourceConfig_RapidGossipSyncCopyWith<$Res> {
-  __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl(_$GossipSourceConfig_RapidGossipSync _value, $Res Function(_$GossipSourceConfig_RapidGossipSync) _then) : super(_value, _then);
+class __$$NetAddress_HostnameCopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Res, _$NetAddress_Hostname> implements _$$NetAddress_HostnameCopyWith<$Res> {
+  __$$NetAddress_HostnameCopyWithImpl(_$NetAddress_Hostname _value, $Res Function(_$NetAddress_Hostname) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? field0 = null,
+    Object? host = null,
+    Object? port = null,
   }) {
-    return _then(_$GossipSourceConfig_RapidGossipSync(
-      null == field0
-          ? _value.field0
-          : field0 // ignore: cast_nullable_to_non_nullable
+    return _then(_$NetAddress_Hostname(
+      host: null == host
+          ? _value.host
+          : host // ignore: cast_nullable_to_non_nullable
               as String,
+      port: null == port
+          ? _value.port
+          : port // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$GossipSourceConfig_RapidGossipSync implements GossipSourceConfig_RapidGossipSync {
-  const _$GossipSourceConfig_RapidGossipSync(this.field0);
+class _$NetAddress_Hostname implements NetAddress_Hostname {
+  const _$NetAddress_Hostname({required this.host, required this.port});
 
   @override
-  final String field0;
+  final String host;
+  @override
+  final int port;
 
   @override
   String toString() {
-    return 'GossipSourceConfig.rapidGossipSync(field0: $field0)';
+    return 'NetAddress.hostname(host: $host, port: $port)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$GossipSourceConfig_RapidGossipSync && (identical(other.field0, field0) || other.field0 == field0));
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$NetAddress_Hostname && (identical(other.host, host) || other.host == host) && (identical(other.port, port) || other.port == port));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, field0);
+  int get hashCode => Object.hash(runtimeType, host, port);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$GossipSourceConfig_RapidGossipSyncCopyWith<_$GossipSourceConfig_RapidGossipSync> get copyWith =>
-      __$$GossipSourceConfig_RapidGossipSyncCopyWithImpl<_$GossipSourceConfig_RapidGossipSync>(this, _$identity);
+  _$$NetAddress_HostnameCopyWith<_$NetAddress_Hostname> get copyWith => __$$NetAddress_HostnameCopyWithImpl<_$NetAddress_Hostname>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() p2PNetwork,
-    required TResult Function(String field0) rapidGossipSync,
+    required TResult Function(String addr, int port) iPv4,
+    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(U8Array12 addr) onionV2,
+    required TResult Function(U8Array32 pubkey, int checksum, int version, int port) onionV3,
+    required TResult Function(String host, int port) hostname,
   }) {
-    return rapidGossipSync(field0);
+    return hostname(host, port);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? p2PNetwork,
-    TResult? Function(String field0)? rapidGossipSync,
+    TResult? Function(String addr, int port)? iPv4,
+    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(U8Array12 addr)? onionV2,
+    TResult? Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult? Function(String host, int port)? hostname,
   }) {
-    return rapidGossipSync?.call(field0);
+    return hostname?.call(host, port);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? p2PNetwork,
-    TResult Function(String field0)? rapidGossipSync,
+    TResult Function(String addr, int port)? iPv4,
+    TResult Function(String addr, int port)? iPv6,
+    TResult Function(U8Array12 addr)? onionV2,
+    TResult Function(U8Array32 pubkey, int checksum, int version, int port)? onionV3,
+    TResult Function(String host, int port)? hostname,
     required TResult orElse(),
   }) {
-    if (rapidGossipSync != null) {
-      return rapidGossipSync(field0);
+    if (hostname != null) {
+      return hostname(host, port);
     }
     return orElse();
   }
@@ -1982,100 +3246,109 @@ class _$GossipSourceConfig_RapidGossipSync implements GossipSourceConfig_RapidGo
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(GossipSourceConfig_P2PNetwork value) p2PNetwork,
-    required TResult Function(GossipSourceConfig_RapidGossipSync value) rapidGossipSync,
+    required TResult Function(NetAddress_IPv4 value) iPv4,
+    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(NetAddress_OnionV2 value) onionV2,
+    required TResult Function(NetAddress_OnionV3 value) onionV3,
+    required TResult Function(NetAddress_Hostname value) hostname,
   }) {
-    return rapidGossipSync(this);
+    return hostname(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
-    TResult? Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    TResult? Function(NetAddress_IPv4 value)? iPv4,
+    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(NetAddress_OnionV2 value)? onionV2,
+    TResult? Function(NetAddress_OnionV3 value)? onionV3,
+    TResult? Function(NetAddress_Hostname value)? hostname,
   }) {
-    return rapidGossipSync?.call(this);
+    return hostname?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(GossipSourceConfig_P2PNetwork value)? p2PNetwork,
-    TResult Function(GossipSourceConfig_RapidGossipSync value)? rapidGossipSync,
+    TResult Function(NetAddress_IPv4 value)? iPv4,
+    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(NetAddress_OnionV2 value)? onionV2,
+    TResult Function(NetAddress_OnionV3 value)? onionV3,
+    TResult Function(NetAddress_Hostname value)? hostname,
     required TResult orElse(),
   }) {
-    if (rapidGossipSync != null) {
-      return rapidGossipSync(this);
+    if (hostname != null) {
+      return hostname(this);
     }
     return orElse();
   }
 }
 
-abstract class GossipSourceConfig_RapidGossipSync implements GossipSourceConfig {
-  const factory GossipSourceConfig_RapidGossipSync(final String field0) = _$GossipSourceConfig_RapidGossipSync;
+abstract class NetAddress_Hostname implements NetAddress {
+  const factory NetAddress_Hostname({required final String host, required final int port}) = _$NetAddress_Hostname;
 
-  String get field0;
+  String get host;
+  int get port;
   @JsonKey(ignore: true)
-  _$$GossipSourceConfig_RapidGossipSyncCopyWith<_$GossipSourceConfig_RapidGossipSync> get copyWith => throw _privateConstructorUsedError;
+  _$$NetAddress_HostnameCopyWith<_$NetAddress_Hostname> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$NetAddress {
-  String get addr => throw _privateConstructorUsedError;
-  int get port => throw _privateConstructorUsedError;
+mixin _$RetryStrategy {
+  int get field0 => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String addr, int port) iPv4,
-    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(int field0) attempts,
+    required TResult Function(int field0) timeoutSecs,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String addr, int port)? iPv4,
-    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(int field0)? attempts,
+    TResult? Function(int field0)? timeoutSecs,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String addr, int port)? iPv4,
-    TResult Function(String addr, int port)? iPv6,
+    TResult Function(int field0)? attempts,
+    TResult Function(int field0)? timeoutSecs,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NetAddress_IPv4 value) iPv4,
-    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(RetryStrategy_Attempts value) attempts,
+    required TResult Function(RetryStrategy_TimeoutSecs value) timeoutSecs,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NetAddress_IPv4 value)? iPv4,
-    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(RetryStrategy_Attempts value)? attempts,
+    TResult? Function(RetryStrategy_TimeoutSecs value)? timeoutSecs,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NetAddress_IPv4 value)? iPv4,
-    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(RetryStrategy_Attempts value)? attempts,
+    TResult Function(RetryStrategy_TimeoutSecs value)? timeoutSecs,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 
   @JsonKey(ignore: true)
-  $NetAddressCopyWith<NetAddress> get copyWith => throw _privateConstructorUsedError;
+  $RetryStrategyCopyWith<RetryStrategy> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $NetAddressCopyWith<$Res> {
-  factory $NetAddressCopyWith(NetAddress value, $Res Function(NetAddress) then) = _$NetAddressCopyWithImpl<$Res, NetAddress>;
+abstract class $RetryStrategyCopyWith<$Res> {
+  factory $RetryStrategyCopyWith(RetryStrategy value, $Res Function(RetryStrategy) then) = _$RetryStrategyCopyWithImpl<$Res, RetryStrategy>;
   @useResult
-  $Res call({String addr, int port});
+  $Res call({int field0});
 }
 
 /// @nodoc
-class _$NetAddressCopyWithImpl<$Res, $Val extends NetAddress> implements $NetAddressCopyWith<$Res> {
-  _$NetAddressCopyWithImpl(this._value, this._then);
+class _$RetryStrategyCopyWithImpl<$Res, $Val extends RetryStrategy> implements $RetryStrategyCopyWith<$Res> {
+  _$RetryStrategyCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -2085,48 +3358,38 @@ class _$NetAddressCopyWithImpl<$Res, $Val extends NetAddress> implements $NetAdd
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? addr = null,
-    Object? port = null,
+    Object? field0 = null,
   }) {
     return _then(_value.copyWith(
-      addr: null == addr
-          ? _value.addr
-          : addr // ignore: cast_nullable_to_non_nullable
-              as String,
-      port: null == port
-          ? _value.port
-          : port // ignore: cast_nullable_to_non_nullable
+      field0: null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
               as int,
     ) as $Val);
   }
 }
 
 /// @nodoc
-abstract class _$$NetAddress_IPv4CopyWith<$Res> implements $NetAddressCopyWith<$Res> {
-  factory _$$NetAddress_IPv4CopyWith(_$NetAddress_IPv4 value, $Res Function(_$NetAddress_IPv4) then) = __$$NetAddress_IPv4CopyWithImpl<$Res>;
+abstract class _$$RetryStrategy_AttemptsCopyWith<$Res> implements $RetryStrategyCopyWith<$Res> {
+  factory _$$RetryStrategy_AttemptsCopyWith(_$RetryStrategy_Attempts value, $Res Function(_$RetryStrategy_Attempts) then) = __$$RetryStrategy_AttemptsCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String addr, int port});
+  $Res call({int field0});
 }
 
 /// @nodoc
-class __$$NetAddress_IPv4CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Res, _$NetAddress_IPv4> implements _$$NetAddress_IPv4CopyWith<$Res> {
-  __$$NetAddress_IPv4CopyWithImpl(_$NetAddress_IPv4 _value, $Res Function(_$NetAddress_IPv4) _then) : super(_value, _then);
+class __$$RetryStrategy_AttemptsCopyWithImpl<$Res> extends _$RetryStrategyCopyWithImpl<$Res, _$RetryStrategy_Attempts> implements _$$RetryStrategy_AttemptsCopyWith<$Res> {
+  __$$RetryStrategy_AttemptsCopyWithImpl(_$RetryStrategy_Attempts _value, $Res Function(_$RetryStrategy_Attempts) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? addr = null,
-    Object? port = null,
+    Object? field0 = null,
   }) {
-    return _then(_$NetAddress_IPv4(
-      addr: null == addr
-          ? _value.addr
-          : addr // ignore: cast_nullable_to_non_nullable
-              as String,
-      port: null == port
-          ? _value.port
-          : port // ignore: cast_nullable_to_non_nullable
+    return _then(_$RetryStrategy_Attempts(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
               as int,
     ));
   }
@@ -2134,60 +3397,57 @@ class __$$NetAddress_IPv4CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Re
 
 /// @nodoc
 
-class _$NetAddress_IPv4 implements NetAddress_IPv4 {
-  const _$NetAddress_IPv4({required this.addr, required this.port});
+class _$RetryStrategy_Attempts implements RetryStrategy_Attempts {
+  const _$RetryStrategy_Attempts(this.field0);
 
   @override
-  final String addr;
-  @override
-  final int port;
+  final int field0;
 
   @override
   String toString() {
-    return 'NetAddress.iPv4(addr: $addr, port: $port)';
+    return 'RetryStrategy.attempts(field0: $field0)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) ||
-        (other.runtimeType == runtimeType && other is _$NetAddress_IPv4 && (identical(other.addr, addr) || other.addr == addr) && (identical(other.port, port) || other.port == port));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$RetryStrategy_Attempts && (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, addr, port);
+  int get hashCode => Object.hash(runtimeType, field0);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$NetAddress_IPv4CopyWith<_$NetAddress_IPv4> get copyWith => __$$NetAddress_IPv4CopyWithImpl<_$NetAddress_IPv4>(this, _$identity);
+  _$$RetryStrategy_AttemptsCopyWith<_$RetryStrategy_Attempts> get copyWith => __$$RetryStrategy_AttemptsCopyWithImpl<_$RetryStrategy_Attempts>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String addr, int port) iPv4,
-    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(int field0) attempts,
+    required TResult Function(int field0) timeoutSecs,
   }) {
-    return iPv4(addr, port);
+    return attempts(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String addr, int port)? iPv4,
-    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(int field0)? attempts,
+    TResult? Function(int field0)? timeoutSecs,
   }) {
-    return iPv4?.call(addr, port);
+    return attempts?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String addr, int port)? iPv4,
-    TResult Function(String addr, int port)? iPv6,
+    TResult Function(int field0)? attempts,
+    TResult Function(int field0)? timeoutSecs,
     required TResult orElse(),
   }) {
-    if (iPv4 != null) {
-      return iPv4(addr, port);
+    if (attempts != null) {
+      return attempts(field0);
     }
     return orElse();
   }
@@ -2195,73 +3455,66 @@ class _$NetAddress_IPv4 implements NetAddress_IPv4 {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NetAddress_IPv4 value) iPv4,
-    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(RetryStrategy_Attempts value) attempts,
+    required TResult Function(RetryStrategy_TimeoutSecs value) timeoutSecs,
   }) {
-    return iPv4(this);
+    return attempts(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NetAddress_IPv4 value)? iPv4,
-    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(RetryStrategy_Attempts value)? attempts,
+    TResult? Function(RetryStrategy_TimeoutSecs value)? timeoutSecs,
   }) {
-    return iPv4?.call(this);
+    return attempts?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NetAddress_IPv4 value)? iPv4,
-    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(RetryStrategy_Attempts value)? attempts,
+    TResult Function(RetryStrategy_TimeoutSecs value)? timeoutSecs,
     required TResult orElse(),
   }) {
-    if (iPv4 != null) {
-      return iPv4(this);
+    if (attempts != null) {
+      return attempts(this);
     }
     return orElse();
   }
 }
 
-abstract class NetAddress_IPv4 implements NetAddress {
-  const factory NetAddress_IPv4({required final String addr, required final int port}) = _$NetAddress_IPv4;
+abstract class RetryStrategy_Attempts implements RetryStrategy {
+  const factory RetryStrategy_Attempts(final int field0) = _$RetryStrategy_Attempts;
 
   @override
-  String get addr;
-  @override
-  int get port;
+  int get field0;
   @override
   @JsonKey(ignore: true)
-  _$$NetAddress_IPv4CopyWith<_$NetAddress_IPv4> get copyWith => throw _privateConstructorUsedError;
+  _$$RetryStrategy_AttemptsCopyWith<_$RetryStrategy_Attempts> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$NetAddress_IPv6CopyWith<$Res> implements $NetAddressCopyWith<$Res> {
-  factory _$$NetAddress_IPv6CopyWith(_$NetAddress_IPv6 value, $Res Function(_$NetAddress_IPv6) then) = __$$NetAddress_IPv6CopyWithImpl<$Res>;
+abstract class _$$RetryStrategy_TimeoutSecsCopyWith<$Res> implements $RetryStrategyCopyWith<$Res> {
+  factory _$$RetryStrategy_TimeoutSecsCopyWith(_$RetryStrategy_TimeoutSecs value, $Res Function(_$RetryStrategy_TimeoutSecs) then) = __$$RetryStrategy_TimeoutSecsCopyWithImpl<$Res>;
   @override
   @useResult
-  $Res call({String addr, int port});
+  $Res call({int field0});
 }
 
 /// @nodoc
-class __$$NetAddress_IPv6CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Res, _$NetAddress_IPv6> implements _$$NetAddress_IPv6CopyWith<$Res> {
-  __$$NetAddress_IPv6CopyWithImpl(_$NetAddress_IPv6 _value, $Res Function(_$NetAddress_IPv6) _then) : super(_value, _then);
+class __$$RetryStrategy_TimeoutSecsCopyWithImpl<$Res> extends _$RetryStrategyCopyWithImpl<$Res, _$RetryStrategy_TimeoutSecs> implements _$$RetryStrategy_TimeoutSecsCopyWith<$Res> {
+  __$$RetryStrategy_TimeoutSecsCopyWithImpl(_$RetryStrategy_TimeoutSecs _value, $Res Function(_$RetryStrategy_TimeoutSecs) _then) : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? addr = null,
-    Object? port = null,
+    Object? field0 = null,
   }) {
-    return _then(_$NetAddress_IPv6(
-      addr: null == addr
-          ? _value.addr
-          : addr // ignore: cast_nullable_to_non_nullable
-              as String,
-      port: null == port
-          ? _value.port
-          : port // ignore: cast_nullable_to_non_nullable
+    return _then(_$RetryStrategy_TimeoutSecs(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
               as int,
     ));
   }
@@ -2269,60 +3522,57 @@ class __$$NetAddress_IPv6CopyWithImpl<$Res> extends _$NetAddressCopyWithImpl<$Re
 
 /// @nodoc
 
-class _$NetAddress_IPv6 implements NetAddress_IPv6 {
-  const _$NetAddress_IPv6({required this.addr, required this.port});
+class _$RetryStrategy_TimeoutSecs implements RetryStrategy_TimeoutSecs {
+  const _$RetryStrategy_TimeoutSecs(this.field0);
 
   @override
-  final String addr;
-  @override
-  final int port;
+  final int field0;
 
   @override
   String toString() {
-    return 'NetAddress.iPv6(addr: $addr, port: $port)';
+    return 'RetryStrategy.timeoutSecs(field0: $field0)';
   }
 
   @override
   bool operator ==(dynamic other) {
-    return identical(this, other) ||
-        (other.runtimeType == runtimeType && other is _$NetAddress_IPv6 && (identical(other.addr, addr) || other.addr == addr) && (identical(other.port, port) || other.port == port));
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$RetryStrategy_TimeoutSecs && (identical(other.field0, field0) || other.field0 == field0));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, addr, port);
+  int get hashCode => Object.hash(runtimeType, field0);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$NetAddress_IPv6CopyWith<_$NetAddress_IPv6> get copyWith => __$$NetAddress_IPv6CopyWithImpl<_$NetAddress_IPv6>(this, _$identity);
+  _$$RetryStrategy_TimeoutSecsCopyWith<_$RetryStrategy_TimeoutSecs> get copyWith => __$$RetryStrategy_TimeoutSecsCopyWithImpl<_$RetryStrategy_TimeoutSecs>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String addr, int port) iPv4,
-    required TResult Function(String addr, int port) iPv6,
+    required TResult Function(int field0) attempts,
+    required TResult Function(int field0) timeoutSecs,
   }) {
-    return iPv6(addr, port);
+    return timeoutSecs(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String addr, int port)? iPv4,
-    TResult? Function(String addr, int port)? iPv6,
+    TResult? Function(int field0)? attempts,
+    TResult? Function(int field0)? timeoutSecs,
   }) {
-    return iPv6?.call(addr, port);
+    return timeoutSecs?.call(field0);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String addr, int port)? iPv4,
-    TResult Function(String addr, int port)? iPv6,
+    TResult Function(int field0)? attempts,
+    TResult Function(int field0)? timeoutSecs,
     required TResult orElse(),
   }) {
-    if (iPv6 != null) {
-      return iPv6(addr, port);
+    if (timeoutSecs != null) {
+      return timeoutSecs(field0);
     }
     return orElse();
   }
@@ -2330,43 +3580,41 @@ class _$NetAddress_IPv6 implements NetAddress_IPv6 {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NetAddress_IPv4 value) iPv4,
-    required TResult Function(NetAddress_IPv6 value) iPv6,
+    required TResult Function(RetryStrategy_Attempts value) attempts,
+    required TResult Function(RetryStrategy_TimeoutSecs value) timeoutSecs,
   }) {
-    return iPv6(this);
+    return timeoutSecs(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NetAddress_IPv4 value)? iPv4,
-    TResult? Function(NetAddress_IPv6 value)? iPv6,
+    TResult? Function(RetryStrategy_Attempts value)? attempts,
+    TResult? Function(RetryStrategy_TimeoutSecs value)? timeoutSecs,
   }) {
-    return iPv6?.call(this);
+    return timeoutSecs?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NetAddress_IPv4 value)? iPv4,
-    TResult Function(NetAddress_IPv6 value)? iPv6,
+    TResult Function(RetryStrategy_Attempts value)? attempts,
+    TResult Function(RetryStrategy_TimeoutSecs value)? timeoutSecs,
     required TResult orElse(),
   }) {
-    if (iPv6 != null) {
-      return iPv6(this);
+    if (timeoutSecs != null) {
+      return timeoutSecs(this);
     }
     return orElse();
   }
 }
 
-abstract class NetAddress_IPv6 implements NetAddress {
-  const factory NetAddress_IPv6({required final String addr, required final int port}) = _$NetAddress_IPv6;
+abstract class RetryStrategy_TimeoutSecs implements RetryStrategy {
+  const factory RetryStrategy_TimeoutSecs(final int field0) = _$RetryStrategy_TimeoutSecs;
 
   @override
-  String get addr;
-  @override
-  int get port;
+  int get field0;
   @override
   @JsonKey(ignore: true)
-  _$$NetAddress_IPv6CopyWith<_$NetAddress_IPv6> get copyWith => throw _privateConstructorUsedError;
+  _$$RetryStrategy_TimeoutSecsCopyWith<_$RetryStrategy_TimeoutSecs> get copyWith => throw _privateConstructorUsedError;
 }
@@ -12,9 +12,11 @@ Future<Mnemonic> generateEntropyMnemonic() async {
 ///Needs to be initialized and instantiated through builder.build().
 ///
 class Node extends NodePointer {
-  Node._({required super.bridge, required super.field0});
+  Node._(
+      {required super.bridge, required super.field0, required super.field1});
   factory Node.create({required NodePointer pointer}) {
-    return Node._(bridge: pointer.bridge, field0: pointer.field0);
+    return Node._(
+        bridge: pointer.bridge, field0: pointer.field0, field1: pointer.field1);
   }
 }
 
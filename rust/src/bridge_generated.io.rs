@@ -6,6 +6,11 @@ pub extern "C" fn wire_generate_entropy_mnemonic(port_: i64) {
     wire_generate_entropy_mnemonic_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_default_channel_config(port_: i64) {
+    wire_default_channel_config_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_build_node(
     port_: i64,
@@ -112,6 +117,14 @@ pub extern "C" fn wire_list_channels__method__NodePointer(port_: i64, that: *mut
     wire_list_channels__method__NodePointer_impl(port_, that)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_list_closed_channels__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+) {
+    wire_list_closed_channels__method__NodePointer_impl(port_, that)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_connect__method__NodePointer(
     port_: i64,
@@ -170,30 +183,58 @@ pub extern "C" fn wire_close_channel__method__NodePointer(
     wire_close_channel__method__NodePointer_impl(port_, that, channel_id, counterparty_node_id)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_channel_config__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+    channel_id: *mut wire_ChannelId,
+) {
+    wire_channel_config__method__NodePointer_impl(port_, that, channel_id)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_update_channel_config__method__NodePointer(
     port_: i64,
     that: *mut wire_NodePointer,
     channel_id: *mut wire_ChannelId,
     counterparty_node_id: *mut wire_PublicKey,
-    channel_config: *mut wire_ChannelConfig,
+    update: *mut wire_ChannelConfigUpdate,
 ) {
     wire_update_channel_config__method__NodePointer_impl(
         port_,
         that,
         channel_id,
         counterparty_node_id,
-        channel_config,
+        update,
     )
 }
 
+#[no_mangle]
+pub extern "C" fn wire_update_all_channel_configs__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+    update: *mut wire_ChannelConfigUpdate,
+    filter: *mut wire_ChannelFilter,
+) {
+    wire_update_all_channel_configs__method__NodePointer_impl(port_, that, update, filter)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_fee_autopilot_proposals__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+) {
+    wire_fee_autopilot_proposals__method__NodePointer_impl(port_, that)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_send_payment__method__NodePointer(
     port_: i64,
     that: *mut wire_NodePointer,
     invoice: *mut wire_Invoice,
+    retry_strategy: *mut wire_RetryStrategy,
 ) {
-    wire_send_payment__method__NodePointer_impl(port_, that, invoice)
+    wire_send_payment__method__NodePointer_impl(port_, that, invoice, retry_strategy)
 }
 
 #[no_mangle]
@@ -202,8 +243,15 @@ pub extern "C" fn wire_send_payment_using_amount__method__NodePointer(
     that: *mut wire_NodePointer,
     invoice: *mut wire_Invoice,
     amount_msat: u64,
+    retry_strategy: *mut wire_RetryStrategy,
 ) {
-    wire_send_payment_using_amount__method__NodePointer_impl(port_, that, invoice, amount_msat)
+    wire_send_payment_using_amount__method__NodePointer_impl(
+        port_,
+        that,
+        invoice,
+        amount_msat,
+        retry_strategy,
+    )
 }
 
 #[no_mangle]
@@ -212,8 +260,15 @@ pub extern "C" fn wire_send_spontaneous_payment__method__NodePointer(
     that: *mut wire_NodePointer,
     amount_msat: u64,
     node_id: *mut wire_PublicKey,
+    retry_strategy: *mut wire_RetryStrategy,
 ) {
-    wire_send_spontaneous_payment__method__NodePointer_impl(port_, that, amount_msat, node_id)
+    wire_send_spontaneous_payment__method__NodePointer_impl(
+        port_,
+        that,
+        amount_msat,
+        node_id,
+        retry_strategy,
+    )
 }
 
 #[no_mangle]
@@ -266,6 +321,25 @@ pub extern "C" fn wire_remove_payment__method__NodePointer(
     wire_remove_payment__method__NodePointer_impl(port_, that, payment_hash)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_abandon_payment__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+    payment_hash: *mut wire_PaymentHash,
+) {
+    wire_abandon_payment__method__NodePointer_impl(port_, that, payment_hash)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_payment_label__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+    payment_hash: *mut wire_PaymentHash,
+    label: *mut wire_uint_8_list,
+) {
+    wire_set_payment_label__method__NodePointer_impl(port_, that, payment_hash, label)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_payments_with_filter__method__NodePointer(
     port_: i64,
@@ -275,11 +349,31 @@ pub extern "C" fn wire_list_payments_with_filter__method__NodePointer(
     wire_list_payments_with_filter__method__NodePointer_impl(port_, that, payment_direction)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_query_payments__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+    query: *mut wire_PaymentQuery,
+) {
+    wire_query_payments__method__NodePointer_impl(port_, that, query)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_payments__method__NodePointer(port_: i64, that: *mut wire_NodePointer) {
     wire_list_payments__method__NodePointer_impl(port_, that)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_export_payments__method__NodePointer(
+    port_: i64,
+    that: *mut wire_NodePointer,
+    format: i32,
+    created_from: *mut u64,
+    created_until: *mut u64,
+) {
+    wire_export_payments__method__NodePointer_impl(port_, that, format, created_from, created_until)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_peers__method__NodePointer(port_: i64, that: *mut wire_NodePointer) {
     wire_list_peers__method__NodePointer_impl(port_, that)
@@ -312,6 +406,11 @@ pub extern "C" fn new_MutexNodeSqliteStore() -> wire_MutexNodeSqliteStore {
     wire_MutexNodeSqliteStore::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_NodeStore() -> wire_NodeStore {
+    wire_NodeStore::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_address_0() -> *mut wire_Address {
     support::new_leak_box_ptr(wire_Address::new_with_null_ptr())
@@ -327,11 +426,26 @@ pub extern "C" fn new_box_autoadd_channel_config_0() -> *mut wire_ChannelConfig
     support::new_leak_box_ptr(wire_ChannelConfig::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_channel_config_update_0() -> *mut wire_ChannelConfigUpdate {
+    support::new_leak_box_ptr(wire_ChannelConfigUpdate::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_channel_filter_0() -> *mut wire_ChannelFilter {
+    support::new_leak_box_ptr(wire_ChannelFilter::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_channel_id_0() -> *mut wire_ChannelId {
     support::new_leak_box_ptr(wire_ChannelId::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_channel_visibility_0(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_config_0() -> *mut wire_Config {
     support::new_leak_box_ptr(wire_Config::new_with_null_ptr())
@@ -342,11 +456,21 @@ pub extern "C" fn new_box_autoadd_entropy_source_config_0() -> *mut wire_Entropy
     support::new_leak_box_ptr(wire_EntropySourceConfig::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_fee_autopilot_config_0() -> *mut wire_FeeAutopilotConfig {
+    support::new_leak_box_ptr(wire_FeeAutopilotConfig::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_gossip_source_config_0() -> *mut wire_GossipSourceConfig {
     support::new_leak_box_ptr(wire_GossipSourceConfig::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_inbound_channel_policy_0() -> *mut wire_InboundChannelPolicy {
+    support::new_leak_box_ptr(wire_InboundChannelPolicy::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_invoice_0() -> *mut wire_Invoice {
     support::new_leak_box_ptr(wire_Invoice::new_with_null_ptr())
@@ -367,21 +491,60 @@ pub extern "C" fn new_box_autoadd_node_pointer_0() -> *mut wire_NodePointer {
     support::new_leak_box_ptr(wire_NodePointer::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_payment_direction_0(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_payment_hash_0() -> *mut wire_PaymentHash {
     support::new_leak_box_ptr(wire_PaymentHash::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_payment_kind_0(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_payment_query_0() -> *mut wire_PaymentQuery {
+    support::new_leak_box_ptr(wire_PaymentQuery::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_public_key_0() -> *mut wire_PublicKey {
     support::new_leak_box_ptr(wire_PublicKey::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_retry_strategy_0() -> *mut wire_RetryStrategy {
+    support::new_leak_box_ptr(wire_RetryStrategy::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_u16_0(value: u16) -> *mut u16 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_u32_0(value: u32) -> *mut u32 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_u64_0(value: u64) -> *mut u64 {
     support::new_leak_box_ptr(value)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_payment_status_0(len: i32) -> *mut wire_list_payment_status {
+    let wrap = wire_list_payment_status {
+        ptr: support::new_leak_vec_ptr(Default::default(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_public_key_0(len: i32) -> *mut wire_list_public_key {
     let wrap = wire_list_public_key {
@@ -417,6 +580,21 @@ pub extern "C" fn share_opaque_MutexNodeSqliteStore(ptr: *const c_void) -> *cons
     }
 }
 
+#[no_mangle]
+pub extern "C" fn drop_opaque_NodeStore(ptr: *const c_void) {
+    unsafe {
+        Arc::<NodeStore>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_NodeStore(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<NodeStore>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 // Section: impl Wire2Api
 
 impl Wire2Api<RustOpaque<Mutex<Node<SqliteStore>>>> for wire_MutexNodeSqliteStore {
@@ -424,6 +602,11 @@ impl Wire2Api<RustOpaque<Mutex<Node<SqliteStore>>>> for wire_MutexNodeSqliteStor
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
+impl Wire2Api<RustOpaque<NodeStore>> for wire_NodeStore {
+    fn wire2api(self) -> RustOpaque<NodeStore> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
 impl Wire2Api<String> for *mut wire_uint_8_list {
     fn wire2api(self) -> String {
         let vec: Vec<u8> = self.wire2api();
@@ -456,12 +639,30 @@ impl Wire2Api<ChannelConfig> for *mut wire_ChannelConfig {
         Wire2Api::<ChannelConfig>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<ChannelConfigUpdate> for *mut wire_ChannelConfigUpdate {
+    fn wire2api(self) -> ChannelConfigUpdate {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ChannelConfigUpdate>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ChannelFilter> for *mut wire_ChannelFilter {
+    fn wire2api(self) -> ChannelFilter {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ChannelFilter>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<ChannelId> for *mut wire_ChannelId {
     fn wire2api(self) -> ChannelId {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<ChannelId>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<ChannelVisibility> for *mut i32 {
+    fn wire2api(self) -> ChannelVisibility {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ChannelVisibility>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<Config> for *mut wire_Config {
     fn wire2api(self) -> Config {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -474,12 +675,24 @@ impl Wire2Api<EntropySourceConfig> for *mut wire_EntropySourceConfig {
         Wire2Api::<EntropySourceConfig>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<FeeAutopilotConfig> for *mut wire_FeeAutopilotConfig {
+    fn wire2api(self) -> FeeAutopilotConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<FeeAutopilotConfig>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<GossipSourceConfig> for *mut wire_GossipSourceConfig {
     fn wire2api(self) -> GossipSourceConfig {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<GossipSourceConfig>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<InboundChannelPolicy> for *mut wire_InboundChannelPolicy {
+    fn wire2api(self) -> InboundChannelPolicy {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<InboundChannelPolicy>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<Invoice> for *mut wire_Invoice {
     fn wire2api(self) -> Invoice {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -504,18 +717,52 @@ impl Wire2Api<NodePointer> for *mut wire_NodePointer {
         Wire2Api::<NodePointer>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<PaymentDirection> for *mut i32 {
+    fn wire2api(self) -> PaymentDirection {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PaymentDirection>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<PaymentHash> for *mut wire_PaymentHash {
     fn wire2api(self) -> PaymentHash {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<PaymentHash>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<PaymentKind> for *mut i32 {
+    fn wire2api(self) -> PaymentKind {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PaymentKind>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<PaymentQuery> for *mut wire_PaymentQuery {
+    fn wire2api(self) -> PaymentQuery {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PaymentQuery>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<PublicKey> for *mut wire_PublicKey {
     fn wire2api(self) -> PublicKey {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<PublicKey>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<RetryStrategy> for *mut wire_RetryStrategy {
+    fn wire2api(self) -> RetryStrategy {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<RetryStrategy>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<u16> for *mut u16 {
+    fn wire2api(self) -> u16 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
+impl Wire2Api<u32> for *mut u32 {
+    fn wire2api(self) -> u32 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
 impl Wire2Api<u64> for *mut u64 {
     fn wire2api(self) -> u64 {
         unsafe { *support::box_from_leak_ptr(self) }
@@ -548,6 +795,39 @@ impl Wire2Api<ChannelConfig> for wire_ChannelConfig {
         }
     }
 }
+impl Wire2Api<ChannelConfigUpdate> for wire_ChannelConfigUpdate {
+    fn wire2api(self) -> ChannelConfigUpdate {
+        ChannelConfigUpdate {
+            forwarding_fee_proportional_millionths: self
+                .forwarding_fee_proportional_millionths
+                .wire2api(),
+            forwarding_fee_base_msat: self.forwarding_fee_base_msat.wire2api(),
+            cltv_expiry_delta: self.cltv_expiry_delta.wire2api(),
+            max_dust_htlc_exposure_msat: self.max_dust_htlc_exposure_msat.wire2api(),
+            force_close_avoidance_max_fee_satoshis: self
+                .force_close_avoidance_max_fee_satoshis
+                .wire2api(),
+        }
+    }
+}
+impl Wire2Api<ChannelFilter> for wire_ChannelFilter {
+    fn wire2api(self) -> ChannelFilter {
+        match self.tag {
+            0 => ChannelFilter::All,
+            1 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Counterparty);
+                ChannelFilter::Counterparty(ans.field0.wire2api())
+            },
+            2 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Visibility);
+                ChannelFilter::Visibility(ans.field0.wire2api())
+            },
+            _ => unreachable!(),
+        }
+    }
+}
 impl Wire2Api<ChannelId> for wire_ChannelId {
     fn wire2api(self) -> ChannelId {
         ChannelId {
@@ -555,6 +835,7 @@ impl Wire2Api<ChannelId> for wire_ChannelId {
         }
     }
 }
+
 impl Wire2Api<Config> for wire_Config {
     fn wire2api(self) -> Config {
         Config {
@@ -569,6 +850,8 @@ impl Wire2Api<Config> for wire_Config {
             log_level: self.log_level.wire2api(),
             listening_address: self.listening_address.wire2api(),
             default_cltv_expiry_delta: self.default_cltv_expiry_delta.wire2api(),
+            inbound_channel_policy: self.inbound_channel_policy.wire2api(),
+            fee_autopilot: self.fee_autopilot.wire2api(),
         }
     }
 }
@@ -597,6 +880,20 @@ impl Wire2Api<EntropySourceConfig> for wire_EntropySourceConfig {
         }
     }
 }
+
+impl Wire2Api<FeeAutopilotConfig> for wire_FeeAutopilotConfig {
+    fn wire2api(self) -> FeeAutopilotConfig {
+        FeeAutopilotConfig {
+            min_base_fee_msat: self.min_base_fee_msat.wire2api(),
+            max_base_fee_msat: self.max_base_fee_msat.wire2api(),
+            min_proportional_millionths: self.min_proportional_millionths.wire2api(),
+            max_proportional_millionths: self.max_proportional_millionths.wire2api(),
+            target_outbound_percent: self.target_outbound_percent.wire2api(),
+            interval_secs: self.interval_secs.wire2api(),
+            dry_run: self.dry_run.wire2api(),
+        }
+    }
+}
 impl Wire2Api<GossipSourceConfig> for wire_GossipSourceConfig {
     fn wire2api(self) -> GossipSourceConfig {
         match self.tag {
@@ -611,6 +908,16 @@ impl Wire2Api<GossipSourceConfig> for wire_GossipSourceConfig {
     }
 }
 
+impl Wire2Api<InboundChannelPolicy> for wire_InboundChannelPolicy {
+    fn wire2api(self) -> InboundChannelPolicy {
+        InboundChannelPolicy {
+            allowed_peers: self.allowed_peers.wire2api(),
+            min_channel_size_sats: self.min_channel_size_sats.wire2api(),
+            required_visibility: self.required_visibility.wire2api(),
+            max_pending_channels: self.max_pending_channels.wire2api(),
+        }
+    }
+}
 impl Wire2Api<Invoice> for wire_Invoice {
     fn wire2api(self) -> Invoice {
         Invoice {
@@ -618,6 +925,15 @@ impl Wire2Api<Invoice> for wire_Invoice {
         }
     }
 }
+impl Wire2Api<Vec<PaymentStatus>> for *mut wire_list_payment_status {
+    fn wire2api(self) -> Vec<PaymentStatus> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
 impl Wire2Api<Vec<PublicKey>> for *mut wire_list_public_key {
     fn wire2api(self) -> Vec<PublicKey> {
         let vec = unsafe {
@@ -654,6 +970,31 @@ impl Wire2Api<NetAddress> for wire_NetAddress {
                     port: ans.port.wire2api(),
                 }
             },
+            2 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.OnionV2);
+                NetAddress::OnionV2 {
+                    addr: ans.addr.wire2api(),
+                }
+            },
+            3 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.OnionV3);
+                NetAddress::OnionV3 {
+                    pubkey: ans.pubkey.wire2api(),
+                    checksum: ans.checksum.wire2api(),
+                    version: ans.version.wire2api(),
+                    port: ans.port.wire2api(),
+                }
+            },
+            4 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Hostname);
+                NetAddress::Hostname {
+                    host: ans.host.wire2api(),
+                    port: ans.port.wire2api(),
+                }
+            },
             _ => unreachable!(),
         }
     }
@@ -661,7 +1002,7 @@ impl Wire2Api<NetAddress> for wire_NetAddress {
 
 impl Wire2Api<NodePointer> for wire_NodePointer {
     fn wire2api(self) -> NodePointer {
-        NodePointer(self.field0.wire2api())
+        NodePointer(self.field0.wire2api(), self.field1.wire2api())
     }
 }
 
@@ -672,6 +1013,25 @@ impl Wire2Api<PaymentHash> for wire_PaymentHash {
         }
     }
 }
+
+impl Wire2Api<PaymentQuery> for wire_PaymentQuery {
+    fn wire2api(self) -> PaymentQuery {
+        PaymentQuery {
+            direction: self.direction.wire2api(),
+            statuses: self.statuses.wire2api(),
+            created_from: self.created_from.wire2api(),
+            created_until: self.created_until.wire2api(),
+            min_amount_msat: self.min_amount_msat.wire2api(),
+            max_amount_msat: self.max_amount_msat.wire2api(),
+            label_contains: self.label_contains.wire2api(),
+            kind: self.kind.wire2api(),
+            sort_order: self.sort_order.wire2api(),
+            cursor: self.cursor.wire2api(),
+            limit: self.limit.wire2api(),
+        }
+    }
+}
+
 impl Wire2Api<PublicKey> for wire_PublicKey {
     fn wire2api(self) -> PublicKey {
         PublicKey {
@@ -679,7 +1039,30 @@ impl Wire2Api<PublicKey> for wire_PublicKey {
         }
     }
 }
+impl Wire2Api<RetryStrategy> for wire_RetryStrategy {
+    fn wire2api(self) -> RetryStrategy {
+        match self.tag {
+            0 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Attempts);
+                RetryStrategy::Attempts(ans.field0.wire2api())
+            },
+            1 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.TimeoutSecs);
+                RetryStrategy::TimeoutSecs(ans.field0.wire2api())
+            },
+            _ => unreachable!(),
+        }
+    }
+}
 
+impl Wire2Api<[u8; 12]> for *mut wire_uint_8_list {
+    fn wire2api(self) -> [u8; 12] {
+        let vec: Vec<u8> = self.wire2api();
+        support::from_vec_to_array(vec)
+    }
+}
 impl Wire2Api<[u8; 32]> for *mut wire_uint_8_list {
     fn wire2api(self) -> [u8; 32] {
         let vec: Vec<u8> = self.wire2api();
@@ -708,6 +1091,12 @@ pub struct wire_MutexNodeSqliteStore {
     ptr: *const core::ffi::c_void,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NodeStore {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_Address {
@@ -724,6 +1113,16 @@ pub struct wire_ChannelConfig {
     force_close_avoidance_max_fee_satoshis: u64,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChannelConfigUpdate {
+    forwarding_fee_proportional_millionths: *mut u32,
+    forwarding_fee_base_msat: *mut u32,
+    cltv_expiry_delta: *mut u16,
+    max_dust_htlc_exposure_msat: *mut u64,
+    force_close_avoidance_max_fee_satoshis: *mut u64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ChannelId {
@@ -742,6 +1141,29 @@ pub struct wire_Config {
     log_level: i32,
     listening_address: *mut wire_NetAddress,
     default_cltv_expiry_delta: u32,
+    inbound_channel_policy: *mut wire_InboundChannelPolicy,
+    fee_autopilot: *mut wire_FeeAutopilotConfig,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_FeeAutopilotConfig {
+    min_base_fee_msat: u32,
+    max_base_fee_msat: u32,
+    min_proportional_millionths: u32,
+    max_proportional_millionths: u32,
+    target_outbound_percent: u8,
+    interval_secs: u64,
+    dry_run: bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_InboundChannelPolicy {
+    allowed_peers: *mut wire_list_public_key,
+    min_channel_size_sats: *mut u64,
+    required_visibility: *mut i32,
+    max_pending_channels: *mut u32,
 }
 
 #[repr(C)]
@@ -750,6 +1172,13 @@ pub struct wire_Invoice {
     internal: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_payment_status {
+    ptr: *mut i32,
+    len: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_list_public_key {
@@ -767,6 +1196,7 @@ pub struct wire_Mnemonic {
 #[derive(Clone)]
 pub struct wire_NodePointer {
     field0: wire_MutexNodeSqliteStore,
+    field1: wire_NodeStore,
 }
 
 #[repr(C)]
@@ -775,6 +1205,22 @@ pub struct wire_PaymentHash {
     internal: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PaymentQuery {
+    direction: *mut i32,
+    statuses: *mut wire_list_payment_status,
+    created_from: *mut u64,
+    created_until: *mut u64,
+    min_amount_msat: *mut u64,
+    max_amount_msat: *mut u64,
+    label_contains: *mut wire_uint_8_list,
+    kind: *mut i32,
+    sort_order: i32,
+    cursor: *mut wire_uint_8_list,
+    limit: *mut u32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_PublicKey {
@@ -806,6 +1252,36 @@ pub struct wire_ChainDataSourceConfig_Esplora {
     field0: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChannelFilter {
+    tag: i32,
+    kind: *mut ChannelFilterKind,
+}
+
+#[repr(C)]
+pub union ChannelFilterKind {
+    All: *mut wire_ChannelFilter_All,
+    Counterparty: *mut wire_ChannelFilter_Counterparty,
+    Visibility: *mut wire_ChannelFilter_Visibility,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChannelFilter_All {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChannelFilter_Counterparty {
+    field0: *mut wire_PublicKey,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChannelFilter_Visibility {
+    field0: i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_EntropySourceConfig {
@@ -838,6 +1314,7 @@ pub struct wire_EntropySourceConfig_Bip39Mnemonic {
     mnemonic: *mut wire_Mnemonic,
     passphrase: *mut wire_uint_8_list,
 }
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_GossipSourceConfig {
@@ -872,6 +1349,9 @@ pub struct wire_NetAddress {
 pub union NetAddressKind {
     IPv4: *mut wire_NetAddress_IPv4,
     IPv6: *mut wire_NetAddress_IPv6,
+    OnionV2: *mut wire_NetAddress_OnionV2,
+    OnionV3: *mut wire_NetAddress_OnionV3,
+    Hostname: *mut wire_NetAddress_Hostname,
 }
 
 #[repr(C)]
@@ -888,6 +1368,53 @@ pub struct wire_NetAddress_IPv6 {
     port: u16,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NetAddress_OnionV2 {
+    addr: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NetAddress_OnionV3 {
+    pubkey: *mut wire_uint_8_list,
+    checksum: u16,
+    version: u8,
+    port: u16,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NetAddress_Hostname {
+    host: *mut wire_uint_8_list,
+    port: u16,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RetryStrategy {
+    tag: i32,
+    kind: *mut RetryStrategyKind,
+}
+
+#[repr(C)]
+pub union RetryStrategyKind {
+    Attempts: *mut wire_RetryStrategy_Attempts,
+    TimeoutSecs: *mut wire_RetryStrategy_TimeoutSecs,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RetryStrategy_Attempts {
+    field0: u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RetryStrategy_TimeoutSecs {
+    field0: u64,
+}
+
 // Section: impl NewWithNullPtr
 
 pub trait NewWithNullPtr {
@@ -908,6 +1435,14 @@ impl NewWithNullPtr for wire_MutexNodeSqliteStore {
     }
 }
 
+impl NewWithNullPtr for wire_NodeStore {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
+
 impl NewWithNullPtr for wire_Address {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -964,6 +1499,57 @@ impl Default for wire_ChannelConfig {
     }
 }
 
+impl NewWithNullPtr for wire_ChannelConfigUpdate {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            forwarding_fee_proportional_millionths: core::ptr::null_mut(),
+            forwarding_fee_base_msat: core::ptr::null_mut(),
+            cltv_expiry_delta: core::ptr::null_mut(),
+            max_dust_htlc_exposure_msat: core::ptr::null_mut(),
+            force_close_avoidance_max_fee_satoshis: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ChannelConfigUpdate {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl Default for wire_ChannelFilter {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ChannelFilter {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_ChannelFilter_Counterparty() -> *mut ChannelFilterKind {
+    support::new_leak_box_ptr(ChannelFilterKind {
+        Counterparty: support::new_leak_box_ptr(wire_ChannelFilter_Counterparty {
+            field0: core::ptr::null_mut(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_ChannelFilter_Visibility() -> *mut ChannelFilterKind {
+    support::new_leak_box_ptr(ChannelFilterKind {
+        Visibility: support::new_leak_box_ptr(wire_ChannelFilter_Visibility {
+            field0: Default::default(),
+        }),
+    })
+}
+
 impl NewWithNullPtr for wire_ChannelId {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -990,6 +1576,8 @@ impl NewWithNullPtr for wire_Config {
             log_level: Default::default(),
             listening_address: core::ptr::null_mut(),
             default_cltv_expiry_delta: Default::default(),
+            inbound_channel_policy: core::ptr::null_mut(),
+            fee_autopilot: core::ptr::null_mut(),
         }
     }
 }
@@ -1043,6 +1631,26 @@ pub extern "C" fn inflate_EntropySourceConfig_Bip39Mnemonic() -> *mut EntropySou
     })
 }
 
+impl NewWithNullPtr for wire_FeeAutopilotConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            min_base_fee_msat: Default::default(),
+            max_base_fee_msat: Default::default(),
+            min_proportional_millionths: Default::default(),
+            max_proportional_millionths: Default::default(),
+            target_outbound_percent: Default::default(),
+            interval_secs: Default::default(),
+            dry_run: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_FeeAutopilotConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl Default for wire_GossipSourceConfig {
     fn default() -> Self {
         Self::new_with_null_ptr()
@@ -1067,6 +1675,23 @@ pub extern "C" fn inflate_GossipSourceConfig_RapidGossipSync() -> *mut GossipSou
     })
 }
 
+impl NewWithNullPtr for wire_InboundChannelPolicy {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            allowed_peers: core::ptr::null_mut(),
+            min_channel_size_sats: core::ptr::null_mut(),
+            required_visibility: core::ptr::null_mut(),
+            max_pending_channels: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_InboundChannelPolicy {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_Invoice {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1130,10 +1755,42 @@ pub extern "C" fn inflate_NetAddress_IPv6() -> *mut NetAddressKind {
     })
 }
 
+#[no_mangle]
+pub extern "C" fn inflate_NetAddress_OnionV2() -> *mut NetAddressKind {
+    support::new_leak_box_ptr(NetAddressKind {
+        OnionV2: support::new_leak_box_ptr(wire_NetAddress_OnionV2 {
+            addr: core::ptr::null_mut(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_NetAddress_OnionV3() -> *mut NetAddressKind {
+    support::new_leak_box_ptr(NetAddressKind {
+        OnionV3: support::new_leak_box_ptr(wire_NetAddress_OnionV3 {
+            pubkey: core::ptr::null_mut(),
+            checksum: Default::default(),
+            version: Default::default(),
+            port: Default::default(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_NetAddress_Hostname() -> *mut NetAddressKind {
+    support::new_leak_box_ptr(NetAddressKind {
+        Hostname: support::new_leak_box_ptr(wire_NetAddress_Hostname {
+            host: core::ptr::null_mut(),
+            port: Default::default(),
+        }),
+    })
+}
+
 impl NewWithNullPtr for wire_NodePointer {
     fn new_with_null_ptr() -> Self {
         Self {
             field0: wire_MutexNodeSqliteStore::new_with_null_ptr(),
+            field1: wire_NodeStore::new_with_null_ptr(),
         }
     }
 }
@@ -1158,6 +1815,30 @@ impl Default for wire_PaymentHash {
     }
 }
 
+impl NewWithNullPtr for wire_PaymentQuery {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            direction: core::ptr::null_mut(),
+            statuses: core::ptr::null_mut(),
+            created_from: core::ptr::null_mut(),
+            created_until: core::ptr::null_mut(),
+            min_amount_msat: core::ptr::null_mut(),
+            max_amount_msat: core::ptr::null_mut(),
+            label_contains: core::ptr::null_mut(),
+            kind: core::ptr::null_mut(),
+            sort_order: Default::default(),
+            cursor: core::ptr::null_mut(),
+            limit: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_PaymentQuery {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_PublicKey {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1172,6 +1853,39 @@ impl Default for wire_PublicKey {
     }
 }
 
+impl Default for wire_RetryStrategy {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_RetryStrategy {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_RetryStrategy_Attempts() -> *mut RetryStrategyKind {
+    support::new_leak_box_ptr(RetryStrategyKind {
+        Attempts: support::new_leak_box_ptr(wire_RetryStrategy_Attempts {
+            field0: Default::default(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_RetryStrategy_TimeoutSecs() -> *mut RetryStrategyKind {
+    support::new_leak_box_ptr(RetryStrategyKind {
+        TimeoutSecs: support::new_leak_box_ptr(wire_RetryStrategy_TimeoutSecs {
+            field0: Default::default(),
+        }),
+    })
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
@@ -21,13 +21,23 @@ use std::sync::Arc;
 
 use crate::types::Address;
 use crate::types::ChainDataSourceConfig;
+use crate::types::ChannelCloseType;
 use crate::types::ChannelConfig;
+use crate::types::ChannelConfigUpdate;
+use crate::types::ChannelConfigUpdateResult;
 use crate::types::ChannelDetails;
+use crate::types::ChannelFeeProposal;
+use crate::types::ChannelFilter;
 use crate::types::ChannelId;
+use crate::types::ChannelVisibility;
+use crate::types::ClosedChannelDetails;
 use crate::types::Config;
 use crate::types::EntropySourceConfig;
 use crate::types::Event;
+use crate::types::ExportFormat;
+use crate::types::FeeAutopilotConfig;
 use crate::types::GossipSourceConfig;
+use crate::types::InboundChannelPolicy;
 use crate::types::Invoice;
 use crate::types::LogLevel;
 use crate::types::Mnemonic;
@@ -37,11 +47,16 @@ use crate::types::OutPoint;
 use crate::types::PaymentDetails;
 use crate::types::PaymentDirection;
 use crate::types::PaymentHash;
+use crate::types::PaymentKind;
+use crate::types::PaymentPage;
 use crate::types::PaymentPreimage;
+use crate::types::PaymentQuery;
 use crate::types::PaymentSecret;
+use crate::types::PaymentSortOrder;
 use crate::types::PaymentStatus;
 use crate::types::PeerDetails;
 use crate::types::PublicKey;
+use crate::types::RetryStrategy;
 use crate::types::Txid;
 use crate::types::UserChannelId;
 
@@ -57,6 +72,16 @@ fn wire_generate_entropy_mnemonic_impl(port_: MessagePort) {
         move || move |task_callback| Ok(generate_entropy_mnemonic()),
     )
 }
+fn wire_default_channel_config_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "default_channel_config",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Ok(default_channel_config()),
+    )
+}
 fn wire_build_node_impl(
     port_: MessagePort,
     config: impl Wire2Api<Config> + UnwindSafe,
@@ -302,6 +327,22 @@ fn wire_list_channels__method__NodePointer_impl(
         },
     )
 }
+fn wire_list_closed_channels__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "list_closed_channels__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| Ok(NodePointer::list_closed_channels(&api_that))
+        },
+    )
+}
 fn wire_connect__method__NodePointer_impl(
     port_: MessagePort,
     that: impl Wire2Api<NodePointer> + UnwindSafe,
@@ -420,12 +461,30 @@ fn wire_close_channel__method__NodePointer_impl(
         },
     )
 }
+fn wire_channel_config__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+    channel_id: impl Wire2Api<ChannelId> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "channel_config__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_channel_id = channel_id.wire2api();
+            move |task_callback| NodePointer::channel_config(&api_that, api_channel_id)
+        },
+    )
+}
 fn wire_update_channel_config__method__NodePointer_impl(
     port_: MessagePort,
     that: impl Wire2Api<NodePointer> + UnwindSafe,
     channel_id: impl Wire2Api<ChannelId> + UnwindSafe,
     counterparty_node_id: impl Wire2Api<PublicKey> + UnwindSafe,
-    channel_config: impl Wire2Api<ChannelConfig> + UnwindSafe,
+    update: impl Wire2Api<ChannelConfigUpdate> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -437,22 +496,61 @@ fn wire_update_channel_config__method__NodePointer_impl(
             let api_that = that.wire2api();
             let api_channel_id = channel_id.wire2api();
             let api_counterparty_node_id = counterparty_node_id.wire2api();
-            let api_channel_config = channel_config.wire2api();
+            let api_update = update.wire2api();
             move |task_callback| {
                 NodePointer::update_channel_config(
                     &api_that,
                     api_channel_id,
                     api_counterparty_node_id,
-                    api_channel_config,
+                    api_update,
                 )
             }
         },
     )
 }
+fn wire_update_all_channel_configs__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+    update: impl Wire2Api<ChannelConfigUpdate> + UnwindSafe,
+    filter: impl Wire2Api<ChannelFilter> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "update_all_channel_configs__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_update = update.wire2api();
+            let api_filter = filter.wire2api();
+            move |task_callback| {
+                NodePointer::update_all_channel_configs(&api_that, api_update, api_filter)
+            }
+        },
+    )
+}
+fn wire_fee_autopilot_proposals__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "fee_autopilot_proposals__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| NodePointer::fee_autopilot_proposals(&api_that)
+        },
+    )
+}
 fn wire_send_payment__method__NodePointer_impl(
     port_: MessagePort,
     that: impl Wire2Api<NodePointer> + UnwindSafe,
     invoice: impl Wire2Api<Invoice> + UnwindSafe,
+    retry_strategy: impl Wire2Api<Option<RetryStrategy>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -463,7 +561,10 @@ fn wire_send_payment__method__NodePointer_impl(
         move || {
             let api_that = that.wire2api();
             let api_invoice = invoice.wire2api();
-            move |task_callback| NodePointer::send_payment(&api_that, api_invoice)
+            let api_retry_strategy = retry_strategy.wire2api();
+            move |task_callback| {
+                NodePointer::send_payment(&api_that, api_invoice, api_retry_strategy)
+            }
         },
     )
 }
@@ -472,6 +573,7 @@ fn wire_send_payment_using_amount__method__NodePointer_impl(
     that: impl Wire2Api<NodePointer> + UnwindSafe,
     invoice: impl Wire2Api<Invoice> + UnwindSafe,
     amount_msat: impl Wire2Api<u64> + UnwindSafe,
+    retry_strategy: impl Wire2Api<Option<RetryStrategy>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -483,8 +585,14 @@ fn wire_send_payment_using_amount__method__NodePointer_impl(
             let api_that = that.wire2api();
             let api_invoice = invoice.wire2api();
             let api_amount_msat = amount_msat.wire2api();
+            let api_retry_strategy = retry_strategy.wire2api();
             move |task_callback| {
-                NodePointer::send_payment_using_amount(&api_that, api_invoice, api_amount_msat)
+                NodePointer::send_payment_using_amount(
+                    &api_that,
+                    api_invoice,
+                    api_amount_msat,
+                    api_retry_strategy,
+                )
             }
         },
     )
@@ -494,6 +602,7 @@ fn wire_send_spontaneous_payment__method__NodePointer_impl(
     that: impl Wire2Api<NodePointer> + UnwindSafe,
     amount_msat: impl Wire2Api<u64> + UnwindSafe,
     node_id: impl Wire2Api<PublicKey> + UnwindSafe,
+    retry_strategy: impl Wire2Api<Option<RetryStrategy>> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap(
         WrapInfo {
@@ -505,8 +614,14 @@ fn wire_send_spontaneous_payment__method__NodePointer_impl(
             let api_that = that.wire2api();
             let api_amount_msat = amount_msat.wire2api();
             let api_node_id = node_id.wire2api();
+            let api_retry_strategy = retry_strategy.wire2api();
             move |task_callback| {
-                NodePointer::send_spontaneous_payment(&api_that, api_amount_msat, api_node_id)
+                NodePointer::send_spontaneous_payment(
+                    &api_that,
+                    api_amount_msat,
+                    api_node_id,
+                    api_retry_strategy,
+                )
             }
         },
     )
@@ -602,6 +717,46 @@ fn wire_remove_payment__method__NodePointer_impl(
         },
     )
 }
+fn wire_abandon_payment__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+    payment_hash: impl Wire2Api<PaymentHash> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "abandon_payment__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_payment_hash = payment_hash.wire2api();
+            move |task_callback| NodePointer::abandon_payment(&api_that, api_payment_hash)
+        },
+    )
+}
+fn wire_set_payment_label__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+    payment_hash: impl Wire2Api<PaymentHash> + UnwindSafe,
+    label: impl Wire2Api<Option<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "set_payment_label__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_payment_hash = payment_hash.wire2api();
+            let api_label = label.wire2api();
+            move |task_callback| {
+                NodePointer::set_payment_label(&api_that, api_payment_hash, api_label)
+            }
+        },
+    )
+}
 fn wire_list_payments_with_filter__method__NodePointer_impl(
     port_: MessagePort,
     that: impl Wire2Api<NodePointer> + UnwindSafe,
@@ -625,6 +780,24 @@ fn wire_list_payments_with_filter__method__NodePointer_impl(
         },
     )
 }
+fn wire_query_payments__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+    query: impl Wire2Api<PaymentQuery> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "query_payments__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_query = query.wire2api();
+            move |task_callback| NodePointer::query_payments(&api_that, api_query)
+        },
+    )
+}
 fn wire_list_payments__method__NodePointer_impl(
     port_: MessagePort,
     that: impl Wire2Api<NodePointer> + UnwindSafe,
@@ -641,6 +814,35 @@ fn wire_list_payments__method__NodePointer_impl(
         },
     )
 }
+fn wire_export_payments__method__NodePointer_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<NodePointer> + UnwindSafe,
+    format: impl Wire2Api<ExportFormat> + UnwindSafe,
+    created_from: impl Wire2Api<Option<u64>> + UnwindSafe,
+    created_until: impl Wire2Api<Option<u64>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap(
+        WrapInfo {
+            debug_name: "export_payments__method__NodePointer",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_format = format.wire2api();
+            let api_created_from = created_from.wire2api();
+            let api_created_until = created_until.wire2api();
+            move |task_callback| {
+                Ok(NodePointer::export_payments(
+                    &api_that,
+                    api_format,
+                    api_created_from,
+                    api_created_until,
+                ))
+            }
+        },
+    )
+}
 fn wire_list_peers__method__NodePointer_impl(
     port_: MessagePort,
     that: impl Wire2Api<NodePointer> + UnwindSafe,
@@ -728,6 +930,26 @@ impl Wire2Api<bool> for bool {
     }
 }
 
+impl Wire2Api<ChannelVisibility> for i32 {
+    fn wire2api(self) -> ChannelVisibility {
+        match self {
+            0 => ChannelVisibility::Public,
+            1 => ChannelVisibility::Private,
+            _ => unreachable!("Invalid variant for ChannelVisibility: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<ExportFormat> for i32 {
+    fn wire2api(self) -> ExportFormat {
+        match self {
+            0 => ExportFormat::Csv,
+            1 => ExportFormat::Json,
+            _ => unreachable!("Invalid variant for ExportFormat: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<i32> for i32 {
     fn wire2api(self) -> i32 {
         self
@@ -770,6 +992,37 @@ impl Wire2Api<PaymentDirection> for i32 {
     }
 }
 
+impl Wire2Api<PaymentKind> for i32 {
+    fn wire2api(self) -> PaymentKind {
+        match self {
+            0 => PaymentKind::Bolt11,
+            1 => PaymentKind::Spontaneous,
+            _ => unreachable!("Invalid variant for PaymentKind: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<PaymentSortOrder> for i32 {
+    fn wire2api(self) -> PaymentSortOrder {
+        match self {
+            0 => PaymentSortOrder::NewestFirst,
+            1 => PaymentSortOrder::OldestFirst,
+            _ => unreachable!("Invalid variant for PaymentSortOrder: {}", self),
+        }
+    }
+}
+impl Wire2Api<PaymentStatus> for i32 {
+    fn wire2api(self) -> PaymentStatus {
+        match self {
+            0 => PaymentStatus::Pending,
+            1 => PaymentStatus::Succeeded,
+            2 => PaymentStatus::Failed,
+            3 => PaymentStatus::Abandoning,
+            _ => unreachable!("Invalid variant for PaymentStatus: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<u16> for u16 {
     fn wire2api(self) -> u16 {
         self
@@ -800,6 +1053,42 @@ impl support::IntoDart for Address {
 }
 impl support::IntoDartExceptPrimitive for Address {}
 
+impl support::IntoDart for ChannelCloseType {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::LocalCooperative => 0,
+            Self::Unknown => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChannelCloseType {}
+impl support::IntoDart for ChannelConfig {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.forwarding_fee_proportional_millionths.into_dart(),
+            self.forwarding_fee_base_msat.into_dart(),
+            self.cltv_expiry_delta.into_dart(),
+            self.max_dust_htlc_exposure_msat.into_dart(),
+            self.force_close_avoidance_max_fee_satoshis.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChannelConfig {}
+
+impl support::IntoDart for ChannelConfigUpdateResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.channel_id.into_dart(),
+            self.counterparty_node_id.into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChannelConfigUpdateResult {}
+
 impl support::IntoDart for ChannelDetails {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -819,12 +1108,30 @@ impl support::IntoDart for ChannelDetails {
             self.is_channel_ready.into_dart(),
             self.is_usable.into_dart(),
             self.is_public.into_dart(),
+            self.config.into_dart(),
         ]
         .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for ChannelDetails {}
 
+impl support::IntoDart for ChannelFeeProposal {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.channel_id.into_dart(),
+            self.counterparty_node_id.into_dart(),
+            self.outbound_capacity_msat.into_dart(),
+            self.inbound_capacity_msat.into_dart(),
+            self.current_base_fee_msat.into_dart(),
+            self.current_proportional_millionths.into_dart(),
+            self.proposed_base_fee_msat.into_dart(),
+            self.proposed_proportional_millionths.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChannelFeeProposal {}
+
 impl support::IntoDart for ChannelId {
     fn into_dart(self) -> support::DartAbi {
         vec![self.internal.into_dart()].into_dart()
@@ -832,6 +1139,25 @@ impl support::IntoDart for ChannelId {
 }
 impl support::IntoDartExceptPrimitive for ChannelId {}
 
+impl support::IntoDart for ClosedChannelDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.channel_id.into_dart(),
+            self.user_channel_id.into_dart(),
+            self.counterparty_node_id.into_dart(),
+            self.channel_value_sats.into_dart(),
+            self.final_balance_msat.into_dart(),
+            self.funding_txo.into_dart(),
+            self.closing_txid.into_dart(),
+            self.close_type.into_dart(),
+            self.opened_at.into_dart(),
+            self.closed_at.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ClosedChannelDetails {}
+
 impl support::IntoDart for Event {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -902,6 +1228,22 @@ impl support::IntoDart for NetAddress {
         match self {
             Self::IPv4 { addr, port } => vec![0.into_dart(), addr.into_dart(), port.into_dart()],
             Self::IPv6 { addr, port } => vec![1.into_dart(), addr.into_dart(), port.into_dart()],
+            Self::OnionV2 { addr } => vec![2.into_dart(), addr.into_dart()],
+            Self::OnionV3 {
+                pubkey,
+                checksum,
+                version,
+                port,
+            } => vec![
+                3.into_dart(),
+                pubkey.into_dart(),
+                checksum.into_dart(),
+                version.into_dart(),
+                port.into_dart(),
+            ],
+            Self::Hostname { host, port } => {
+                vec![4.into_dart(), host.into_dart(), port.into_dart()]
+            }
         }
         .into_dart()
     }
@@ -909,7 +1251,7 @@ impl support::IntoDart for NetAddress {
 impl support::IntoDartExceptPrimitive for NetAddress {}
 impl support::IntoDart for NodePointer {
     fn into_dart(self) -> support::DartAbi {
-        vec![self.0.into_dart()].into_dart()
+        vec![self.0.into_dart(), self.1.into_dart()].into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for NodePointer {}
@@ -930,6 +1272,15 @@ impl support::IntoDart for PaymentDetails {
             self.amount_msat.into_dart(),
             self.direction.into_dart(),
             self.status.into_dart(),
+            self.kind.into_dart(),
+            self.attempts.into_dart(),
+            self.created_at.into_dart(),
+            self.updated_at.into_dart(),
+            self.fee_paid_msat.into_dart(),
+            self.bolt11.into_dart(),
+            self.description.into_dart(),
+            self.label.into_dart(),
+            self.counterparty_node_id.into_dart(),
         ]
         .into_dart()
     }
@@ -953,6 +1304,28 @@ impl support::IntoDart for PaymentHash {
 }
 impl support::IntoDartExceptPrimitive for PaymentHash {}
 
+impl support::IntoDart for PaymentKind {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Bolt11 => 0,
+            Self::Spontaneous => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentKind {}
+impl support::IntoDart for PaymentPage {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.payments.into_dart(),
+            self.next_cursor.into_dart(),
+            self.total_count.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentPage {}
+
 impl support::IntoDart for PaymentPreimage {
     fn into_dart(self) -> support::DartAbi {
         vec![self.internal.into_dart()].into_dart()
@@ -973,6 +1346,7 @@ impl support::IntoDart for PaymentStatus {
             Self::Pending => 0,
             Self::Succeeded => 1,
             Self::Failed => 2,
+            Self::Abandoning => 3,
         }
         .into_dart()
     }
@@ -0,0 +1,253 @@
+use crate::store::OnchainPayment;
+use crate::types::{ExportFormat, PaymentDetails, PaymentDirection, PaymentKind, PaymentStatus};
+use ldk_node::bitcoin::hashes::hex::ToHex;
+
+/// The columns of an export, in the order they appear in. Documented on `ExportFormat`.
+const COLUMNS: [&str; 12] = [
+    "created_at",
+    "updated_at",
+    "kind",
+    "direction",
+    "amount_msat",
+    "fee_paid_msat",
+    "status",
+    "payment_hash",
+    "txid",
+    "address",
+    "label",
+    "description",
+];
+
+enum Cell {
+    Number(u64),
+    Text(String),
+    Null,
+}
+
+impl From<Option<u64>> for Cell {
+    fn from(value: Option<u64>) -> Self {
+        value.map_or(Cell::Null, Cell::Number)
+    }
+}
+
+impl From<Option<String>> for Cell {
+    fn from(value: Option<String>) -> Self {
+        value.map_or(Cell::Null, Cell::Text)
+    }
+}
+
+/// A single Lightning payment or on-chain transaction of an export.
+pub(crate) struct ExportRow {
+    created_at: u64,
+    cells: [Cell; 12],
+}
+
+impl From<&PaymentDetails> for ExportRow {
+    fn from(value: &PaymentDetails) -> Self {
+        let kind = match value.kind {
+            PaymentKind::Bolt11 => "bolt11",
+            PaymentKind::Spontaneous => "spontaneous",
+        };
+        let direction = match value.direction {
+            PaymentDirection::Inbound => "inbound",
+            PaymentDirection::Outbound => "outbound",
+        };
+        let status = match value.status {
+            PaymentStatus::Pending => "pending",
+            PaymentStatus::Succeeded => "succeeded",
+            PaymentStatus::Failed => "failed",
+            PaymentStatus::Abandoning => "abandoning",
+        };
+        ExportRow {
+            created_at: value.created_at,
+            cells: [
+                Cell::Number(value.created_at),
+                Cell::Number(value.updated_at),
+                Cell::Text(kind.to_string()),
+                Cell::Text(direction.to_string()),
+                value.amount_msat.into(),
+                value.fee_paid_msat.into(),
+                Cell::Text(status.to_string()),
+                Cell::Text(value.hash.internal.to_hex()),
+                Cell::Null,
+                Cell::Null,
+                value.label.clone().into(),
+                value.description.clone().into(),
+            ],
+        }
+    }
+}
+
+impl From<&OnchainPayment> for ExportRow {
+    fn from(value: &OnchainPayment) -> Self {
+        ExportRow {
+            created_at: value.created_at,
+            cells: [
+                Cell::Number(value.created_at),
+                Cell::Number(value.created_at),
+                Cell::Text("onchain".to_string()),
+                Cell::Text("outbound".to_string()),
+                value.amount_sats.map(|x| x * 1000).into(),
+                Cell::Null,
+                Cell::Text("broadcast".to_string()),
+                Cell::Null,
+                Cell::Text(value.txid.clone()),
+                Cell::Text(value.address.clone()),
+                Cell::Null,
+                Cell::Null,
+            ],
+        }
+    }
+}
+
+/// Renders the given rows in the given format, oldest first.
+pub(crate) fn export(format: ExportFormat, mut rows: Vec<ExportRow>) -> String {
+    rows.sort_by_key(|x| x.created_at);
+    match format {
+        ExportFormat::Csv => to_csv(&rows),
+        ExportFormat::Json => to_json(&rows),
+    }
+}
+
+fn to_csv(rows: &[ExportRow]) -> String {
+    let mut out = COLUMNS.join(",");
+    out.push('\n');
+    for row in rows {
+        let line: Vec<String> = row
+            .cells
+            .iter()
+            .map(|x| match x {
+                Cell::Number(e) => e.to_string(),
+                Cell::Text(e) => csv_escape(e),
+                Cell::Null => String::new(),
+            })
+            .collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_escape(value: &str) -> String {
+    // Neutralise values spreadsheet software would evaluate as a formula.
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn to_json(rows: &[ExportRow]) -> String {
+    let objects: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let fields: Vec<String> = COLUMNS
+                .iter()
+                .zip(row.cells.iter())
+                .map(|(column, cell)| {
+                    let value = match cell {
+                        Cell::Number(e) => e.to_string(),
+                        Cell::Text(e) => json_escape(e),
+                        Cell::Null => "null".to_string(),
+                    };
+                    format!("\"{column}\":{value}")
+                })
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        })
+        .collect();
+    format!("[{}]", objects.join(","))
+}
+
+fn json_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_escape_neutralises_formulas() {
+        assert_eq!(
+            csv_escape("=HYPERLINK(\"x\")"),
+            "\"'=HYPERLINK(\"\"x\"\")\""
+        );
+        assert_eq!(csv_escape("+1"), "'+1");
+        assert_eq!(csv_escape("-1"), "'-1");
+        assert_eq!(csv_escape("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_escape("\t=1"), "'\t=1");
+        assert_eq!(csv_escape("\r=1"), "\"'\r=1\"");
+        assert_eq!(csv_escape("1=1"), "1=1");
+    }
+
+    #[test]
+    fn csv_escape_quotes_separators() {
+        assert_eq!(csv_escape("coffee"), "coffee");
+        assert_eq!(csv_escape(""), "");
+        assert_eq!(csv_escape("coffee, large"), "\"coffee, large\"");
+        assert_eq!(
+            csv_escape("a \"large\" coffee"),
+            "\"a \"\"large\"\" coffee\""
+        );
+        assert_eq!(csv_escape("coffee\nlarge"), "\"coffee\nlarge\"");
+        assert_eq!(csv_escape("-1,5"), "\"'-1,5\"");
+    }
+
+    #[test]
+    fn json_escape_escapes_control_characters() {
+        assert_eq!(json_escape("coffee"), "\"coffee\"");
+        assert_eq!(
+            json_escape("a \"large\" \\ coffee"),
+            "\"a \\\"large\\\" \\\\ coffee\""
+        );
+        assert_eq!(json_escape("\n\r\t"), "\"\\n\\r\\t\"");
+        assert_eq!(json_escape("\u{0}\u{8}\u{1f}"), "\"\\u0000\\u0008\\u001f\"");
+        assert_eq!(json_escape("\u{7f}é☕"), "\"\u{7f}é☕\"");
+    }
+
+    #[test]
+    fn onchain_payment_exports_its_address() {
+        let rows = || {
+            vec![ExportRow::from(&OnchainPayment {
+                txid: "ab".to_string(),
+                address: "bc1q".to_string(),
+                amount_sats: None,
+                created_at: 7,
+            })]
+        };
+        assert_eq!(
+            export(ExportFormat::Csv, rows()),
+            format!(
+                "{}\n7,7,onchain,outbound,,,broadcast,,ab,bc1q,,\n",
+                COLUMNS.join(",")
+            )
+        );
+        assert_eq!(
+            export(ExportFormat::Json, rows()),
+            "[{\"created_at\":7,\"updated_at\":7,\"kind\":\"onchain\",\"direction\":\"outbound\",\
+             \"amount_msat\":null,\"fee_paid_msat\":null,\"status\":\"broadcast\",\
+             \"payment_hash\":null,\"txid\":\"ab\",\"address\":\"bc1q\",\"label\":null,\
+             \"description\":null}]"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use crate::store::NodeStore;
+use crate::types::{ChannelFeeProposal, FeeAutopilotConfig};
+use flutter_rust_bridge::RustOpaque;
+use ldk_node::io::SqliteStore;
+use ldk_node::lightning::util::config::ChannelConfig;
+use ldk_node::Node;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const PPM: u64 = 1_000_000;
+/// The minimum time in-between two fee adjustments, as each one broadcasts a channel update.
+const MIN_INTERVAL_SECS: u64 = 60;
+/// The minimum change of a fee, relative to its current value, worth broadcasting a channel
+/// update for. Without it, nearly every forward would change the fees of the channels involved,
+/// and peers rate-limit or ignore nodes flooding them with channel updates.
+const MIN_FEE_CHANGE_PERCENT: u64 = 10;
+
+/// Periodically adjusts the forwarding fees of our public channels according to a
+/// [FeeAutopilotConfig].
+pub(crate) struct FeeAutopilot {
+    pub config: FeeAutopilotConfig,
+    stop_signal: Mutex<Option<Arc<AtomicBool>>>,
+}
+
+impl FeeAutopilot {
+    pub(crate) fn new(config: FeeAutopilotConfig) -> Self {
+        FeeAutopilot {
+            config,
+            stop_signal: Mutex::new(None),
+        }
+    }
+
+    /// Spawns the background thread applying the fee proposals, unless running in dry-run mode.
+    pub(crate) fn start(
+        &self,
+        node: RustOpaque<Mutex<Node<SqliteStore>>>,
+        store: RustOpaque<NodeStore>,
+    ) {
+        if self.config.dry_run {
+            return;
+        }
+        let stop_signal = Arc::new(AtomicBool::new(false));
+        if let Some(previous) = self
+            .stop_signal
+            .lock()
+            .unwrap()
+            .replace(stop_signal.clone())
+        {
+            previous.store(true, Ordering::Release);
+        }
+        let config = self.config.clone();
+        let interval = Duration::from_secs(config.interval_secs.max(MIN_INTERVAL_SECS));
+        thread::spawn(move || {
+            let mut last_run = Instant::now();
+            while !stop_signal.load(Ordering::Acquire) {
+                thread::sleep(Duration::from_secs(1));
+                if last_run.elapsed() < interval {
+                    continue;
+                }
+                last_run = Instant::now();
+                apply(&config, &node.lock().unwrap(), &store);
+            }
+        });
+    }
+
+    pub(crate) fn stop(&self) {
+        if let Some(stop_signal) = self.stop_signal.lock().unwrap().take() {
+            stop_signal.store(true, Ordering::Release);
+        }
+    }
+}
+
+/// Returns the channels whose fees the autopilot would change, along with their current and new
+/// config. Channels whose current config is unknown are skipped, as we'd otherwise overwrite the
+/// rest of their config with guessed values.
+fn proposed_configs<'a>(
+    config: &FeeAutopilotConfig,
+    store: &NodeStore,
+    channels: &'a [ldk_node::ChannelDetails],
+) -> Vec<(&'a ldk_node::ChannelDetails, ChannelConfig, ChannelConfig)> {
+    let target_ppm = config.target_outbound_percent as u64 * PPM / 100;
+    channels
+        .iter()
+        .filter(|x| x.is_public && x.is_channel_ready)
+        .filter_map(|channel| {
+            let capacity_msat = channel.outbound_capacity_msat + channel.inbound_capacity_msat;
+            if capacity_msat == 0 {
+                return None;
+            }
+            let outbound_ppm = channel.outbound_capacity_msat * PPM / capacity_msat;
+            let current = store.channel_config(channel.user_channel_id.0)?;
+            let mut proposed = current;
+            proposed.forwarding_fee_base_msat = fee_for(
+                config.min_base_fee_msat,
+                config.max_base_fee_msat,
+                outbound_ppm,
+                target_ppm,
+            );
+            proposed.forwarding_fee_proportional_millionths = fee_for(
+                config.min_proportional_millionths,
+                config.max_proportional_millionths,
+                outbound_ppm,
+                target_ppm,
+            );
+            let is_significant = is_significant_change(
+                current.forwarding_fee_base_msat,
+                proposed.forwarding_fee_base_msat,
+            ) || is_significant_change(
+                current.forwarding_fee_proportional_millionths,
+                proposed.forwarding_fee_proportional_millionths,
+            );
+            if !is_significant {
+                return None;
+            }
+            Some((channel, current, proposed))
+        })
+        .collect()
+}
+
+/// Returns the fee for a channel with the given share of its capacity on our side.
+///
+/// The fee decreases linearly from `max` for a drained channel to halfway between `min` and `max`
+/// at the target share, and on to `min` for a channel holding all of its capacity on our side.
+fn fee_for(min: u32, max: u32, outbound_ppm: u64, target_ppm: u64) -> u32 {
+    let (min, max) = (min as u64, max as u64);
+    let mid = (min + max) / 2;
+    let fee = if outbound_ppm <= target_ppm {
+        max - (max - mid) * outbound_ppm / target_ppm
+    } else {
+        mid - (mid - min) * (outbound_ppm - target_ppm) / (PPM - target_ppm)
+    };
+    fee as u32
+}
+
+/// Whether a fee changes by at least [MIN_FEE_CHANGE_PERCENT] of its current value.
+fn is_significant_change(current: u32, proposed: u32) -> bool {
+    let diff = (current as u64).abs_diff(proposed as u64);
+    diff > 0 && diff * 100 >= current as u64 * MIN_FEE_CHANGE_PERCENT
+}
+
+pub(crate) fn proposals(
+    config: &FeeAutopilotConfig,
+    node: &Node<SqliteStore>,
+    store: &NodeStore,
+) -> Vec<ChannelFeeProposal> {
+    let channels = node.list_channels();
+    proposed_configs(config, store, &channels)
+        .into_iter()
+        .map(|(channel, current, proposed)| ChannelFeeProposal {
+            channel_id: channel.channel_id.into(),
+            counterparty_node_id: channel.counterparty_node_id.into(),
+            outbound_capacity_msat: channel.outbound_capacity_msat,
+            inbound_capacity_msat: channel.inbound_capacity_msat,
+            current_base_fee_msat: current.forwarding_fee_base_msat,
+            current_proportional_millionths: current.forwarding_fee_proportional_millionths,
+            proposed_base_fee_msat: proposed.forwarding_fee_base_msat,
+            proposed_proportional_millionths: proposed.forwarding_fee_proportional_millionths,
+        })
+        .collect()
+}
+
+fn apply(config: &FeeAutopilotConfig, node: &Node<SqliteStore>, store: &NodeStore) {
+    let channels = node.list_channels();
+    for (channel, _, proposed) in proposed_configs(config, store, &channels) {
+        let res = node.update_channel_config(
+            &channel.channel_id,
+            channel.counterparty_node_id,
+            &proposed,
+        );
+        match res {
+            Ok(_) => {
+                if let Err(e) = store.set_channel_config(channel.user_channel_id.0, proposed) {
+                    log::error!("Failed to persist channel config: {e}");
+                }
+            }
+            Err(e) => log::error!("Failed to update channel fees: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF: u64 = PPM / 2;
+
+    #[test]
+    fn drained_channel_gets_max_fee() {
+        assert_eq!(fee_for(100, 1000, 0, HALF), 1000);
+        assert_eq!(fee_for(100, 1000, 0, PPM / 100), 1000);
+        assert_eq!(fee_for(100, 1000, 0, PPM * 99 / 100), 1000);
+    }
+
+    #[test]
+    fn channel_at_target_share_gets_mid_fee() {
+        assert_eq!(fee_for(100, 1000, HALF, HALF), 550);
+        assert_eq!(fee_for(100, 1000, PPM / 5, PPM / 5), 550);
+        assert_eq!(fee_for(100, 1001, HALF, HALF), 550);
+    }
+
+    #[test]
+    fn channel_with_all_capacity_on_our_side_gets_min_fee() {
+        assert_eq!(fee_for(100, 1000, PPM, HALF), 100);
+        assert_eq!(fee_for(100, 1000, PPM, PPM / 100), 100);
+        assert_eq!(fee_for(100, 1000, PPM, PPM * 99 / 100), 100);
+    }
+
+    #[test]
+    fn fee_is_linear_on_both_sides_of_the_target() {
+        assert_eq!(fee_for(100, 1000, HALF / 2, HALF), 775);
+        assert_eq!(fee_for(100, 1000, HALF + HALF / 2, HALF), 325);
+    }
+
+    #[test]
+    fn equal_min_and_max_fix_the_fee() {
+        for outbound_ppm in [0, PPM / 5, HALF, PPM] {
+            assert_eq!(fee_for(500, 500, outbound_ppm, HALF), 500);
+            assert_eq!(fee_for(0, 0, outbound_ppm, HALF), 0);
+        }
+    }
+
+    #[test]
+    fn fee_never_increases_with_outbound_share() {
+        for target_percent in 1..=99 {
+            let target_ppm = target_percent * PPM / 100;
+            let mut previous = u32::MAX;
+            for outbound_ppm in (0..=PPM).step_by(10_000) {
+                let fee = fee_for(0, u32::MAX, outbound_ppm, target_ppm);
+                assert!(fee <= previous);
+                previous = fee;
+            }
+        }
+    }
+
+    #[test]
+    fn small_fee_changes_are_not_significant() {
+        assert!(!is_significant_change(1000, 1000));
+        assert!(!is_significant_change(1000, 1099));
+        assert!(!is_significant_change(1000, 901));
+        assert!(is_significant_change(1000, 1100));
+        assert!(is_significant_change(1000, 900));
+        assert!(is_significant_change(0, 1));
+        assert!(!is_significant_change(0, 0));
+    }
+}
@@ -1,31 +1,60 @@
+use crate::export::{export, ExportRow};
+use crate::fee_autopilot::{proposals, FeeAutopilot};
+use crate::payment::{PaymentRequest, PaymentRetry};
+pub use crate::store::NodeStore;
+use crate::store::{now_secs, OnchainPayment, PaymentMetadata, NODE_STORE_DIR};
 use crate::types::*;
 pub use anyhow::anyhow;
 use flutter_rust_bridge::*;
+use ldk_node::bitcoin::hashes::hex::ToHex;
+use ldk_node::bitcoin::hashes::Hash;
 pub use ldk_node::io::SqliteStore;
 use ldk_node::lightning::util::ser::Writeable;
 use ldk_node::Builder;
 pub use ldk_node::Node;
+use std::convert::TryInto;
+use std::path::PathBuf;
 pub use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// How often `wait_until_next_event` checks for a new event.
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 pub fn generate_entropy_mnemonic() -> Mnemonic {
     let mnemonic: Mnemonic = ldk_node::generate_entropy_mnemonic().into();
     mnemonic
 }
+/// Returns the default config LDK Node opens channels with.
+pub fn default_channel_config() -> ChannelConfig {
+    ChannelConfig::default()
+}
 pub fn build_node(
     config: Config,
     chain_data_source_config: Option<ChainDataSourceConfig>,
     entropy_source_config: Option<EntropySourceConfig>,
     gossip_source_config: Option<GossipSourceConfig>,
 ) -> anyhow::Result<NodePointer> {
+    let store_dir = PathBuf::from(&config.storage_dir_path).join(NODE_STORE_DIR);
+    let inbound_channel_policy = config.inbound_channel_policy.clone();
+    if let Some(e) = &config.fee_autopilot {
+        e.validate()?;
+    }
+    let fee_autopilot = config.fee_autopilot.clone().map(FeeAutopilot::new);
     let builder = build_builder(
         config,
         chain_data_source_config,
         entropy_source_config,
         gossip_source_config,
-    );
+    )?;
 
+    let store = NodeStore::new(store_dir, inbound_channel_policy, fee_autopilot)
+        .map_err(|e| anyhow!(e.to_string()))?;
     match builder.build() {
-        Ok(e) => Ok(NodePointer(RustOpaque::new(Mutex::from(e)))),
+        Ok(e) => Ok(NodePointer(
+            RustOpaque::new(Mutex::from(e)),
+            RustOpaque::new(store),
+        )),
         Err(e) => Err(anyhow!(e.to_string())),
     }
 }
@@ -34,8 +63,8 @@ fn build_builder(
     chain_data_source_config: Option<ChainDataSourceConfig>,
     entropy_source_config: Option<EntropySourceConfig>,
     gossip_source_config: Option<GossipSourceConfig>,
-) -> Builder {
-    let mut builder = Builder::from_config(config.into());
+) -> anyhow::Result<Builder> {
+    let mut builder = Builder::from_config(config.try_into()?);
     if let Some(source) = entropy_source_config {
         match source {
             EntropySourceConfig::SeedFile(e) => builder.set_entropy_seed_path(e),
@@ -60,10 +89,13 @@ fn build_builder(
             GossipSourceConfig::RapidGossipSync(e) => builder.set_gossip_source_rgs(e),
         };
     }
-    builder
+    Ok(builder)
 }
 
-pub struct NodePointer(pub RustOpaque<Mutex<Node<SqliteStore>>>);
+pub struct NodePointer(
+    pub RustOpaque<Mutex<Node<SqliteStore>>>,
+    pub RustOpaque<NodeStore>,
+);
 impl NodePointer {
     /// Starts the necessary background tasks, such as handling events coming from user input,
     /// LDK/BDK, and the peer-to-peer network.
@@ -75,13 +107,20 @@ impl NodePointer {
             .lock()
             .unwrap()
             .start()
-            .map_err(|e| anyhow!(e.to_string()))
+            .map_err(|e| anyhow!(e.to_string()))?;
+        if let Some(fee_autopilot) = &self.1.fee_autopilot {
+            fee_autopilot.start(self.0.clone(), self.1.clone());
+        }
+        Ok(())
     }
 
     /// Disconnects all peers, stops all running background tasks, and shuts down [Node].
     ///
     /// After this returns most API methods will throw NotRunning Exception.
     pub fn stop(&self) -> anyhow::Result<()> {
+        if let Some(fee_autopilot) = &self.1.fee_autopilot {
+            fee_autopilot.stop();
+        }
         self.0
             .lock()
             .unwrap()
@@ -102,9 +141,22 @@ impl NodePointer {
     /// **Note:** This **MUST** be called after each event has been handled.
     pub fn next_event(&self) -> Option<Event> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.next_event() {
-            None => None,
-            Some(e) => Some(e.into()),
+        loop {
+            let event = node_lock.next_event()?;
+            if self.retry_payment(&node_lock, &event)
+                || self.reject_inbound_channel(&node_lock, &event)
+            {
+                node_lock.event_handled();
+                continue;
+            }
+            if self.is_rejected_channel_event(&event) {
+                self.process_event(&node_lock, &event);
+                node_lock.event_handled();
+                continue;
+            }
+            let event = self.original_payment_event(event);
+            self.process_event(&node_lock, &event);
+            return Some(event.into());
         }
     }
     /// Returns the next event in the event queue.
@@ -114,8 +166,14 @@ impl NodePointer {
     /// **Note:** this will always return the same event until handling is confirmed via `node.eventHandled()`.
     ///
     pub fn wait_until_next_event(&self) -> Event {
-        let node_lock = self.0.lock().unwrap();
-        (node_lock.wait_next_event()).into()
+        // Poll rather than block in LDK Node, which would keep the node locked while waiting and
+        // thereby stall all other calls, e.g. those of the fee autopilot.
+        loop {
+            if let Some(e) = self.next_event() {
+                return e;
+            }
+            thread::sleep(EVENT_POLL_INTERVAL);
+        }
     }
     /// Returns our own node id
     pub fn node_id(&self) -> anyhow::Result<PublicKey> {
@@ -166,10 +224,18 @@ impl NodePointer {
         amount_sats: u64,
     ) -> anyhow::Result<Txid> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.send_to_onchain_address(&address.into(), amount_sats) {
-            Ok(e) => Ok(Txid {
-                internal: e.to_string(),
-            }),
+        match node_lock.send_to_onchain_address(&address.clone().into(), amount_sats) {
+            Ok(e) => {
+                self.record_onchain_payment(OnchainPayment {
+                    txid: e.to_string(),
+                    address: address.internal,
+                    amount_sats: Some(amount_sats),
+                    created_at: now_secs(),
+                });
+                Ok(Txid {
+                    internal: e.to_string(),
+                })
+            }
             Err(e) => Err(anyhow!(e.to_string())),
         }
     }
@@ -177,10 +243,20 @@ impl NodePointer {
     /// Send an on-chain payment to the given address, draining all the available funds.
     pub fn send_all_to_onchain_address(&self, address: Address) -> anyhow::Result<Txid> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.send_all_to_onchain_address(&address.into()) {
-            Ok(e) => Ok(Txid {
-                internal: e.to_string(),
-            }),
+        match node_lock.send_all_to_onchain_address(&address.clone().into()) {
+            Ok(e) => {
+                // LDK Node doesn't report the amount sent, which is the drained balance minus the
+                // on-chain fee, so leave it unknown.
+                self.record_onchain_payment(OnchainPayment {
+                    txid: e.to_string(),
+                    address: address.internal,
+                    amount_sats: None,
+                    created_at: now_secs(),
+                });
+                Ok(Txid {
+                    internal: e.to_string(),
+                })
+            }
             Err(e) => Err(anyhow!(e.to_string())),
         }
     }
@@ -189,7 +265,24 @@ impl NodePointer {
     ///
     pub fn list_channels(&self) -> Vec<ChannelDetails> {
         let node_lock = self.0.lock().unwrap();
-        node_lock.list_channels().iter().map(|x| x.into()).collect()
+        let channels = node_lock.list_channels();
+        self.1.update_channels(&channels);
+        channels
+            .iter()
+            .map(|x| ChannelDetails::new(x, self.1.channel_config(x.user_channel_id.0)))
+            .collect()
+    }
+
+    /// Retrieves the channels that were closed, as far as they were known to this package.
+    pub fn list_closed_channels(&self) -> Vec<ClosedChannelDetails> {
+        let mut channels: Vec<ClosedChannelDetails> = self
+            .1
+            .closed_channels()
+            .into_iter()
+            .map(|x| x.into())
+            .collect();
+        channels.sort_by_key(|x| x.closed_at);
+        channels
     }
     /// Connect to a node on the peer-to-peer network.
     ///
@@ -201,7 +294,7 @@ impl NodePointer {
         persist: bool,
     ) -> anyhow::Result<()> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.connect(node_id.into(), address.into(), persist) {
+        match node_lock.connect(node_id.into(), address.try_into()?, persist) {
             Ok(_) => Ok(()),
             Err(e) => Err(anyhow!(e.to_string())),
         }
@@ -238,15 +331,37 @@ impl NodePointer {
         channel_config: Option<ChannelConfig>,
     ) -> anyhow::Result<()> {
         let node_lock = self.0.lock().unwrap();
+        let node_id: ldk_node::bitcoin::secp256k1::PublicKey = node_id.into();
+        let channel_config: Option<ldk_node::lightning::util::config::ChannelConfig> =
+            channel_config.map(|x| x.into());
+        let known_channels: Vec<u128> = node_lock
+            .list_channels()
+            .iter()
+            .map(|x| x.user_channel_id.0)
+            .collect();
         match node_lock.connect_open_channel(
-            node_id.into(),
-            address.into(),
+            node_id,
+            address.try_into()?,
             channel_amount_sats,
             push_to_counterparty_msat,
-            channel_config.map(|x| x.into()),
+            channel_config,
             announce_channel,
         ) {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                if let Some(config) = channel_config {
+                    // LDK Node doesn't tell which channel was opened, so find the new one. Inbound
+                    // channels may have been accepted in the meantime, so only consider ours.
+                    let new_channel = node_lock.list_channels().into_iter().find(|x| {
+                        x.is_outbound
+                            && x.counterparty_node_id == node_id
+                            && !known_channels.contains(&x.user_channel_id.0)
+                    });
+                    if let Some(channel) = new_channel {
+                        self.store_channel_config(channel.user_channel_id.0, config);
+                    }
+                }
+                Ok(())
+            }
             Err(e) => Err(anyhow!(e.to_string())),
         }
     }
@@ -261,42 +376,120 @@ impl NodePointer {
         }
     }
     /// Close a previously opened channel.
+    ///
+    /// The channel is closed cooperatively, with the closing fee negotiated by LDK and the funds
+    /// sent to the on-chain wallet. LDK Node 0.1.0 offers no way to force-close a channel.
     pub fn close_channel(
         &self,
         channel_id: ChannelId,
         counterparty_node_id: PublicKey,
     ) -> anyhow::Result<()> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.close_channel(&(channel_id.into()), counterparty_node_id.into()) {
-            Ok(_) => Ok(()),
+        let channel_id: ldk_node::ChannelId = channel_id.into();
+        match node_lock.close_channel(&channel_id, counterparty_node_id.into()) {
+            Ok(_) => {
+                self.record_close_requested(&node_lock, &channel_id, false);
+                Ok(())
+            }
             Err(e) => Err(anyhow!(e.to_string())),
         }
     }
+    /// Returns the config of a previously opened channel.
+    ///
+    /// This is the config set on channel open or via `node.updateChannelConfig()`, or `null` if it
+    /// was never set via this package, as LDK Node 0.1.0 offers no way to read it back.
+    pub fn channel_config(&self, channel_id: ChannelId) -> anyhow::Result<Option<ChannelConfig>> {
+        let node_lock = self.0.lock().unwrap();
+        let channel_id: ldk_node::ChannelId = channel_id.into();
+        match node_lock
+            .list_channels()
+            .into_iter()
+            .find(|x| x.channel_id == channel_id)
+        {
+            Some(e) => Ok(self.1.channel_config(e.user_channel_id.0).map(|x| x.into())),
+            None => Err(anyhow!("Unknown channel")),
+        }
+    }
     ///Update the config for a previously opened channel.
     ///
+    /// Only the fields set in `update` are changed, all others keep their current value. Throws
+    /// if `node.channelConfig()` returns `null` for the channel and not all fields are set, as
+    /// their current value is unknown then.
     pub fn update_channel_config(
         &self,
         channel_id: ChannelId,
         counterparty_node_id: PublicKey,
-        channel_config: ChannelConfig,
+        update: ChannelConfigUpdate,
     ) -> anyhow::Result<()> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.update_channel_config(
-            &(channel_id.into()),
-            counterparty_node_id.into(),
-            &(channel_config).into(),
-        ) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(anyhow!(e.to_string())),
+        let channel_id: ldk_node::ChannelId = channel_id.into();
+        let counterparty_node_id: ldk_node::bitcoin::secp256k1::PublicKey =
+            counterparty_node_id.into();
+        let channel = node_lock
+            .list_channels()
+            .into_iter()
+            .find(|x| x.channel_id == channel_id && x.counterparty_node_id == counterparty_node_id);
+        match channel {
+            Some(e) => self.apply_channel_config_update(&node_lock, &e, &update),
+            None => Err(anyhow!("Unknown channel")),
         }
     }
-    /// Send a payement given an invoice.
-    pub fn send_payment(&self, invoice: Invoice) -> anyhow::Result<PaymentHash> {
+    /// Updates the config of all channels matching the given filter.
+    ///
+    /// Only the fields set in `update` are changed, all others keep their current value. Channels
+    /// whose config is unknown are only updated if all fields are set. A failed update doesn't
+    /// stop the remaining channels from being updated; the outcome is reported
+    /// per channel.
+    ///
+    /// Throws if the node ID of a `ChannelFilter.counterparty()` filter is invalid.
+    pub fn update_all_channel_configs(
+        &self,
+        update: ChannelConfigUpdate,
+        filter: ChannelFilter,
+    ) -> anyhow::Result<Vec<ChannelConfigUpdateResult>> {
+        let matcher: ChannelMatcher = filter.try_into()?;
         let node_lock = self.0.lock().unwrap();
-        match node_lock.send_payment(&invoice.into()) {
-            Ok(e) => Ok(PaymentHash { internal: e.0 }),
-            Err(e) => Err(anyhow!(e.to_string())),
-        }
+        Ok(node_lock
+            .list_channels()
+            .iter()
+            .filter(|x| matcher.matches(x))
+            .map(|x| ChannelConfigUpdateResult {
+                channel_id: x.channel_id.into(),
+                counterparty_node_id: x.counterparty_node_id.into(),
+                error: self
+                    .apply_channel_config_update(&node_lock, x, &update)
+                    .err()
+                    .map(|e| e.to_string()),
+            })
+            .collect())
+    }
+    /// Returns the changes the fee autopilot would currently make to the forwarding fees of our
+    /// channels, without applying them.
+    ///
+    /// Throws if no `Config.feeAutopilot` is set.
+    pub fn fee_autopilot_proposals(&self) -> anyhow::Result<Vec<ChannelFeeProposal>> {
+        let fee_autopilot = match &self.1.fee_autopilot {
+            Some(e) => e,
+            None => return Err(anyhow!("The fee autopilot is not configured")),
+        };
+        let node_lock = self.0.lock().unwrap();
+        Ok(proposals(&fee_autopilot.config, &node_lock, &self.1))
+    }
+    /// Send a payement given an invoice.
+    ///
+    /// If `retryStrategy` is set, the payment is sent again according to it when it fails.
+    pub fn send_payment(
+        &self,
+        invoice: Invoice,
+        retry_strategy: Option<RetryStrategy>,
+    ) -> anyhow::Result<PaymentHash> {
+        self.send(
+            PaymentRequest::Invoice {
+                invoice: invoice.into(),
+                amount_msat: None,
+            },
+            retry_strategy,
+        )
     }
 
     /// Send a payment given an invoice and an amount in millisatoshi.
@@ -305,29 +498,42 @@ impl NodePointer {
     ///
     /// This can be used to pay a so-called "zero-amount" invoice, i.e., an invoice that leaves the
     /// amount paid to be determined by the user.
+    ///
+    /// If `retryStrategy` is set, the payment is sent again according to it when it fails.
     pub fn send_payment_using_amount(
         &self,
         invoice: Invoice,
         amount_msat: u64,
+        retry_strategy: Option<RetryStrategy>,
     ) -> anyhow::Result<PaymentHash> {
-        let node_lock = self.0.lock().unwrap();
-        match node_lock.send_payment_using_amount(&invoice.into(), amount_msat) {
-            Ok(e) => Ok(PaymentHash { internal: e.0 }),
-            Err(e) => Err(anyhow!(e.to_string())),
-        }
+        self.send(
+            PaymentRequest::Invoice {
+                invoice: invoice.into(),
+                amount_msat: Some(amount_msat),
+            },
+            retry_strategy,
+        )
     }
 
     /// Send a spontaneous, aka. "keysend", payment
+    ///
+    /// If `retryStrategy` is set, the payment is sent again according to it when it fails. Every
+    /// attempt uses a new payment hash, but the payment stays known by the returned hash of its
+    /// first attempt: its events and `PaymentDetails` carry that hash, and it is the one to pass
+    /// to `node.payment()` or `node.abandonPayment()`.
     pub fn send_spontaneous_payment(
         &self,
         amount_msat: u64,
         node_id: PublicKey,
+        retry_strategy: Option<RetryStrategy>,
     ) -> anyhow::Result<PaymentHash> {
-        let node_lock = self.0.lock().unwrap();
-        match node_lock.send_spontaneous_payment(amount_msat, node_id.into()) {
-            Ok(e) => Ok(PaymentHash { internal: e.0 }),
-            Err(e) => Err(anyhow!(e.to_string())),
-        }
+        self.send(
+            PaymentRequest::Spontaneous {
+                amount_msat,
+                node_id: node_id.into(),
+            },
+            retry_strategy,
+        )
     }
 
     /// Returns a payable invoice that can be used to request and receive a payment of the amount
@@ -340,9 +546,12 @@ impl NodePointer {
     ) -> anyhow::Result<Invoice> {
         let node_lock = self.0.lock().unwrap();
         match node_lock.receive_payment(amount_msat, description.as_str(), expiry_secs) {
-            Ok(e) => Ok(Invoice {
-                internal: e.to_string(),
-            }),
+            Ok(e) => {
+                self.record_payment(&e.payment_hash().into_inner(), |x| x.set_invoice(&e));
+                Ok(Invoice {
+                    internal: e.to_string(),
+                })
+            }
             Err(e) => Err(anyhow!(e.to_string())),
         }
     }
@@ -355,9 +564,12 @@ impl NodePointer {
     ) -> anyhow::Result<Invoice> {
         let node_lock = self.0.lock().unwrap();
         match node_lock.receive_variable_amount_payment(description.as_str(), expiry_secs) {
-            Ok(e) => Ok(Invoice {
-                internal: e.to_string(),
-            }),
+            Ok(e) => {
+                self.record_payment(&e.payment_hash().into_inner(), |x| x.set_invoice(&e));
+                Ok(Invoice {
+                    internal: e.to_string(),
+                })
+            }
             Err(e) => Err(anyhow!(e.to_string())),
         }
     }
@@ -367,10 +579,8 @@ impl NodePointer {
     /// Returns `PaymentDetails` if the payment was known and `null` otherwise.
     pub fn payment(&self, payment_hash: PaymentHash) -> Option<PaymentDetails> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.payment(&ldk_node::lightning::ln::PaymentHash(payment_hash.internal)) {
-            None => None,
-            Some(e) => Some(e.into()),
-        }
+        self.ldk_payment(&node_lock, &payment_hash.internal)
+            .map(|x| self.payment_details(x))
     }
 
     /// Remove the payment with the given hash from the store.
@@ -378,9 +588,74 @@ impl NodePointer {
     /// Returns `true` if the payment was present and `false` otherwise.
     pub fn remove_payment(&self, payment_hash: PaymentHash) -> anyhow::Result<bool> {
         let node_lock = self.0.lock().unwrap();
-        match node_lock.remove_payment(&ldk_node::lightning::ln::PaymentHash(payment_hash.internal))
+        let metadata = self.1.find_payment(&payment_hash.internal);
+        let mut removed = metadata.as_ref().map_or(false, |x| x.detached.is_some());
+        let mut hashes = vec![payment_hash.internal];
+        hashes.extend(metadata.into_iter().flat_map(|x| x.retry_hashes));
+        for hash in hashes {
+            removed |= node_lock
+                .remove_payment(&ldk_node::lightning::ln::PaymentHash(hash))
+                .map_err(|e| anyhow!(e.to_string()))?;
+            self.1.take_retry(&hash);
+            self.1
+                .remove_payment(&hash)
+                .map_err(|e| anyhow!(e.to_string()))?;
+        }
+        Ok(removed)
+    }
+
+    /// Gives up on the pending outbound payment with the given hash.
+    ///
+    /// No further retries are made, and the payment's status changes to `Abandoning` until LDK
+    /// resolves its in-flight HTLCs, after which it is marked as failed and an
+    /// `Event.paymentFailed` is emitted.
+    ///
+    /// **Note:** LDK Node 0.1.0 can't cancel HTLCs that are already in flight, so an abandoned
+    /// payment may still succeed, in which case an `Event.paymentSuccessful` is emitted instead.
+    pub fn abandon_payment(&self, payment_hash: PaymentHash) -> anyhow::Result<()> {
+        let node_lock = self.0.lock().unwrap();
+        match self.ldk_payment(&node_lock, &payment_hash.internal) {
+            None => return Err(anyhow!("Unknown payment")),
+            Some(e) => {
+                if e.direction != ldk_node::PaymentDirection::Outbound
+                    || e.status != ldk_node::PaymentStatus::Pending
+                {
+                    return Err(anyhow!("Only pending outbound payments can be abandoned"));
+                }
+            }
+        }
+        let attempt_hash = self
+            .1
+            .find_payment(&payment_hash.internal)
+            .and_then(|x| x.retry_hashes.last().copied())
+            .unwrap_or(payment_hash.internal);
+        self.1.take_retry(&attempt_hash);
+        match self.1.update_payment(&payment_hash.internal, |x| {
+            x.abandoned_at = Some(now_secs())
+        }) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(anyhow!(e.to_string())),
+        }
+    }
+
+    /// Sets the label of the payment with the given hash, or clears it if `label` is `null`.
+    pub fn set_payment_label(
+        &self,
+        payment_hash: PaymentHash,
+        label: Option<String>,
+    ) -> anyhow::Result<()> {
+        let node_lock = self.0.lock().unwrap();
+        if self
+            .ldk_payment(&node_lock, &payment_hash.internal)
+            .is_none()
         {
-            Ok(e) => Ok(e),
+            return Err(anyhow!("Unknown payment"));
+        }
+        match self
+            .1
+            .update_payment(&payment_hash.internal, |x| x.label = label)
+        {
+            Ok(_) => Ok(()),
             Err(e) => Err(anyhow!(e.to_string())),
         }
     }
@@ -392,22 +667,67 @@ impl NodePointer {
         payment_direction: PaymentDirection,
     ) -> Vec<PaymentDetails> {
         let node_lock = self.0.lock().unwrap();
-        let payment_details =
-            node_lock.list_payments_with_filter(|p| p.direction == payment_direction.into());
-        payment_details
-            .iter()
-            .map(|x| x.to_owned().into())
+        let direction: ldk_node::PaymentDirection = payment_direction.into();
+        self.ldk_payments(&node_lock)
+            .into_iter()
+            .filter(|x| x.direction == direction)
+            .map(|x| self.payment_details(x))
             .collect()
     }
+    /// Retrieves the payments matching the given query, one page at a time.
+    ///
+    /// Payments are sorted by their creation time. To retrieve the following page, call this
+    /// again with `query.cursor` set to the `nextCursor` of the returned page.
+    pub fn query_payments(&self, query: PaymentQuery) -> anyhow::Result<PaymentPage> {
+        let node_lock = self.0.lock().unwrap();
+        let payments = self
+            .ldk_payments(&node_lock)
+            .into_iter()
+            .map(|x| self.payment_details(x))
+            .collect();
+        payment_page(payments, &query)
+    }
     /// Retrieves all payments.
     pub fn list_payments(&self) -> Vec<PaymentDetails> {
         let node_lock = self.0.lock().unwrap();
-        node_lock
-            .list_payments()
-            .iter()
-            .map(|x| x.to_owned().into())
+        self.ldk_payments(&node_lock)
+            .into_iter()
+            .map(|x| self.payment_details(x))
             .collect()
     }
+    /// Exports the Lightning payments and on-chain transactions created in the given time range,
+    /// e.g. for accounting.
+    ///
+    /// `createdFrom` and `createdUntil` are in seconds since the UNIX epoch; the former is
+    /// inclusive, the latter exclusive, and either may be `null` to leave the range open. See
+    /// [ExportFormat] for the fields of the export.
+    pub fn export_payments(
+        &self,
+        format: ExportFormat,
+        created_from: Option<u64>,
+        created_until: Option<u64>,
+    ) -> String {
+        let in_range = |created_at: u64| {
+            created_from.map_or(true, |x| created_at >= x)
+                && created_until.map_or(true, |x| created_at < x)
+        };
+        let node_lock = self.0.lock().unwrap();
+        let mut rows: Vec<ExportRow> = self
+            .ldk_payments(&node_lock)
+            .into_iter()
+            .map(|x| self.payment_details(x))
+            .filter(|x| in_range(x.created_at))
+            .map(|x| (&x).into())
+            .collect();
+        rows.extend(
+            self.1
+                .onchain_payments()
+                .iter()
+                .filter(|x| in_range(x.created_at))
+                .map(|x| x.into()),
+        );
+        export(format, rows)
+    }
     /// Retrieves a list of known peers.
     pub fn list_peers(&self) -> Vec<PeerDetails> {
         let node_lock = self.0.lock().unwrap();
@@ -442,4 +762,528 @@ impl NodePointer {
         let node_lock = self.0.lock().unwrap();
         Ok(node_lock.verify_signature(msg.as_slice(), sig.as_str(), &(pkey.into())))
     }
+
+    /// Returns LDK Node's record of the payment with the given hash as the user knows it: a
+    /// retried spontaneous payment is represented by its latest attempt under the hash of its
+    /// first one, and a payment LDK Node lost while retrying it by the record we kept.
+    fn ldk_payment(
+        &self,
+        node: &Node<SqliteStore>,
+        payment_hash: &[u8; 32],
+    ) -> Option<ldk_node::PaymentDetails> {
+        let metadata = self.1.find_payment(payment_hash);
+        let attempt_hash = metadata
+            .as_ref()
+            .and_then(|x| x.retry_hashes.last().copied())
+            .unwrap_or(*payment_hash);
+        let mut payment = node
+            .payment(&ldk_node::lightning::ln::PaymentHash(attempt_hash))
+            .or_else(|| metadata.and_then(|x| x.detached))?;
+        payment.hash = ldk_node::lightning::ln::PaymentHash(*payment_hash);
+        Some(payment)
+    }
+
+    /// Returns all payments as the user knows them, see [Self::ldk_payment]. Retries of
+    /// spontaneous payments are folded into their first attempt.
+    fn ldk_payments(&self, node: &Node<SqliteStore>) -> Vec<ldk_node::PaymentDetails> {
+        let mut payments = node.list_payments();
+        for payment in self.1.detached_payments() {
+            if !payments.iter().any(|x| x.hash == payment.hash) {
+                payments.push(payment);
+            }
+        }
+        payments
+            .iter()
+            .filter(|x| {
+                self.1
+                    .find_payment(&x.hash.0)
+                    .map_or(true, |x| x.original_hash.is_none())
+            })
+            .filter_map(|x| self.ldk_payment(node, &x.hash.0))
+            .collect()
+    }
+
+    /// Replaces the hash of a retried spontaneous payment's attempt in the given event with the
+    /// hash of its first attempt, which the user knows the payment by.
+    fn original_payment_event(&self, event: ldk_node::Event) -> ldk_node::Event {
+        let original_hash = |payment_hash: ldk_node::lightning::ln::PaymentHash| {
+            self.1
+                .find_payment(&payment_hash.0)
+                .and_then(|x| x.original_hash)
+                .map_or(payment_hash, ldk_node::lightning::ln::PaymentHash)
+        };
+        match event {
+            ldk_node::Event::PaymentSuccessful { payment_hash } => {
+                ldk_node::Event::PaymentSuccessful {
+                    payment_hash: original_hash(payment_hash),
+                }
+            }
+            ldk_node::Event::PaymentFailed { payment_hash } => ldk_node::Event::PaymentFailed {
+                payment_hash: original_hash(payment_hash),
+            },
+            e => e,
+        }
+    }
+
+    fn payment_details(&self, value: ldk_node::PaymentDetails) -> PaymentDetails {
+        let metadata = self.1.payment(&value.hash.0);
+        PaymentDetails::new(value, metadata)
+    }
+
+    fn record_payment<F: FnOnce(&mut PaymentMetadata)>(&self, payment_hash: &[u8; 32], update: F) {
+        if let Err(e) = self.1.update_payment(payment_hash, update) {
+            log::error!("Failed to persist payment metadata: {e}");
+        }
+    }
+
+    fn send(
+        &self,
+        request: PaymentRequest,
+        retry_strategy: Option<RetryStrategy>,
+    ) -> anyhow::Result<PaymentHash> {
+        let node_lock = self.0.lock().unwrap();
+        match request.send(&node_lock) {
+            Ok(e) => {
+                self.record_payment(&e.0, |x| {
+                    request.record(x);
+                    x.attempts = Some(1);
+                });
+                if let Some(strategy) = retry_strategy {
+                    self.1
+                        .insert_retry(e.0, PaymentRetry::new(request, strategy));
+                }
+                Ok(PaymentHash { internal: e.0 })
+            }
+            Err(e) => Err(anyhow!(e.to_string())),
+        }
+    }
+
+    /// Sends the payment failed according to the given event again if its retry strategy
+    /// allows, in which case the event must not be surfaced. Returns whether it did so.
+    fn retry_payment(&self, node: &Node<SqliteStore>, event: &ldk_node::Event) -> bool {
+        let payment_hash = match event {
+            ldk_node::Event::PaymentFailed { payment_hash } => payment_hash,
+            ldk_node::Event::PaymentSuccessful { payment_hash } => {
+                self.1.take_retry(&payment_hash.0);
+                return false;
+            }
+            _ => return false,
+        };
+        let mut retry = match self.1.take_retry(&payment_hash.0) {
+            Some(e) if e.can_retry() && !e.request.is_expired() => e,
+            _ => return false,
+        };
+        if retry.request.reuses_payment_hash() {
+            // LDK Node refuses to send a payment it already knows about, so its record has to go.
+            // Keep a copy in case the payment fails before LDK Node records it again.
+            let payment = match node.payment(payment_hash) {
+                Some(e) => e,
+                None => return false,
+            };
+            if let Err(e) = node.remove_payment(payment_hash) {
+                log::error!("Failed to retry payment: {e}");
+                return false;
+            }
+            if let Err(e) = retry.request.send(node) {
+                log::error!("Failed to retry payment: {e}");
+                if node.payment(payment_hash).is_none() {
+                    self.record_payment(&payment_hash.0, |x| x.detached = Some(payment));
+                }
+                return false;
+            }
+            retry.attempts += 1;
+            let attempts = retry.attempts;
+            self.record_payment(&payment_hash.0, |x| {
+                x.attempts = Some(attempts);
+                x.detached = None;
+            });
+            self.1.insert_retry(payment_hash.0, retry);
+        } else {
+            // Every attempt of a spontaneous payment has its own hash and record, which stay
+            // around linked to the first attempt.
+            let original_hash = self
+                .1
+                .find_payment(&payment_hash.0)
+                .and_then(|x| x.original_hash)
+                .unwrap_or(payment_hash.0);
+            let new_payment_hash = match retry.request.send(node) {
+                Ok(e) => e,
+                Err(e) => {
+                    log::error!("Failed to retry payment: {e}");
+                    return false;
+                }
+            };
+            retry.attempts += 1;
+            let attempts = retry.attempts;
+            self.record_payment(&original_hash, |x| {
+                x.attempts = Some(attempts);
+                x.retry_hashes.push(new_payment_hash.0);
+            });
+            self.record_payment(&new_payment_hash.0, |x| {
+                retry.request.record(x);
+                x.attempts = Some(attempts);
+                x.original_hash = Some(original_hash);
+            });
+            self.1.insert_retry(new_payment_hash.0, retry);
+        }
+        true
+    }
+
+    /// Closes the channel that became pending according to the given event if it is an inbound
+    /// channel violating the inbound channel policy, in which case the event must not be
+    /// surfaced. Returns whether it did so; if closing fails, the event is surfaced so the app
+    /// still learns about the channel.
+    fn reject_inbound_channel(&self, node: &Node<SqliteStore>, event: &ldk_node::Event) -> bool {
+        let policy = match &self.1.inbound_channel_policy {
+            Some(e) => e,
+            None => return false,
+        };
+        let (channel_id, counterparty_node_id) = match event {
+            ldk_node::Event::ChannelPending {
+                channel_id,
+                counterparty_node_id,
+                ..
+            } => (channel_id, counterparty_node_id),
+            _ => return false,
+        };
+        let channels = node.list_channels();
+        let channel = match channels.iter().find(|x| x.channel_id == *channel_id) {
+            Some(e) if !e.is_outbound => e,
+            _ => return false,
+        };
+        let pending_channels = channels
+            .iter()
+            .filter(|x| !x.is_outbound && !x.is_channel_ready)
+            .count();
+        let violation = match policy.violation(channel, pending_channels) {
+            Some(e) => e,
+            None => return false,
+        };
+        log::info!(
+            "Closing inbound channel {} as it violates the inbound channel policy: {violation}",
+            channel_id.0.to_hex()
+        );
+        match node.close_channel(channel_id, *counterparty_node_id) {
+            Ok(_) => {
+                self.record_close_requested(node, channel_id, true);
+                true
+            }
+            Err(e) => {
+                log::error!("Failed to close inbound channel: {e}");
+                false
+            }
+        }
+    }
+
+    /// Whether the given event belongs to a channel closed by [Self::reject_inbound_channel].
+    /// Its `ChannelPending` event wasn't surfaced, so neither are the later ones.
+    fn is_rejected_channel_event(&self, event: &ldk_node::Event) -> bool {
+        let user_channel_id = match event {
+            ldk_node::Event::ChannelReady {
+                user_channel_id, ..
+            }
+            | ldk_node::Event::ChannelClosed {
+                user_channel_id, ..
+            } => user_channel_id,
+            _ => return false,
+        };
+        self.1
+            .channel(user_channel_id.0)
+            .map_or(false, |x| x.rejected_at.is_some())
+    }
+
+    fn record_close_requested(
+        &self,
+        node: &Node<SqliteStore>,
+        channel_id: &ldk_node::ChannelId,
+        rejected: bool,
+    ) {
+        let channels = node.list_channels();
+        self.1.update_channels(&channels);
+        if let Some(channel) = channels.iter().find(|x| x.channel_id == *channel_id) {
+            let res = self.1.update_channel(channel.user_channel_id.0, |x| {
+                x.close_requested_at = Some(now_secs());
+                if rejected {
+                    x.rejected_at = x.close_requested_at;
+                }
+            });
+            if let Err(e) = res {
+                log::error!("Failed to persist channel: {e}");
+            }
+        }
+    }
+
+    /// Applies the given update on top of the channel's current config. As the node stays locked
+    /// by the caller, the config can't change in-between.
+    ///
+    /// If the current config is unknown, the update must set all fields, as we'd otherwise
+    /// overwrite the others with guessed values.
+    fn apply_channel_config_update(
+        &self,
+        node: &Node<SqliteStore>,
+        channel: &ldk_node::ChannelDetails,
+        update: &ChannelConfigUpdate,
+    ) -> anyhow::Result<()> {
+        let mut channel_config = match self.1.channel_config(channel.user_channel_id.0) {
+            Some(e) => e,
+            None if update.is_complete() => Default::default(),
+            None => {
+                return Err(anyhow!(
+                    "The channel's config is unknown, so all fields of the update must be set"
+                ))
+            }
+        };
+        update.apply(&mut channel_config);
+        node.update_channel_config(
+            &channel.channel_id,
+            channel.counterparty_node_id,
+            &channel_config,
+        )
+        .map_err(|e| anyhow!(e.to_string()))?;
+        self.store_channel_config(channel.user_channel_id.0, channel_config);
+        Ok(())
+    }
+
+    fn store_channel_config(
+        &self,
+        user_channel_id: u128,
+        config: ldk_node::lightning::util::config::ChannelConfig,
+    ) {
+        if let Err(e) = self.1.set_channel_config(user_channel_id, config) {
+            log::error!("Failed to persist channel config: {e}");
+        }
+    }
+
+    fn record_onchain_payment(&self, payment: OnchainPayment) {
+        if let Err(e) = self.1.insert_onchain_payment(payment) {
+            log::error!("Failed to persist on-chain payment: {e}");
+        }
+    }
+
+    /// Updates the data we keep on top of LDK Node according to the given event.
+    fn process_event(&self, node: &Node<SqliteStore>, event: &ldk_node::Event) {
+        if !self.1.is_new_event(event) {
+            return;
+        }
+        self.1.update_channels(&node.list_channels());
+        match event {
+            ldk_node::Event::PaymentSuccessful { payment_hash }
+            | ldk_node::Event::PaymentFailed { payment_hash }
+            | ldk_node::Event::PaymentReceived { payment_hash, .. } => {
+                self.record_payment(&payment_hash.0, |_| {})
+            }
+            ldk_node::Event::ChannelClosed {
+                user_channel_id, ..
+            } => {
+                let res = self
+                    .1
+                    .update_channel(user_channel_id.0, |x| x.closed_at = Some(now_secs()));
+                if let Err(e) = res {
+                    log::error!("Failed to persist channel: {e}");
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the page of the given payments selected by the query.
+fn payment_page(
+    payments: Vec<PaymentDetails>,
+    query: &PaymentQuery,
+) -> anyhow::Result<PaymentPage> {
+    if query.limit == Some(0) {
+        return Err(anyhow!("The payment query limit must be greater than 0"));
+    }
+    let cursor = match &query.cursor {
+        None => None,
+        Some(e) => Some(parse_payment_cursor(e)?),
+    };
+    let mut payments: Vec<PaymentDetails> =
+        payments.into_iter().filter(|x| query.matches(x)).collect();
+    payments.sort_by_cached_key(payment_cursor_key);
+    if query.sort_order == PaymentSortOrder::NewestFirst {
+        payments.reverse();
+    }
+    let total_count = payments.len() as u64;
+    if let Some(cursor) = cursor {
+        let is_after_cursor = |x: &PaymentDetails| match query.sort_order {
+            PaymentSortOrder::OldestFirst => payment_cursor_key(x) > cursor,
+            PaymentSortOrder::NewestFirst => payment_cursor_key(x) < cursor,
+        };
+        payments.retain(is_after_cursor);
+    }
+    let mut next_cursor = None;
+    if let Some(limit) = query.limit {
+        if payments.len() > limit as usize {
+            payments.truncate(limit as usize);
+            next_cursor = payments.last().map(|x| {
+                let (created_at, hash) = payment_cursor_key(x);
+                format!("{created_at}:{hash}")
+            });
+        }
+    }
+    Ok(PaymentPage {
+        payments,
+        next_cursor,
+        total_count,
+    })
+}
+
+/// Orders payments by creation time, falling back to the payment hash for a stable order.
+fn payment_cursor_key(payment: &PaymentDetails) -> (u64, String) {
+    (payment.created_at, payment.hash.internal.to_hex())
+}
+
+fn parse_payment_cursor(cursor: &str) -> anyhow::Result<(u64, String)> {
+    let (created_at, hash) = cursor
+        .split_once(':')
+        .ok_or_else(|| anyhow!("Invalid payment cursor"))?;
+    let created_at = created_at
+        .parse()
+        .map_err(|_| anyhow!("Invalid payment cursor"))?;
+    Ok((created_at, hash.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment(created_at: u64, hash: u8) -> PaymentDetails {
+        PaymentDetails {
+            hash: PaymentHash {
+                internal: [hash; 32],
+            },
+            preimage: None,
+            secret: None,
+            amount_msat: Some(1_000),
+            direction: PaymentDirection::Outbound,
+            status: PaymentStatus::Succeeded,
+            kind: PaymentKind::Bolt11,
+            attempts: 1,
+            created_at,
+            updated_at: created_at,
+            fee_paid_msat: None,
+            bolt11: None,
+            description: None,
+            label: None,
+            counterparty_node_id: None,
+        }
+    }
+
+    fn query(sort_order: PaymentSortOrder, limit: u32) -> PaymentQuery {
+        PaymentQuery {
+            direction: None,
+            statuses: vec![],
+            created_from: None,
+            created_until: None,
+            min_amount_msat: None,
+            max_amount_msat: None,
+            label_contains: None,
+            kind: None,
+            sort_order,
+            cursor: None,
+            limit: Some(limit),
+        }
+    }
+
+    /// Follows the cursors from the first page to the last one, returning the `(created_at, hash)`
+    /// of every payment in the order the pages returned them.
+    fn all_pages(
+        payments: &[PaymentDetails],
+        sort_order: PaymentSortOrder,
+        limit: u32,
+    ) -> Vec<(u64, u8)> {
+        let mut query = query(sort_order, limit);
+        let mut seen = vec![];
+        loop {
+            let page = payment_page(payments.to_vec(), &query).unwrap();
+            assert_eq!(page.total_count, payments.len() as u64);
+            assert!(page.payments.len() <= limit as usize);
+            seen.extend(
+                page.payments
+                    .iter()
+                    .map(|x| (x.created_at, x.hash.internal[0])),
+            );
+            assert!(seen.len() <= payments.len(), "pages repeat payments");
+            match page.next_cursor {
+                Some(e) => query.cursor = Some(e),
+                None => return seen,
+            }
+        }
+    }
+
+    /// Payments created within the same second, spread across page boundaries.
+    fn tied_payments() -> Vec<PaymentDetails> {
+        vec![
+            payment(20, 4),
+            payment(10, 3),
+            payment(20, 1),
+            payment(10, 5),
+            payment(20, 2),
+            payment(30, 6),
+            payment(10, 7),
+        ]
+    }
+
+    #[test]
+    fn oldest_first_pages_split_ties_on_created_at() {
+        let expected = vec![
+            (10, 3),
+            (10, 5),
+            (10, 7),
+            (20, 1),
+            (20, 2),
+            (20, 4),
+            (30, 6),
+        ];
+        for limit in 1..=8 {
+            assert_eq!(
+                all_pages(&tied_payments(), PaymentSortOrder::OldestFirst, limit),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn newest_first_pages_split_ties_on_created_at() {
+        let expected = vec![
+            (30, 6),
+            (20, 4),
+            (20, 2),
+            (20, 1),
+            (10, 7),
+            (10, 5),
+            (10, 3),
+        ];
+        for limit in 1..=8 {
+            assert_eq!(
+                all_pages(&tied_payments(), PaymentSortOrder::NewestFirst, limit),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn last_page_has_no_cursor() {
+        let page = payment_page(tied_payments(), &query(PaymentSortOrder::OldestFirst, 7)).unwrap();
+        assert_eq!(page.payments.len(), 7);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn malformed_cursor_is_rejected() {
+        for cursor in ["", "10", "ten:0303", ":0303", "-1:0303"] {
+            let query = PaymentQuery {
+                cursor: Some(cursor.to_string()),
+                ..query(PaymentSortOrder::OldestFirst, 2)
+            };
+            assert!(payment_page(tied_payments(), &query).is_err(), "{}", cursor);
+        }
+    }
+
+    #[test]
+    fn zero_limit_is_rejected() {
+        assert!(payment_page(tied_payments(), &query(PaymentSortOrder::OldestFirst, 0)).is_err());
+    }
 }
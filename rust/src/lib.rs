@@ -1,3 +1,7 @@
 mod bridge_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
+mod export;
+mod fee_autopilot;
 mod ldk;
+mod payment;
+mod store;
 mod types;
@@ -0,0 +1,93 @@
+use crate::store::PaymentMetadata;
+use crate::types::RetryStrategy;
+use ldk_node::bitcoin::hashes::hex::ToHex;
+use ldk_node::bitcoin::secp256k1::PublicKey;
+use ldk_node::io::SqliteStore;
+use ldk_node::lightning::ln::PaymentHash;
+use ldk_node::lightning_invoice::Invoice;
+use ldk_node::Node;
+use std::time::{Duration, Instant};
+
+/// An outbound payment as requested by the user, kept around so it can be sent again.
+#[derive(Clone, Debug)]
+pub(crate) enum PaymentRequest {
+    Invoice {
+        invoice: Invoice,
+        amount_msat: Option<u64>,
+    },
+    Spontaneous {
+        amount_msat: u64,
+        node_id: PublicKey,
+    },
+}
+
+impl PaymentRequest {
+    pub(crate) fn send(&self, node: &Node<SqliteStore>) -> Result<PaymentHash, ldk_node::Error> {
+        match self {
+            PaymentRequest::Invoice {
+                invoice,
+                amount_msat: None,
+            } => node.send_payment(invoice),
+            PaymentRequest::Invoice {
+                invoice,
+                amount_msat: Some(amount_msat),
+            } => node.send_payment_using_amount(invoice, *amount_msat),
+            PaymentRequest::Spontaneous {
+                amount_msat,
+                node_id,
+            } => node.send_spontaneous_payment(*amount_msat, *node_id),
+        }
+    }
+
+    /// Whether every attempt is made with the same payment hash. Spontaneous payments use a new
+    /// preimage, and thus hash, on every attempt.
+    pub(crate) fn reuses_payment_hash(&self) -> bool {
+        matches!(self, PaymentRequest::Invoice { .. })
+    }
+
+    /// Whether the invoice to pay has expired, in which case sending it again is pointless.
+    pub(crate) fn is_expired(&self) -> bool {
+        match self {
+            PaymentRequest::Invoice { invoice, .. } => invoice.is_expired(),
+            PaymentRequest::Spontaneous { .. } => false,
+        }
+    }
+
+    pub(crate) fn record(&self, metadata: &mut PaymentMetadata) {
+        match self {
+            PaymentRequest::Invoice { invoice, .. } => {
+                metadata.set_invoice(invoice);
+                metadata.counterparty_node_id = Some(invoice.recover_payee_pub_key().to_hex());
+            }
+            PaymentRequest::Spontaneous { node_id, .. } => {
+                metadata.counterparty_node_id = Some(node_id.to_hex());
+            }
+        }
+    }
+}
+
+/// An outbound payment that is sent again according to its [RetryStrategy] when it fails.
+pub(crate) struct PaymentRetry {
+    pub request: PaymentRequest,
+    pub strategy: RetryStrategy,
+    pub attempts: u32,
+    pub started_at: Instant,
+}
+
+impl PaymentRetry {
+    pub(crate) fn new(request: PaymentRequest, strategy: RetryStrategy) -> Self {
+        PaymentRetry {
+            request,
+            strategy,
+            attempts: 1,
+            started_at: Instant::now(),
+        }
+    }
+
+    pub(crate) fn can_retry(&self) -> bool {
+        match self.strategy {
+            RetryStrategy::Attempts(e) => self.attempts <= e,
+            RetryStrategy::TimeoutSecs(e) => self.started_at.elapsed() < Duration::from_secs(e),
+        }
+    }
+}
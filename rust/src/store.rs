@@ -0,0 +1,433 @@
+use crate::fee_autopilot::FeeAutopilot;
+use crate::payment::PaymentRetry;
+use crate::types::InboundChannelPolicy;
+use ldk_node::bitcoin::hashes::hex::ToHex;
+use ldk_node::lightning::impl_writeable_tlv_based;
+use ldk_node::lightning::util::config::ChannelConfig;
+use ldk_node::lightning::util::ser::{Readable, Writeable};
+use ldk_node::lightning_invoice::{Invoice, InvoiceDescription};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The directory, relative to `Config.storageDirPath`, holding the data persisted by [NodeStore].
+pub(crate) const NODE_STORE_DIR: &str = "ldk_node_flutter";
+const PAYMENT_METADATA_NAMESPACE: &str = "payment_metadata";
+const ONCHAIN_PAYMENTS_NAMESPACE: &str = "onchain_payments";
+const CHANNEL_CONFIGS_NAMESPACE: &str = "channel_configs";
+const CHANNELS_NAMESPACE: &str = "channels";
+
+/// Returns the current time in seconds since the UNIX epoch.
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|x| x.as_secs())
+        .unwrap_or(0)
+}
+
+/// What we know about a payment on top of the `PaymentDetails` kept by LDK Node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PaymentMetadata {
+    pub hash: [u8; 32],
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub fee_paid_msat: Option<u64>,
+    pub bolt11: Option<String>,
+    pub description: Option<String>,
+    pub label: Option<String>,
+    pub counterparty_node_id: Option<String>,
+    /// How many times an outbound payment was sent, including retries.
+    pub attempts: Option<u32>,
+    /// When the user abandoned an outbound payment via `node.abandonPayment()`.
+    pub abandoned_at: Option<u64>,
+    /// The hashes of the retries of a spontaneous payment, oldest first. Every retry is sent with
+    /// a new hash, while the payment stays known by the hash of its first attempt.
+    pub retry_hashes: Vec<[u8; 32]>,
+    /// The hash of the first attempt, if this is a retry of a spontaneous payment.
+    pub original_hash: Option<[u8; 32]>,
+    /// LDK Node's record of an invoice payment, kept in case LDK Node lost it as retrying the
+    /// payment failed after the record had to be removed.
+    pub detached: Option<ldk_node::PaymentDetails>,
+}
+
+impl PaymentMetadata {
+    fn new(hash: [u8; 32]) -> Self {
+        let now = now_secs();
+        PaymentMetadata {
+            hash,
+            created_at: now,
+            updated_at: now,
+            fee_paid_msat: None,
+            bolt11: None,
+            description: None,
+            label: None,
+            counterparty_node_id: None,
+            attempts: None,
+            abandoned_at: None,
+            retry_hashes: Vec::new(),
+            original_hash: None,
+            detached: None,
+        }
+    }
+}
+
+impl PaymentMetadata {
+    /// Records the invoice a payment was made to or received for.
+    pub(crate) fn set_invoice(&mut self, invoice: &Invoice) {
+        self.bolt11 = Some(invoice.to_string());
+        self.description = match invoice.description() {
+            InvoiceDescription::Direct(e) => Some(e.clone().into_inner()),
+            InvoiceDescription::Hash(_) => None,
+        };
+    }
+}
+
+impl_writeable_tlv_based!(PaymentMetadata, {
+    (0, hash, required),
+    (2, created_at, required),
+    (4, updated_at, required),
+    (6, fee_paid_msat, option),
+    (8, bolt11, option),
+    (10, description, option),
+    (12, label, option),
+    (14, counterparty_node_id, option),
+    (16, attempts, option),
+    (18, abandoned_at, option),
+    (20, retry_hashes, vec_type),
+    (22, original_hash, option),
+    (24, detached, option),
+});
+
+/// An on-chain payment sent via `node.sendToOnchainAddress()` or
+/// `node.sendAllToOnchainAddress()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct OnchainPayment {
+    pub txid: String,
+    pub address: String,
+    /// `None` for payments sent via `node.sendAllToOnchainAddress()`, as LDK Node doesn't report
+    /// the amount sent then.
+    pub amount_sats: Option<u64>,
+    pub created_at: u64,
+}
+
+impl_writeable_tlv_based!(OnchainPayment, {
+    (0, txid, required),
+    (2, address, required),
+    (4, amount_sats, option),
+    (6, created_at, required),
+});
+
+/// The config last applied to a channel, as LDK Node 0.1.0 offers no way to read it back.
+struct ChannelConfigRecord {
+    user_channel_id: u128,
+    config: ChannelConfig,
+}
+
+impl_writeable_tlv_based!(ChannelConfigRecord, {
+    (0, user_channel_id, required),
+    (2, config, required),
+});
+
+/// The last known state of a channel, kept so we still know about it once LDK Node forgot
+/// about it after it was closed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ChannelRecord {
+    pub user_channel_id: u128,
+    pub channel_id: [u8; 32],
+    pub counterparty_node_id: String,
+    pub channel_value_sats: u64,
+    pub balance_msat: u64,
+    pub funding_txid: Option<String>,
+    pub funding_vout: Option<u32>,
+    pub opened_at: u64,
+    /// When we asked LDK Node to close the channel cooperatively.
+    pub close_requested_at: Option<u64>,
+    pub closed_at: Option<u64>,
+    /// When we closed the channel as it violated the inbound channel policy, in which case none
+    /// of its events are surfaced.
+    pub rejected_at: Option<u64>,
+}
+
+impl_writeable_tlv_based!(ChannelRecord, {
+    (0, user_channel_id, required),
+    (2, channel_id, required),
+    (4, counterparty_node_id, required),
+    (6, channel_value_sats, required),
+    (8, balance_msat, required),
+    (10, funding_txid, option),
+    (12, funding_vout, option),
+    (14, opened_at, required),
+    (16, close_requested_at, option),
+    (18, closed_at, option),
+    (20, rejected_at, option),
+});
+
+/// Persists the data this crate exposes on top of what LDK Node 0.1.0 stores itself, and holds
+/// the state and settings of the features we implement around it.
+///
+/// Every record is written to its own file below `<namespace>/<key>`, and the whole store is
+/// loaded into memory when the node is built.
+pub struct NodeStore {
+    pub(crate) inbound_channel_policy: Option<InboundChannelPolicy>,
+    pub(crate) fee_autopilot: Option<FeeAutopilot>,
+    dest_dir: PathBuf,
+    payments: Mutex<HashMap<[u8; 32], PaymentMetadata>>,
+    onchain_payments: Mutex<Vec<OnchainPayment>>,
+    channel_configs: Mutex<HashMap<u128, ChannelConfig>>,
+    channels: Mutex<HashMap<u128, ChannelRecord>>,
+    // Retries are kept in memory only and thus not resumed after a restart.
+    retries: Mutex<HashMap<[u8; 32], PaymentRetry>>,
+    last_event: Mutex<Option<ldk_node::Event>>,
+}
+
+impl NodeStore {
+    pub(crate) fn new(
+        dest_dir: PathBuf,
+        inbound_channel_policy: Option<InboundChannelPolicy>,
+        fee_autopilot: Option<FeeAutopilot>,
+    ) -> io::Result<Self> {
+        let payments = read_namespace::<PaymentMetadata>(&dest_dir, PAYMENT_METADATA_NAMESPACE)?
+            .into_iter()
+            .map(|x| (x.hash, x))
+            .collect();
+        let onchain_payments = read_namespace(&dest_dir, ONCHAIN_PAYMENTS_NAMESPACE)?;
+        let channel_configs =
+            read_namespace::<ChannelConfigRecord>(&dest_dir, CHANNEL_CONFIGS_NAMESPACE)?
+                .into_iter()
+                .map(|x| (x.user_channel_id, x.config))
+                .collect();
+        let channels = read_namespace::<ChannelRecord>(&dest_dir, CHANNELS_NAMESPACE)?
+            .into_iter()
+            .map(|x| (x.user_channel_id, x))
+            .collect();
+        Ok(NodeStore {
+            inbound_channel_policy,
+            fee_autopilot,
+            dest_dir,
+            payments: Mutex::new(payments),
+            onchain_payments: Mutex::new(onchain_payments),
+            channel_configs: Mutex::new(channel_configs),
+            channels: Mutex::new(channels),
+            retries: Mutex::new(HashMap::new()),
+            last_event: Mutex::new(None),
+        })
+    }
+
+    /// Returns `true` the first time the given event is passed in, and `false` while LDK Node
+    /// keeps handing out the same event until it is marked as handled.
+    pub(crate) fn is_new_event(&self, event: &ldk_node::Event) -> bool {
+        let mut last_event = self.last_event.lock().unwrap();
+        if last_event.as_ref() == Some(event) {
+            return false;
+        }
+        *last_event = Some(event.clone());
+        true
+    }
+
+    /// Returns the metadata of the payment with the given hash.
+    ///
+    /// Payments we haven't seen before, e.g. those made before the metadata was tracked, are
+    /// recorded as created now. That record is only kept in memory until the payment is updated,
+    /// so listing a large number of such payments doesn't write a file for each of them.
+    pub(crate) fn payment(&self, hash: &[u8; 32]) -> PaymentMetadata {
+        self.payments
+            .lock()
+            .unwrap()
+            .entry(*hash)
+            .or_insert_with(|| PaymentMetadata::new(*hash))
+            .clone()
+    }
+
+    /// Returns the metadata of the payment with the given hash, if we know about it.
+    pub(crate) fn find_payment(&self, hash: &[u8; 32]) -> Option<PaymentMetadata> {
+        self.payments.lock().unwrap().get(hash).cloned()
+    }
+
+    /// Returns the records of the payments LDK Node lost while they were retried.
+    pub(crate) fn detached_payments(&self) -> Vec<ldk_node::PaymentDetails> {
+        self.payments
+            .lock()
+            .unwrap()
+            .values()
+            .filter_map(|x| x.detached.clone())
+            .collect()
+    }
+
+    /// Applies `update` to the metadata of the payment with the given hash, bumps its
+    /// `updated_at` and persists the result.
+    pub(crate) fn update_payment<F: FnOnce(&mut PaymentMetadata)>(
+        &self,
+        hash: &[u8; 32],
+        update: F,
+    ) -> io::Result<PaymentMetadata> {
+        let mut payments = self.payments.lock().unwrap();
+        let mut metadata = payments
+            .get(hash)
+            .cloned()
+            .unwrap_or_else(|| PaymentMetadata::new(*hash));
+        update(&mut metadata);
+        metadata.updated_at = now_secs();
+        self.write(PAYMENT_METADATA_NAMESPACE, &hash.to_hex(), &metadata)?;
+        payments.insert(*hash, metadata.clone());
+        Ok(metadata)
+    }
+
+    pub(crate) fn remove_payment(&self, hash: &[u8; 32]) -> io::Result<()> {
+        let mut payments = self.payments.lock().unwrap();
+        self.remove(PAYMENT_METADATA_NAMESPACE, &hash.to_hex())?;
+        payments.remove(hash);
+        Ok(())
+    }
+
+    pub(crate) fn onchain_payments(&self) -> Vec<OnchainPayment> {
+        self.onchain_payments.lock().unwrap().clone()
+    }
+
+    pub(crate) fn insert_onchain_payment(&self, payment: OnchainPayment) -> io::Result<()> {
+        let mut onchain_payments = self.onchain_payments.lock().unwrap();
+        self.write(ONCHAIN_PAYMENTS_NAMESPACE, &payment.txid, &payment)?;
+        onchain_payments.push(payment);
+        Ok(())
+    }
+
+    /// Returns the config last applied to the channel with the given user channel id, or `None`
+    /// if it was never set via this package and thus is unknown.
+    pub(crate) fn channel_config(&self, user_channel_id: u128) -> Option<ChannelConfig> {
+        self.channel_configs
+            .lock()
+            .unwrap()
+            .get(&user_channel_id)
+            .cloned()
+    }
+
+    pub(crate) fn set_channel_config(
+        &self,
+        user_channel_id: u128,
+        config: ChannelConfig,
+    ) -> io::Result<()> {
+        let mut channel_configs = self.channel_configs.lock().unwrap();
+        let record = ChannelConfigRecord {
+            user_channel_id,
+            config,
+        };
+        self.write(
+            CHANNEL_CONFIGS_NAMESPACE,
+            &user_channel_id.to_string(),
+            &record,
+        )?;
+        channel_configs.insert(user_channel_id, record.config);
+        Ok(())
+    }
+
+    /// Records the current state of the given open channels.
+    pub(crate) fn update_channels(&self, open_channels: &[ldk_node::ChannelDetails]) {
+        let mut channels = self.channels.lock().unwrap();
+        for channel in open_channels {
+            let user_channel_id = channel.user_channel_id.0;
+            let previous = channels.get(&user_channel_id);
+            let record = ChannelRecord {
+                user_channel_id,
+                channel_id: channel.channel_id.0,
+                counterparty_node_id: channel.counterparty_node_id.to_hex(),
+                channel_value_sats: channel.channel_value_sats,
+                balance_msat: channel.balance_msat,
+                funding_txid: channel.funding_txo.map(|x| x.txid.to_string()),
+                funding_vout: channel.funding_txo.map(|x| x.vout),
+                opened_at: previous.map_or_else(now_secs, |x| x.opened_at),
+                close_requested_at: previous.and_then(|x| x.close_requested_at),
+                closed_at: None,
+                rejected_at: previous.and_then(|x| x.rejected_at),
+            };
+            if previous == Some(&record) {
+                continue;
+            }
+            let key = user_channel_id.to_string();
+            if let Err(e) = self.write(CHANNELS_NAMESPACE, &key, &record) {
+                log::error!("Failed to persist channel: {e}");
+            }
+            channels.insert(user_channel_id, record);
+        }
+    }
+
+    /// Updates the record of the channel with the given user channel id, if we know about it.
+    pub(crate) fn update_channel<F: FnOnce(&mut ChannelRecord)>(
+        &self,
+        user_channel_id: u128,
+        update: F,
+    ) -> io::Result<()> {
+        let mut channels = self.channels.lock().unwrap();
+        let mut record = match channels.get(&user_channel_id) {
+            Some(e) => e.clone(),
+            None => return Ok(()),
+        };
+        update(&mut record);
+        self.write(CHANNELS_NAMESPACE, &user_channel_id.to_string(), &record)?;
+        channels.insert(user_channel_id, record);
+        Ok(())
+    }
+
+    pub(crate) fn channel(&self, user_channel_id: u128) -> Option<ChannelRecord> {
+        self.channels.lock().unwrap().get(&user_channel_id).cloned()
+    }
+
+    pub(crate) fn closed_channels(&self) -> Vec<ChannelRecord> {
+        self.channels
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|x| x.closed_at.is_some())
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn insert_retry(&self, hash: [u8; 32], retry: PaymentRetry) {
+        self.retries.lock().unwrap().insert(hash, retry);
+    }
+
+    pub(crate) fn take_retry(&self, hash: &[u8; 32]) -> Option<PaymentRetry> {
+        self.retries.lock().unwrap().remove(hash)
+    }
+
+    fn write<W: Writeable>(&self, namespace: &str, key: &str, value: &W) -> io::Result<()> {
+        let dir = self.dest_dir.join(namespace);
+        fs::create_dir_all(&dir)?;
+        // Write to a temporary file first so a crash never leaves a truncated record behind.
+        let tmp_path = dir.join(format!("{key}.tmp"));
+        fs::write(&tmp_path, value.encode())?;
+        fs::rename(tmp_path, dir.join(key))
+    }
+
+    fn remove(&self, namespace: &str, key: &str) -> io::Result<()> {
+        match fs::remove_file(self.dest_dir.join(namespace).join(key)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            res => res,
+        }
+    }
+}
+
+fn read_namespace<R: Readable>(dest_dir: &Path, namespace: &str) -> io::Result<Vec<R>> {
+    let dir = dest_dir.join(namespace);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut values = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().map_or(false, |x| x == "tmp") {
+            continue;
+        }
+        let mut reader = Cursor::new(fs::read(&path)?);
+        let value = R::read(&mut reader).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to decode {}: {e:?}", path.display()),
+            )
+        })?;
+        values.push(value);
+    }
+    Ok(values)
+}
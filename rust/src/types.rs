@@ -1,12 +1,17 @@
+use crate::store::{ChannelRecord, PaymentMetadata};
 pub use anyhow::anyhow;
 use flutter_rust_bridge::*;
 use ldk_node::bitcoin::hashes::hex::ToHex;
+use ldk_node::lightning::ln::msgs::NetAddress as LdkNetAddress;
+use ldk_node::lightning::util::ser::Hostname;
 pub use ldk_node::Node;
-use std::net::SocketAddr;
+use std::convert::{TryFrom, TryInto};
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 use std::string::ToString;
 
 ///Options which apply on a per-channel basis and may change at runtime or based on negotiation with our counterparty.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChannelConfig {
     ///Amount (in millionths of a satoshi) charged per satoshi for payments forwarded outbound over the channel. This may be allowed to change at runtime in a later update, however doing so must result in update messages sent to notify all nodes of our updated relay fee.
     ///
@@ -55,6 +60,140 @@ impl From<ChannelConfig> for ldk_node::lightning::util::config::ChannelConfig {
         }
     }
 }
+impl From<ldk_node::lightning::util::config::ChannelConfig> for ChannelConfig {
+    fn from(x: ldk_node::lightning::util::config::ChannelConfig) -> Self {
+        ChannelConfig {
+            forwarding_fee_proportional_millionths: x.forwarding_fee_proportional_millionths,
+            forwarding_fee_base_msat: x.forwarding_fee_base_msat,
+            cltv_expiry_delta: x.cltv_expiry_delta,
+            max_dust_htlc_exposure_msat: x.max_dust_htlc_exposure_msat,
+            force_close_avoidance_max_fee_satoshis: x.force_close_avoidance_max_fee_satoshis,
+        }
+    }
+}
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        ldk_node::lightning::util::config::ChannelConfig::default().into()
+    }
+}
+
+/// A change to some of the fields of a channel's `ChannelConfig`. Fields left `null` keep their
+/// current value.
+///
+/// Channels whose config is unknown, see `node.channelConfig()`, only accept an update setting
+/// all fields.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChannelConfigUpdate {
+    /// The new `forwardingFeeProportionalMillionths`.
+    ///
+    pub forwarding_fee_proportional_millionths: Option<u32>,
+    /// The new `forwardingFeeBaseMsat`.
+    ///
+    pub forwarding_fee_base_msat: Option<u32>,
+    /// The new `cltvExpiryDelta`.
+    ///
+    pub cltv_expiry_delta: Option<u16>,
+    /// The new `maxDustHtlcExposureMsat`.
+    ///
+    pub max_dust_htlc_exposure_msat: Option<u64>,
+    /// The new `forceCloseAvoidanceMaxFeeSatoshis`.
+    ///
+    pub force_close_avoidance_max_fee_satoshis: Option<u64>,
+}
+
+impl ChannelConfigUpdate {
+    /// Whether all fields are set, i.e. the update replaces the whole config.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.forwarding_fee_proportional_millionths.is_some()
+            && self.forwarding_fee_base_msat.is_some()
+            && self.cltv_expiry_delta.is_some()
+            && self.max_dust_htlc_exposure_msat.is_some()
+            && self.force_close_avoidance_max_fee_satoshis.is_some()
+    }
+
+    pub(crate) fn apply(&self, config: &mut ldk_node::lightning::util::config::ChannelConfig) {
+        if let Some(e) = self.forwarding_fee_proportional_millionths {
+            config.forwarding_fee_proportional_millionths = e;
+        }
+        if let Some(e) = self.forwarding_fee_base_msat {
+            config.forwarding_fee_base_msat = e;
+        }
+        if let Some(e) = self.cltv_expiry_delta {
+            config.cltv_expiry_delta = e;
+        }
+        if let Some(e) = self.max_dust_htlc_exposure_msat {
+            config.max_dust_htlc_exposure_msat = e;
+        }
+        if let Some(e) = self.force_close_avoidance_max_fee_satoshis {
+            config.force_close_avoidance_max_fee_satoshis = e;
+        }
+    }
+}
+
+/// Selects the channels updated by `node.updateAllChannelConfigs()`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelFilter {
+    /// All channels.
+    ///
+    All,
+    /// All channels with the given counterparty.
+    ///
+    Counterparty(PublicKey),
+    /// All announced or unannounced channels, respectively.
+    ///
+    Visibility(ChannelVisibility),
+}
+
+/// A [ChannelFilter] with the node ID of a `Counterparty` filter parsed, so an invalid one fails
+/// once upfront rather than silently matching no channel.
+pub(crate) enum ChannelMatcher {
+    All,
+    Counterparty(ldk_node::bitcoin::secp256k1::PublicKey),
+    Visibility(ChannelVisibility),
+}
+
+impl TryFrom<ChannelFilter> for ChannelMatcher {
+    type Error = anyhow::Error;
+
+    fn try_from(value: ChannelFilter) -> Result<Self, Self::Error> {
+        Ok(match value {
+            ChannelFilter::All => ChannelMatcher::All,
+            ChannelFilter::Counterparty(e) => ChannelMatcher::Counterparty(
+                ldk_node::bitcoin::secp256k1::PublicKey::from_str(e.internal.as_str())
+                    .map_err(|_| anyhow!("Invalid counterparty node ID: {}", e.internal))?,
+            ),
+            ChannelFilter::Visibility(e) => ChannelMatcher::Visibility(e),
+        })
+    }
+}
+
+impl ChannelMatcher {
+    pub(crate) fn matches(&self, channel: &ldk_node::ChannelDetails) -> bool {
+        match self {
+            ChannelMatcher::All => true,
+            ChannelMatcher::Counterparty(e) => *e == channel.counterparty_node_id,
+            ChannelMatcher::Visibility(ChannelVisibility::Public) => channel.is_public,
+            ChannelMatcher::Visibility(ChannelVisibility::Private) => !channel.is_public,
+        }
+    }
+}
+
+/// The outcome of updating the config of a single channel via `node.updateAllChannelConfigs()`.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelConfigUpdateResult {
+    /// The channel's ID.
+    ///
+    pub channel_id: ChannelId,
+    ///The node ID of our the channel’s counterparty.
+    pub counterparty_node_id: PublicKey,
+    /// Why the update failed, or `null` if it succeeded.
+    ///
+    pub error: Option<String>,
+}
+
 /// The global identifier of a channel.
 ///
 /// Note that this will start out to be a temporary ID until channel funding negotiation is
@@ -234,6 +373,9 @@ pub enum PaymentStatus {
     Succeeded,
     /// The payment failed.
     Failed,
+    /// The payment was abandoned via `node.abandonPayment()` and will be marked as failed once
+    /// all of its HTLCs are resolved.
+    Abandoning,
 }
 
 impl From<ldk_node::PaymentStatus> for PaymentStatus {
@@ -274,6 +416,32 @@ impl From<PaymentDirection> for ldk_node::PaymentDirection {
     }
 }
 
+/// Represents the kind of a payment.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PaymentKind {
+    /// A payment to or from a BOLT11 invoice.
+    Bolt11,
+    /// A spontaneous, aka. "keysend", payment.
+    Spontaneous,
+}
+
+/// How an outbound payment is retried after it failed.
+///
+/// Each attempt is itself retried along different paths by LDK for a few seconds; a strategy
+/// sends the whole payment again once LDK gave up on an attempt. Only the outcome of the last
+/// attempt is reported as an [Event].
+///
+/// **Note:** retries are not resumed after the node is restarted.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RetryStrategy {
+    /// Send the payment again up to this many times after the first attempt failed.
+    Attempts(u32),
+    /// Keep sending the payment again until this many seconds passed since the first attempt.
+    TimeoutSecs(u64),
+}
+
 /// paymentHash type, use to cross-lock hop
 ///
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -305,6 +473,9 @@ pub struct PaymentDetails {
     /// The payment hash, i.e., the hash of the `preimage`.
     pub hash: PaymentHash,
     /// The pre-image used by the payment.
+    ///
+    /// For a retried spontaneous payment, this is the pre-image of its latest attempt, while
+    /// `hash` stays the hash of its first attempt.
     pub preimage: Option<PaymentPreimage>,
     /// The secret used by the payment.
     pub secret: Option<PaymentSecret>,
@@ -314,23 +485,196 @@ pub struct PaymentDetails {
     pub direction: PaymentDirection,
     /// The status of the payment.
     pub status: PaymentStatus,
+    /// The kind of the payment.
+    pub kind: PaymentKind,
+    /// How many times an outbound payment was sent, including retries. Always 0 for inbound
+    /// payments.
+    pub attempts: u32,
+    /// The time the payment was created, in seconds since the UNIX epoch.
+    ///
+    /// For payments made before this was tracked, this is the time the payment was first seen.
+    /// It is only kept across restarts once the payment is updated, e.g. by setting its label.
+    pub created_at: u64,
+    /// The time the payment was last updated, in seconds since the UNIX epoch.
+    pub updated_at: u64,
+    /// The routing fee paid for an outbound payment, if known.
+    ///
+    /// **Note:** LDK Node 0.1.0 doesn't report the fees paid, so this is currently always `null`.
+    pub fee_paid_msat: Option<u64>,
+    /// The BOLT11 invoice this payment was made to or received for, if any.
+    pub bolt11: Option<Invoice>,
+    /// The description of the invoice, if it had a plain text description.
+    pub description: Option<String>,
+    /// A user-provided label, as set via `node.setPaymentLabel()`.
+    pub label: Option<String>,
+    /// The node ID of the counterparty, if known.
+    ///
+    /// This is the payee for outbound payments, and unknown for inbound ones.
+    pub counterparty_node_id: Option<PublicKey>,
 }
 
-impl From<ldk_node::PaymentDetails> for PaymentDetails {
-    fn from(value: ldk_node::PaymentDetails) -> Self {
+impl PaymentDetails {
+    pub(crate) fn new(value: ldk_node::PaymentDetails, metadata: PaymentMetadata) -> Self {
         PaymentDetails {
             hash: PaymentHash {
                 internal: value.hash.0,
             },
             preimage: value.preimage.map(|x| PaymentPreimage { internal: x.0 }),
             secret: value.secret.map(|x| PaymentSecret { internal: x.0 }),
-            status: value.status.into(),
+            status: match (value.status, metadata.abandoned_at) {
+                (ldk_node::PaymentStatus::Pending, Some(_)) => PaymentStatus::Abandoning,
+                (e, _) => e.into(),
+            },
             amount_msat: value.amount_msat,
             direction: value.direction.into(),
+            kind: match value.secret {
+                Some(_) => PaymentKind::Bolt11,
+                None => PaymentKind::Spontaneous,
+            },
+            attempts: match value.direction {
+                ldk_node::PaymentDirection::Inbound => 0,
+                ldk_node::PaymentDirection::Outbound => metadata.attempts.unwrap_or(1),
+            },
+            created_at: metadata.created_at,
+            updated_at: metadata.updated_at,
+            fee_paid_msat: metadata.fee_paid_msat,
+            bolt11: metadata.bolt11.map(|x| Invoice { internal: x }),
+            description: metadata.description,
+            label: metadata.label,
+            counterparty_node_id: metadata
+                .counterparty_node_id
+                .map(|x| PublicKey { internal: x }),
         }
     }
 }
 
+/// The order in which `node.queryPayments()` returns payments.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PaymentSortOrder {
+    /// Most recently created payments first.
+    NewestFirst,
+    /// Least recently created payments first.
+    OldestFirst,
+}
+
+/// Criteria for `node.queryPayments()`. A payment is returned if it matches all of the criteria
+/// that are set.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentQuery {
+    /// Only return payments in this direction.
+    pub direction: Option<PaymentDirection>,
+    /// Only return payments with one of these statuses. An empty list matches any status.
+    pub statuses: Vec<PaymentStatus>,
+    /// Only return payments created at or after this time, in seconds since the UNIX epoch.
+    pub created_from: Option<u64>,
+    /// Only return payments created before this time, in seconds since the UNIX epoch.
+    pub created_until: Option<u64>,
+    /// Only return payments of at least this amount. Payments of unknown amount never match.
+    pub min_amount_msat: Option<u64>,
+    /// Only return payments of at most this amount. Payments of unknown amount never match.
+    pub max_amount_msat: Option<u64>,
+    /// Only return payments whose label contains this text, ignoring case.
+    pub label_contains: Option<String>,
+    /// Only return payments of this kind.
+    pub kind: Option<PaymentKind>,
+    /// The order in which payments are returned.
+    pub sort_order: PaymentSortOrder,
+    /// The `nextCursor` of the previous page, or `null` to start from the first page.
+    pub cursor: Option<String>,
+    /// The maximum number of payments returned in one page, or `null` to return all of them. Must
+    /// be greater than 0.
+    pub limit: Option<u32>,
+}
+
+impl PaymentQuery {
+    pub(crate) fn matches(&self, payment: &PaymentDetails) -> bool {
+        if self.direction.map_or(false, |x| x != payment.direction) {
+            return false;
+        }
+        if !self.statuses.is_empty() && !self.statuses.contains(&payment.status) {
+            return false;
+        }
+        if self.created_from.map_or(false, |x| payment.created_at < x)
+            || self
+                .created_until
+                .map_or(false, |x| payment.created_at >= x)
+        {
+            return false;
+        }
+        if self.min_amount_msat.is_some() || self.max_amount_msat.is_some() {
+            let amount_msat = match payment.amount_msat {
+                Some(e) => e,
+                None => return false,
+            };
+            if self.min_amount_msat.map_or(false, |x| amount_msat < x)
+                || self.max_amount_msat.map_or(false, |x| amount_msat > x)
+            {
+                return false;
+            }
+        }
+        if let Some(text) = &self.label_contains {
+            let text = text.to_lowercase();
+            match &payment.label {
+                Some(e) if e.to_lowercase().contains(&text) => {}
+                _ => return false,
+            }
+        }
+        self.kind.map_or(true, |x| x == payment.kind)
+    }
+}
+
+/// A page of payments, as returned by `node.queryPayments()`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentPage {
+    /// The payments on this page.
+    pub payments: Vec<PaymentDetails>,
+    /// The cursor to pass in `PaymentQuery.cursor` to retrieve the next page, or `null` if this
+    /// is the last page.
+    pub next_cursor: Option<String>,
+    /// The total number of payments matching the query, across all pages.
+    pub total_count: u64,
+}
+
+/// The format of `node.exportPayments()`.
+///
+/// Both formats contain one record per Lightning payment or on-chain transaction, oldest first,
+/// with the following fields in this order:
+///
+/// - `created_at`: when the payment was created, in seconds since the UNIX epoch.
+/// - `updated_at`: when the payment was last updated, in seconds since the UNIX epoch.
+/// - `kind`: `bolt11`, `spontaneous` or `onchain`.
+/// - `direction`: `inbound` or `outbound`.
+/// - `amount_msat`: the amount in millisatoshis, empty if unknown. This is the case for on-chain
+/// transactions sent via `node.sendAllToOnchainAddress()`, as LDK Node 0.1.0 doesn't report the
+/// amount sent.
+/// - `fee_paid_msat`: the fee paid in millisatoshis, empty if unknown.
+/// - `status`: `pending`, `succeeded`, `failed` or `abandoning`, and `broadcast` for on-chain
+/// transactions.
+/// - `payment_hash`: the hex-encoded payment hash of Lightning payments.
+/// - `txid`: the transaction ID of on-chain transactions.
+/// - `address`: the destination address of on-chain transactions.
+/// - `label`: the label set via `node.setPaymentLabel()`.
+/// - `description`: the invoice description of Lightning payments.
+///
+/// **Note:** LDK Node 0.1.0 doesn't expose the transactions of its on-chain wallet, so only
+/// on-chain payments sent via `node.sendToOnchainAddress()` and `node.sendAllToOnchainAddress()`
+/// are included.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row. Empty fields are left blank.
+    ///
+    /// Text fields starting with `=`, `+`, `-`, `@`, a tab or a carriage return are prefixed with
+    /// a `'`, so spreadsheet software doesn't evaluate labels or invoice descriptions chosen by
+    /// a counterparty as formulas.
+    Csv,
+    /// A JSON array of objects keyed by field name. Empty fields are `null`.
+    Json,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 ///Represents a syntactically and semantically correct lightning BOLT11 invoice.
 ///
@@ -344,6 +688,7 @@ impl From<Invoice> for ldk_node::lightning_invoice::Invoice {
             .expect("Invalid Invoice")
     }
 }
+
 ///A Secp256k1 public key, used for verification of signatures.
 ///
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -366,6 +711,7 @@ impl From<ldk_node::bitcoin::secp256k1::PublicKey> for PublicKey {
 }
 /// A Bitcoin address.
 ///
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Address {
     pub internal: String,
 }
@@ -391,7 +737,7 @@ pub struct ChannelDetails {
     /// The Channel's funding transaction output, if we've negotiated the funding transaction with
     /// our counterparty already.
     ///
-    pub funding_txo: Option<String>,
+    pub funding_txo: Option<OutPoint>,
     ///The value, in satoshis, of this channel as it appears in the funding output.
     pub channel_value_sats: u64,
     /// The value, in satoshis, that must always be held in the channel for us. This value ensures
@@ -474,13 +820,22 @@ pub struct ChannelDetails {
     /// True if this channel is (or will be) publicly-announced.
     ///
     pub is_public: bool,
+    /// The channel's config, as set on channel open or via `node.updateChannelConfig()`.
+    ///
+    /// This is `null` for channels whose config was never set via this package, as LDK Node 0.1.0
+    /// offers no way to read it back.
+    ///
+    pub config: Option<ChannelConfig>,
 }
-impl From<&ldk_node::ChannelDetails> for ChannelDetails {
-    fn from(value: &ldk_node::ChannelDetails) -> Self {
+impl ChannelDetails {
+    pub(crate) fn new(
+        value: &ldk_node::ChannelDetails,
+        config: Option<ldk_node::lightning::util::config::ChannelConfig>,
+    ) -> Self {
         ChannelDetails {
             channel_id: value.clone().channel_id.into(),
             counterparty_node_id: value.clone().counterparty_node_id.into(),
-            funding_txo: value.clone().funding_txo.map(|x| x.txid.to_string()),
+            funding_txo: value.clone().funding_txo.map(|x| x.into()),
             channel_value_sats: value.clone().channel_value_sats,
             unspendable_punishment_reserve: value.clone().unspendable_punishment_reserve,
             user_channel_id: value.clone().user_channel_id.into(),
@@ -494,6 +849,92 @@ impl From<&ldk_node::ChannelDetails> for ChannelDetails {
             is_channel_ready: value.clone().is_channel_ready,
             is_usable: value.clone().is_usable,
             is_public: value.clone().is_public,
+            config: config.map(|x| x.into()),
+        }
+    }
+}
+
+/// How a channel was closed.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChannelCloseType {
+    /// We closed the channel cooperatively via `node.closeChannel()`, or because it violated the
+    /// `InboundChannelPolicy`.
+    ///
+    LocalCooperative,
+    /// The channel was closed by the counterparty or force-closed. LDK Node 0.1.0 doesn't report
+    /// how.
+    ///
+    Unknown,
+}
+
+/// Details of a closed channel, as returned by node.listClosedChannels()
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClosedChannelDetails {
+    /// The channel's ID.
+    ///
+    pub channel_id: ChannelId,
+    /// The userChannelId of the channel.
+    ///
+    pub user_channel_id: UserChannelId,
+    ///The node ID of our the channel’s counterparty.
+    pub counterparty_node_id: PublicKey,
+    ///The value, in satoshis, of this channel as it appears in the funding output.
+    pub channel_value_sats: u64,
+    /// Our last known balance in the channel before it was closed.
+    ///
+    pub final_balance_msat: u64,
+    /// The Channel's funding transaction output, if it was negotiated before the channel closed.
+    ///
+    pub funding_txo: Option<OutPoint>,
+    /// The ID of the transaction that closed the channel, if known.
+    ///
+    /// **Note:** LDK Node 0.1.0 doesn't report the closing transaction, so this is currently
+    /// always `null`.
+    ///
+    pub closing_txid: Option<Txid>,
+    /// How the channel was closed.
+    ///
+    pub close_type: ChannelCloseType,
+    /// When the channel was opened, in seconds since the UNIX epoch.
+    ///
+    /// For channels opened before this was tracked, this is the time the channel was first seen.
+    ///
+    pub opened_at: u64,
+    /// When the channel was closed, in seconds since the UNIX epoch.
+    ///
+    pub closed_at: u64,
+}
+
+impl From<ChannelRecord> for ClosedChannelDetails {
+    fn from(value: ChannelRecord) -> Self {
+        ClosedChannelDetails {
+            channel_id: ChannelId {
+                internal: value.channel_id,
+            },
+            user_channel_id: UserChannelId {
+                internal: value.user_channel_id as u64,
+            },
+            counterparty_node_id: PublicKey {
+                internal: value.counterparty_node_id,
+            },
+            channel_value_sats: value.channel_value_sats,
+            final_balance_msat: value.balance_msat,
+            funding_txo: value
+                .funding_txid
+                .zip(value.funding_vout)
+                .map(|(txid, vout)| OutPoint {
+                    txid: Txid { internal: txid },
+                    vout,
+                }),
+            closing_txid: None,
+            close_type: match value.close_requested_at {
+                Some(_) => ChannelCloseType::LocalCooperative,
+                None => ChannelCloseType::Unknown,
+            },
+            opened_at: value.opened_at,
+            closed_at: value.closed_at.unwrap_or_default(),
         }
     }
 }
@@ -555,30 +996,104 @@ impl From<ldk_node::PeerDetails> for PeerDetails {
 ///
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NetAddress {
+    ///An IPv4 address/port on which the peer is listening.
+    ///
     IPv4 { addr: String, port: u16 },
+    ///An IPv6 address/port on which the peer is listening.
+    ///
     IPv6 { addr: String, port: u16 },
+    ///An old-style Tor onion address/port on which the peer is listening.
+    ///
+    /// The 12 bytes are the 10 byte onion address followed by the big-endian port. This field is deprecated and the Tor network generally no longer supports V2 Onion addresses.
+    OnionV2 { addr: [u8; 12] },
+    ///A new-style Tor onion address/port on which the peer is listening.
+    ///
+    /// To create the human-readable "hostname", concatenate the ED25519 pubkey, checksum, and version, wrap as base32 and append ".onion".
+    OnionV3 {
+        ///The ed25519 long-term public key of the peer.
+        ///
+        pubkey: [u8; 32],
+        ///The checksum of the pubkey and version, as included in the onion address.
+        ///
+        checksum: u16,
+        ///The version byte, as defined by the Tor Onion v3 spec.
+        ///
+        version: u8,
+        ///The port on which the node is listening.
+        ///
+        port: u16,
+    },
+    ///A hostname/port on which the peer is listening.
+    ///
+    Hostname { host: String, port: u16 },
 }
 
-impl From<NetAddress> for ldk_node::NetAddress {
-    fn from(value: NetAddress) -> Self {
-        match value {
-            NetAddress::IPv4 { addr, port } => {
-                ldk_node::NetAddress::from_str(&*format!("{addr}:{port}"))
-                    .expect("Invalid IPv4 address")
-            }
-            NetAddress::IPv6 { addr, port } => {
-                ldk_node::NetAddress::from_str(&*format!("{addr}:{port}"))
-                    .expect("Invalid IPv6 address")
-            }
-        }
+impl TryFrom<NetAddress> for ldk_node::NetAddress {
+    type Error = anyhow::Error;
+
+    fn try_from(value: NetAddress) -> Result<Self, Self::Error> {
+        let address = match value {
+            NetAddress::IPv4 { addr, port } => LdkNetAddress::IPv4 {
+                addr: Ipv4Addr::from_str(addr.as_str())
+                    .map_err(|_| anyhow!("Invalid IPv4 address: {addr}"))?
+                    .octets(),
+                port,
+            },
+            NetAddress::IPv6 { addr, port } => LdkNetAddress::IPv6 {
+                addr: Ipv6Addr::from_str(addr.as_str())
+                    .map_err(|_| anyhow!("Invalid IPv6 address: {addr}"))?
+                    .octets(),
+                port,
+            },
+            NetAddress::OnionV2 { addr } => LdkNetAddress::OnionV2(addr),
+            NetAddress::OnionV3 {
+                pubkey,
+                checksum,
+                version,
+                port,
+            } => LdkNetAddress::OnionV3 {
+                ed25519_pubkey: pubkey,
+                checksum,
+                version,
+                port,
+            },
+            NetAddress::Hostname { host, port } => LdkNetAddress::Hostname {
+                hostname: Hostname::try_from(host.clone())
+                    .map_err(|_| anyhow!("Invalid hostname: {host}"))?,
+                port,
+            },
+        };
+        Ok(ldk_node::NetAddress(address))
     }
 }
 impl From<ldk_node::NetAddress> for NetAddress {
     fn from(value: ldk_node::NetAddress) -> Self {
-        let addr: SocketAddr = value.to_string().parse().unwrap();
-        let host = addr.ip().to_string();
-        let port = addr.port();
-        NetAddress::IPv4 { addr: host, port }
+        match value.0 {
+            LdkNetAddress::IPv4 { addr, port } => NetAddress::IPv4 {
+                addr: Ipv4Addr::from(addr).to_string(),
+                port,
+            },
+            LdkNetAddress::IPv6 { addr, port } => NetAddress::IPv6 {
+                addr: Ipv6Addr::from(addr).to_string(),
+                port,
+            },
+            LdkNetAddress::OnionV2(addr) => NetAddress::OnionV2 { addr },
+            LdkNetAddress::OnionV3 {
+                ed25519_pubkey,
+                checksum,
+                version,
+                port,
+            } => NetAddress::OnionV3 {
+                pubkey: ed25519_pubkey,
+                checksum,
+                version,
+                port,
+            },
+            LdkNetAddress::Hostname { hostname, port } => NetAddress::Hostname {
+                host: hostname.to_string(),
+                port,
+            },
+        }
     }
 }
 
@@ -619,12 +1134,14 @@ impl From<LogLevel> for ldk_node::LogLevel {
     }
 }
 
-impl From<Config> for ldk_node::Config {
-    fn from(value: Config) -> Self {
-        ldk_node::Config {
+impl TryFrom<Config> for ldk_node::Config {
+    type Error = anyhow::Error;
+
+    fn try_from(value: Config) -> Result<Self, Self::Error> {
+        Ok(ldk_node::Config {
             storage_dir_path: value.storage_dir_path,
             network: value.network.into(),
-            listening_address: value.listening_address.map(|x| x.into()),
+            listening_address: value.listening_address.map(|x| x.try_into()).transpose()?,
             default_cltv_expiry_delta: value.default_cltv_expiry_delta,
             onchain_wallet_sync_interval_secs: value.onchain_wallet_sync_interval_secs,
             wallet_sync_interval_secs: value.wallet_sync_interval_secs,
@@ -635,7 +1152,7 @@ impl From<Config> for ldk_node::Config {
                 .map(|x| x.into())
                 .collect(),
             log_level: value.log_level.into(),
-        }
+        })
     }
 }
 
@@ -685,6 +1202,180 @@ pub struct Config {
     ///
     #[frb(non_final)]
     pub default_cltv_expiry_delta: u32,
+    /// The policy inbound channels have to comply with, or `null` to accept all of them.
+    ///
+    #[frb(non_final)]
+    pub inbound_channel_policy: Option<InboundChannelPolicy>,
+    /// The settings of the fee autopilot, or `null` to leave forwarding fees alone.
+    ///
+    #[frb(non_final)]
+    pub fee_autopilot: Option<FeeAutopilotConfig>,
+}
+
+/// Settings of the fee autopilot, which periodically adjusts the forwarding fees of our public
+/// channels to their balance while the node is running.
+///
+/// A channel holding less than the target share of its capacity on our side gets higher fees,
+/// up to the maximums for a drained channel, to discourage forwarding through it. A channel
+/// holding more gets lower fees, down to the minimums for a channel with all of its capacity on
+/// our side. At the target share, fees are halfway between the minimums and maximums.
+///
+/// A channel's fees are only changed once its base or proportional fee would move by at least
+/// 10% of its current value, so small shifts in balance don't broadcast a channel update.
+///
+/// Channels whose config is unknown, see `node.channelConfig()`, are left alone, as updating
+/// their fees would overwrite the rest of their config with guessed values. Set their config via
+/// `node.updateChannelConfig()` once to put them under the autopilot.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeAutopilotConfig {
+    /// The lowest base fee the autopilot sets.
+    ///
+    pub min_base_fee_msat: u32,
+    /// The highest base fee the autopilot sets.
+    ///
+    pub max_base_fee_msat: u32,
+    /// The lowest proportional fee the autopilot sets.
+    ///
+    pub min_proportional_millionths: u32,
+    /// The highest proportional fee the autopilot sets.
+    ///
+    pub max_proportional_millionths: u32,
+    /// The share of a channel's capacity we aim to hold on our side, in percent. Must be between
+    /// 1 and 99.
+    ///
+    pub target_outbound_percent: u8,
+    /// The time in-between fee adjustments, in seconds.
+    /// Note: A minimum of 60 seconds is always enforced.
+    ///
+    pub interval_secs: u64,
+    /// If set, fees are never changed automatically and the proposed changes can only be
+    /// retrieved via `node.feeAutopilotProposals()`.
+    ///
+    pub dry_run: bool,
+}
+
+impl FeeAutopilotConfig {
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        if self.min_base_fee_msat > self.max_base_fee_msat
+            || self.min_proportional_millionths > self.max_proportional_millionths
+        {
+            return Err(anyhow!(
+                "Fee autopilot minimum fees must not exceed maximum fees"
+            ));
+        }
+        if !(1..=99).contains(&self.target_outbound_percent) {
+            return Err(anyhow!(
+                "Fee autopilot target outbound percent must be between 1 and 99"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A change of a channel's forwarding fees proposed by the fee autopilot.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelFeeProposal {
+    /// The channel's ID.
+    ///
+    pub channel_id: ChannelId,
+    ///The node ID of our the channel’s counterparty.
+    pub counterparty_node_id: PublicKey,
+    /// The channel's outbound capacity the proposal is based on.
+    ///
+    pub outbound_capacity_msat: u64,
+    /// The channel's inbound capacity the proposal is based on.
+    ///
+    pub inbound_capacity_msat: u64,
+    /// The channel's current `forwardingFeeBaseMsat`.
+    ///
+    pub current_base_fee_msat: u32,
+    /// The channel's current `forwardingFeeProportionalMillionths`.
+    ///
+    pub current_proportional_millionths: u32,
+    /// The proposed `forwardingFeeBaseMsat`.
+    ///
+    pub proposed_base_fee_msat: u32,
+    /// The proposed `forwardingFeeProportionalMillionths`.
+    ///
+    pub proposed_proportional_millionths: u32,
+}
+
+/// Whether a channel is announced to the network.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChannelVisibility {
+    /// The channel is announced, i.e., can be used to route payments of other nodes.
+    ///
+    Public,
+    /// The channel is unannounced.
+    ///
+    Private,
+}
+
+/// Requirements inbound channels have to meet.
+///
+/// **Note:** LDK Node 0.1.0 accepts inbound channels on its own, so the policy is enforced once
+/// a channel is pending: the `Event.channelPending` of a channel violating the policy is not
+/// emitted and the channel is closed right away, and neither are its later events, such as
+/// `Event.channelClosed`. This happens while the app retrieves events via `node.nextEvent()` or
+/// `node.waitNextEvent()` only, so channels arriving while it doesn't poll stay open until it
+/// does. If closing a channel fails, its `Event.channelPending` is emitted as usual.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundChannelPolicy {
+    /// Only accept channels from these peers. An empty list accepts channels from any peer.
+    ///
+    pub allowed_peers: Vec<PublicKey>,
+    /// Only accept channels of at least this capacity.
+    ///
+    pub min_channel_size_sats: Option<u64>,
+    /// Only accept channels that are announced or unannounced, respectively.
+    ///
+    pub required_visibility: Option<ChannelVisibility>,
+    /// Only accept up to this many inbound channels that are not ready yet at the same time.
+    ///
+    pub max_pending_channels: Option<u32>,
+}
+
+impl InboundChannelPolicy {
+    /// Returns why the given channel violates the policy, if it does.
+    pub(crate) fn violation(
+        &self,
+        channel: &ldk_node::ChannelDetails,
+        pending_channels: usize,
+    ) -> Option<&'static str> {
+        let is_allowed_peer = |x: &PublicKey| {
+            ldk_node::bitcoin::secp256k1::PublicKey::from_str(x.internal.as_str())
+                .map_or(false, |x| x == channel.counterparty_node_id)
+        };
+        if !self.allowed_peers.is_empty() && !self.allowed_peers.iter().any(is_allowed_peer) {
+            return Some("peer is not allowed to open channels");
+        }
+        if self
+            .min_channel_size_sats
+            .map_or(false, |x| channel.channel_value_sats < x)
+        {
+            return Some("channel is too small");
+        }
+        match self.required_visibility {
+            Some(ChannelVisibility::Public) if !channel.is_public => {
+                return Some("channel is not announced")
+            }
+            Some(ChannelVisibility::Private) if channel.is_public => {
+                return Some("channel is announced")
+            }
+            _ => {}
+        }
+        if self
+            .max_pending_channels
+            .map_or(false, |x| pending_channels > x as usize)
+        {
+            return Some("too many pending inbound channels");
+        }
+        None
+    }
 }
 
 impl Default for Config {
@@ -702,6 +1393,8 @@ impl Default for Config {
             fee_rate_cache_update_interval_secs: DEFAULT_FEE_RATE_CACHE_UPDATE_INTERVAL_SECS,
             trusted_peers_0conf: vec![],
             log_level: DEFAULT_LOG_LEVEL,
+            inbound_channel_policy: None,
+            fee_autopilot: None,
         }
     }
 }
@@ -755,3 +1448,115 @@ const DEFAULT_BDK_WALLET_SYNC_INTERVAL_SECS: u64 = 60;
 const DEFAULT_LDK_WALLET_SYNC_INTERVAL_SECS: u64 = 20;
 const DEFAULT_FEE_RATE_CACHE_UPDATE_INTERVAL_SECS: u64 = 60;
 const DEFAULT_LOG_LEVEL: LogLevel = LogLevel::Debug;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A xorshift generator, so the round-trip tests cover many addresses without pulling in a
+    /// property testing crate.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn bytes<const N: usize>(&mut self) -> [u8; N] {
+            let mut bytes = [0u8; N];
+            bytes.iter_mut().for_each(|x| *x = self.next() as u8);
+            bytes
+        }
+    }
+
+    fn arbitrary_net_address(rng: &mut Rng, variant: u64) -> NetAddress {
+        let port = rng.next() as u16;
+        match variant % 5 {
+            0 => NetAddress::IPv4 {
+                addr: Ipv4Addr::from(rng.bytes::<4>()).to_string(),
+                port,
+            },
+            1 => NetAddress::IPv6 {
+                addr: Ipv6Addr::from(rng.bytes::<16>()).to_string(),
+                port,
+            },
+            2 => NetAddress::OnionV2 {
+                addr: rng.bytes::<12>(),
+            },
+            3 => NetAddress::OnionV3 {
+                pubkey: rng.bytes::<32>(),
+                checksum: rng.next() as u16,
+                version: rng.next() as u8,
+                port,
+            },
+            _ => {
+                let len = 1 + rng.next() % 63;
+                let host = (0..len)
+                    .map(|_| {
+                        b"abcdefghijklmnopqrstuvwxyz0123456789-."[rng.next() as usize % 38] as char
+                    })
+                    .collect();
+                NetAddress::Hostname { host, port }
+            }
+        }
+    }
+
+    #[test]
+    fn net_address_round_trips_through_ldk() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for i in 0..1000 {
+            let address = arbitrary_net_address(&mut rng, i);
+            let ldk_address = ldk_node::NetAddress::try_from(address.clone()).unwrap();
+            assert_eq!(NetAddress::from(ldk_address), address);
+        }
+    }
+
+    #[test]
+    fn net_address_maps_to_matching_ldk_variant() {
+        let ipv6: ldk_node::NetAddress = NetAddress::IPv6 {
+            addr: "2001:db8::1".to_string(),
+            port: 9735,
+        }
+        .try_into()
+        .unwrap();
+        assert!(matches!(ipv6.0, LdkNetAddress::IPv6 { port: 9735, .. }));
+        let hostname: ldk_node::NetAddress = NetAddress::Hostname {
+            host: "example.com".to_string(),
+            port: 9735,
+        }
+        .try_into()
+        .unwrap();
+        assert!(matches!(
+            hostname.0,
+            LdkNetAddress::Hostname { port: 9735, .. }
+        ));
+    }
+
+    #[test]
+    fn invalid_net_address_is_rejected() {
+        let invalid = [
+            NetAddress::IPv4 {
+                addr: "256.0.0.1".to_string(),
+                port: 9735,
+            },
+            NetAddress::IPv4 {
+                addr: "::1".to_string(),
+                port: 9735,
+            },
+            NetAddress::IPv6 {
+                addr: "example.com".to_string(),
+                port: 9735,
+            },
+            NetAddress::Hostname {
+                host: "a".repeat(256),
+                port: 9735,
+            },
+        ];
+        for address in invalid {
+            assert!(ldk_node::NetAddress::try_from(address).is_err());
+        }
+    }
+}